@@ -0,0 +1,219 @@
+//! Exchange detection for instrument names that arrive without an exchange
+
+use std::cmp::Ordering;
+
+use crate::exchange::binance::{BinanceHandler, BinanceMarket};
+use crate::exchange::bybit::{BybitCategory, BybitHandler};
+use crate::exchange::kucoin::{KucoinHandler, KucoinMarket};
+use crate::{registry, Exchange, ExchangeHandler, Instrument, InstrumentError, InstrumentType, MarketType};
+
+/// Factor of a candidate whose handler writes the name back exactly as given
+const EXACT_MATCH_WEIGHT: f64 = 2.0;
+/// Factor of a candidate whose handler accepts the name but writes it differently
+/// (e.g., different case or XBT for BTC)
+const LOOSE_MATCH_WEIGHT: f64 = 1.0;
+
+/// An exchange whose handler accepts a raw instrument name
+#[derive(Debug, PartialEq, Clone)]
+pub struct Candidate {
+    pub exchange: Exchange,
+    pub instrument: Instrument,
+    /// Share of the total confidence across all candidates, in (0, 1]
+    pub confidence: f64,
+}
+
+/// Number of instrument fields spelled out in `instrument_name`.
+///
+/// The base and any expiry, strike or option kind are always read from the name, while
+/// handlers may imply a quote currency or index kind (e.g., Hyperliquid's BTC is BTC-USDC),
+/// so those only count when they appear in the name.
+fn specificity(instrument_name: &str, instrument_type: &InstrumentType) -> usize {
+    let name = instrument_name.to_ascii_uppercase();
+    let spelled_out = |token: &str| usize::from(name.contains(&token.to_ascii_uppercase()));
+
+    match instrument_type {
+        InstrumentType::Spot { quote, .. } | InstrumentType::Perpetual { quote, .. } => 1 + spelled_out(quote.as_ref()),
+        InstrumentType::Future { quote, .. } => 2 + spelled_out(quote.as_ref()),
+        InstrumentType::PerpetualOption { quote, .. } | InstrumentType::Move { quote, .. } => 3 + spelled_out(quote.as_ref()),
+        InstrumentType::Option { quote, .. } => 4 + spelled_out(quote.as_ref()),
+        InstrumentType::Index { quote, kind, .. } => 1 + spelled_out(quote.as_ref()) + spelled_out(&kind.to_string()),
+        InstrumentType::Combo { legs, .. } => legs.iter().map(|leg| specificity(instrument_name, &leg.instrument_type)).sum(),
+    }
+}
+
+/// Handlers of the sub-markets a bare symbol may come from, tried when the exchange's own
+/// handler rejects the symbol as ambiguous (e.g., BTCUSDT on Binance)
+fn sub_market_handlers(exchange: Exchange) -> Vec<&'static dyn ExchangeHandler> {
+    match exchange {
+        Exchange::Binance => [BinanceMarket::Spot, BinanceMarket::UsdM, BinanceMarket::CoinM, BinanceMarket::Options]
+            .into_iter()
+            .map(|market| BinanceHandler::for_market(market) as &dyn ExchangeHandler)
+            .collect(),
+        Exchange::Bybit => [BybitCategory::Spot, BybitCategory::Linear, BybitCategory::Inverse, BybitCategory::Option]
+            .into_iter()
+            .map(|category| BybitHandler::for_category(category) as &dyn ExchangeHandler)
+            .collect(),
+        Exchange::Kucoin => [KucoinMarket::Spot, KucoinMarket::Futures]
+            .into_iter()
+            .map(|market| KucoinHandler::for_market(market) as &dyn ExchangeHandler)
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Weighted readings of `instrument_name` by `handler`, one per sub-market that accepts it
+/// if the handler finds the name ambiguous
+fn readings(handler: &dyn ExchangeHandler, exchange: Exchange, instrument_name: &str, market_type: &MarketType) -> Vec<(Instrument, f64)> {
+    let weigh = |handler: &dyn ExchangeHandler, instrument: Instrument| {
+        let round_trip = match handler.try_denormalize(&instrument) {
+            Ok(name) if name == instrument_name => EXACT_MATCH_WEIGHT,
+            _ => LOOSE_MATCH_WEIGHT,
+        };
+        let weight = specificity(instrument_name, &instrument.instrument_type) as f64 * round_trip;
+        (instrument, weight)
+    };
+
+    match handler.try_normalize(market_type.clone(), instrument_name) {
+        Ok(instrument) => vec![weigh(handler, instrument)],
+        Err(InstrumentError::AmbiguousInstrument { .. }) => sub_market_handlers(exchange)
+            .into_iter()
+            .filter_map(|handler| {
+                let instrument = handler.try_normalize(market_type.clone(), instrument_name).ok()?;
+                Some(weigh(handler, instrument))
+            })
+            .collect(),
+        Err(_) => Vec::new(),
+    }
+}
+
+/// Runs `instrument_name` through every registered handler and returns the exchanges
+/// that accept it, most likely first.
+///
+/// Candidates are weighted by how many instrument fields the name spells out, so a dated
+/// option outranks a spot pair read from the same name, and that weight doubles when the
+/// handler denormalizes the instrument back to exactly `instrument_name`. The confidences
+/// of all candidates sum to 1, so a name only one exchange understands is returned with
+/// confidence 1.
+///
+/// A name an exchange only finds ambiguous across its sub-markets (e.g., BTCUSDT, a spot
+/// pair on Binance Spot and a perpetual on USDⓈ-M) yields one candidate per sub-market reading.
+pub fn detect_exchange(instrument_name: &str, market_type: MarketType) -> Vec<Candidate> {
+    let mut matches: Vec<(Instrument, f64)> = registry::exchanges()
+        .into_iter()
+        .flat_map(|exchange| readings(exchange.handler(), exchange, instrument_name, &market_type))
+        .collect();
+
+    let total: f64 = matches.iter().map(|(_, weight)| weight).sum();
+    matches.sort_by(|(a, wa), (b, wb)| {
+        wb.partial_cmp(wa)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| a.exchange.name().cmp(b.exchange.name()))
+    });
+
+    matches
+        .into_iter()
+        .map(|(instrument, weight)| Candidate {
+            exchange: instrument.exchange,
+            instrument,
+            confidence: weight / total,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod detect_tests {
+    use super::detect_exchange;
+    use crate::{Exchange, InstrumentType, MarketType};
+
+    fn exchanges(instrument_name: &str, market_type: MarketType) -> Vec<Exchange> {
+        detect_exchange(instrument_name, market_type)
+            .into_iter()
+            .map(|candidate| candidate.exchange)
+            .collect()
+    }
+
+    #[test]
+    fn test_detect_unique() {
+        let candidates = detect_exchange("BTC-20250328-3000-P", MarketType::OrderBook);
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].exchange, Exchange::Derive);
+        assert_eq!(candidates[0].confidence, 1.0);
+        assert_eq!(candidates[0].instrument.to_string(), "o.o.BTC-USD-20250328-3000-P.derive");
+
+        assert_eq!(exchanges("BTC-USD-PERP", MarketType::OrderBook), vec![Exchange::Paradex]);
+    }
+
+    #[test]
+    fn test_detect_ambiguous() {
+        let candidates = detect_exchange("BTC-28MAR25-100000-C", MarketType::OrderBook);
+        assert!(candidates.len() > 1);
+        assert!(candidates.iter().all(|c| matches!(c.instrument.instrument_type, InstrumentType::Option { .. })));
+        let found = candidates.iter().map(|c| c.exchange).collect::<Vec<_>>();
+        assert!(found.contains(&Exchange::Deribit));
+        assert!(found.contains(&Exchange::Aevo));
+        assert!(!found.contains(&Exchange::Derive));
+
+        // dYdX only publishes order books
+        assert!(exchanges("ETH-USD", MarketType::OrderBook).contains(&Exchange::Dydx));
+        assert!(!exchanges("ETH-USD", MarketType::Ticker).contains(&Exchange::Dydx));
+    }
+
+    #[test]
+    fn test_detect_specificity() {
+        // A quote spelled out in the name beats one the handler implies
+        let candidates = detect_exchange("BTCUSDT", MarketType::OrderBook);
+        assert_eq!(candidates[0].instrument.instrument_type.quote(), "USDT");
+        assert!(candidates.iter().any(|c| c.exchange == Exchange::Hyperliquid && c.confidence < candidates[0].confidence));
+    }
+
+    #[test]
+    fn test_detect_sub_markets() {
+        // Binance and Bybit list BTCUSDT as both a spot pair and a perpetual
+        let candidates = detect_exchange("BTCUSDT", MarketType::OrderBook);
+        let readings = |exchange: Exchange| candidates.iter()
+            .filter(|c| c.exchange == exchange)
+            .map(|c| c.instrument.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(readings(Exchange::Binance), vec!["o.s.BTC-USDT.binance", "o.p.BTC-USDT.binance"]);
+        assert_eq!(readings(Exchange::Bybit), vec!["o.s.BTC-USDT.bybit", "o.p.BTC-USDT.bybit"]);
+        let total: f64 = candidates.iter().map(|c| c.confidence).sum();
+        assert!((total - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_detect_negatives() {
+        // Dated names are never read as spot pairs or perpetuals
+        for instrument_name in ["BTC-28MAR25", "BTC-20250328"] {
+            assert!(detect_exchange(instrument_name, MarketType::OrderBook).iter().all(|c| matches!(
+                c.instrument.instrument_type,
+                InstrumentType::Future { .. }
+            )));
+        }
+        assert!(!exchanges("BTC-28MAR25", MarketType::OrderBook).contains(&Exchange::Derive));
+        // Perpetual suffixes are never read as spot quotes
+        for instrument_name in ["ETH-PERP", "BTC-PERPETUAL", "BTC-USD-PERP"] {
+            assert!(detect_exchange(instrument_name, MarketType::OrderBook).iter().all(|c| !matches!(
+                c.instrument.instrument_type,
+                InstrumentType::Spot { .. }
+            )));
+        }
+    }
+
+    #[test]
+    fn test_detect_confidence() {
+        // Several exchanges accept the lowercase name, but none writes it back unchanged
+        let candidates = detect_exchange("btc-28mar25-100000-c", MarketType::OrderBook);
+        assert!(candidates.len() > 1);
+        assert!(candidates.iter().all(|c| c.confidence == candidates[0].confidence));
+        let total: f64 = candidates.iter().map(|c| c.confidence).sum();
+        assert!((total - 1.0).abs() < 1e-9);
+
+        let deribit = candidates.iter().find(|c| c.exchange == Exchange::Deribit).unwrap();
+        assert_eq!(deribit.instrument.to_string(), "o.o.BTC-USD-20250328-100000-C.deribit");
+    }
+
+    #[test]
+    fn test_detect_unknown() {
+        assert!(detect_exchange("not an instrument", MarketType::OrderBook).is_empty());
+    }
+}
@@ -1,30 +1,84 @@
-use tracing::error;
+use chrono::NaiveTime;
 use std::borrow::Cow;
 
-use crate::{denormalize_expiry, normalize_expiry, parse_expiry_date, Currency, Exchange, ExchangeHandler, Instrument, InstrumentType, MarketType, OptionKind};
+use crate::exchange::parse_listed_quote;
+use crate::{denormalize_expiry, normalize_expiry, parse_base, parse_expiry_date, ContractSpec, Currency, Decimal, Exchange, ExchangeHandler, Expiry, IndexKind, Instrument, InstrumentError, InstrumentResult, InstrumentType, MarketType, OptionKind, Strike};
 
-const LOG_CTX: &str = "normify::exchange#aevo";
+/// Every market is margined and settled in USDC
+const SETTLEMENT_CURRENCY: &str = "USDC";
 const DEFAULT_QUOTE_CURRENCY: &str = "usdc";
 const DEFAULT_EXPIRY_FORMAT: &str = "%d%b%y";
+const STRIKE_DECIMAL_SEPARATOR: char = '.';
+/// Quote currencies of spot pairs (e.g., AEVO-USDC)
+const SPOT_QUOTE_CURRENCIES: [&str; 1] = [SETTLEMENT_CURRENCY];
+/// Quote currencies of perpetuals (e.g., BTC-PERP in USDC, SOL_USDT-PERP)
+const PERPETUAL_QUOTE_CURRENCIES: [&str; 2] = [SETTLEMENT_CURRENCY, "USDT"];
+/// Suffix of perpetuals (e.g., BTC-PERP)
+const PERPETUAL_SUFFIX: &str = "PERP";
+/// Channel prefix of index prices (e.g., index:BTC)
+const INDEX_CHANNEL_PREFIX: &str = "index:";
+/// Index prices are published in USD
+const INDEX_QUOTE_CURRENCY: &str = "USD";
+/// Expiring contracts settle at 08:00 UTC
+const SETTLEMENT_TIME: NaiveTime = NaiveTime::from_hms_opt(8, 0, 0).unwrap();
+
+/// Handler for Aevo perpetuals (e.g., BTC-PERP), dated futures (e.g., BTC-28MAR25),
+/// options (e.g., BTC-28MAR25-100000-C), spot pairs (e.g., AEVO-USDC) and index prices (e.g., index:BTC).
+///
+/// Pre-launch perpetuals are named like any other perpetual (e.g., XYZ-PERP) and pay no funding.
+/// The built-in handler treats every perpetual as launched; a handler built with
+/// `with_prelaunch` flags them and rejects their funding. The flag is not part of the name,
+/// so it is not carried on `Instrument` (whose standard format must round-trip); query it
+/// with `is_prelaunch`.
+#[derive(Default)]
+pub struct Aevohandler {
+    /// Bases of the pre-launch perpetuals, as flagged by the `markets` endpoint
+    prelaunch: Vec<Currency>,
+}
 
-pub struct Aevohandler;
-        
 // Create a static instance to avoid allocations
-pub static AEVO_HANDLER: Aevohandler = Aevohandler;
+pub static AEVO_HANDLER: Aevohandler = Aevohandler::new();
 
-impl ExchangeHandler for Aevohandler {
-    fn normalize(&self, market_type: MarketType, instrument_name: &str) -> Option<Instrument> {
-        
-        if !self.supports_market_type(&market_type) {
-            error!(name: LOG_CTX, "denormalize::Market Type is unsupported: {:?}", market_type);
-            return None;
-        }
+impl Aevohandler {
+    /// Handler treating every perpetual as launched, like `AEVO_HANDLER`
+    pub const fn new() -> Self {
+        Self { prelaunch: Vec::new() }
+    }
+
+    /// Flags the perpetuals on `bases` as pre-launch
+    pub fn with_prelaunch(mut self, bases: impl IntoIterator<Item = Currency>) -> Self {
+        self.prelaunch.extend(bases);
+        self
+    }
+
+    /// Whether the perpetual on `base` is a pre-launch market
+    pub fn is_prelaunch(&self, base: &Currency) -> bool {
+        self.prelaunch.contains(base)
+    }
+
+    /// Funding is only published for launched perpetuals
+    fn supports_funding(&self, instrument_type: &InstrumentType) -> bool {
+        matches!(instrument_type, InstrumentType::Perpetual { base, .. } if !self.is_prelaunch(base))
+    }
+
+    /// Parses a `DDMMMYY` expiry
+    fn parse_expiry(&self, expiry: &str) -> InstrumentResult<Expiry> {
+        parse_expiry_date(expiry, DEFAULT_EXPIRY_FORMAT)
+            .and_then(|_| normalize_expiry(expiry, self.settlement_time()))
+            .ok_or_else(|| InstrumentError::invalid_expiry(Exchange::Aevo, expiry))
+    }
+
+    fn is_default_quote(&self, quote: &Currency) -> bool {
+        quote.as_ref().eq_ignore_ascii_case(DEFAULT_QUOTE_CURRENCY)
+    }
+
+    fn normalize_type(&self, instrument_name: &str) -> InstrumentResult<InstrumentType> {
         // Split the instrument name into parts
         let parts: Vec<&str> = instrument_name.split('-').collect();
     
         match parts.as_slice() {
-            // Perpetual: e.g., BTC-PERPETUAL or SOL_USDC-PERPETUAL (Non USD quote)
-            [base_quote, perpetual] if perpetual.eq_ignore_ascii_case("perp") => {
+            // Perpetual: e.g., BTC-PERP or SOL_USDT-PERP (Non USDC quote)
+            [base_quote, perpetual] if perpetual.eq_ignore_ascii_case(PERPETUAL_SUFFIX) => {
                 // Use split_once to avoid additional allocations
                 let (base, quote) = if let Some((b, q)) = base_quote.split_once('_') {
                     (b, q)
@@ -32,105 +86,139 @@ impl ExchangeHandler for Aevohandler {
                     (*base_quote, DEFAULT_QUOTE_CURRENCY)
                 };
                 
-                Some(Instrument::new(
-                    Exchange::Aevo, 
-                    market_type, 
-                    InstrumentType::Perpetual {
-                        base: Currency::new(Cow::Owned(base.to_string())), 
-                        quote: Currency::new(Cow::Owned(quote.to_string())), 
-                    }
-                ))
+                Ok(InstrumentType::Perpetual {
+                    base: parse_base(Exchange::Aevo, base)?,
+                    quote: parse_listed_quote(Exchange::Aevo, instrument_name, quote, &PERPETUAL_QUOTE_CURRENCIES)?,
+                })
+            }
+
+            // Future: e.g., BTC-28MAR25
+            [base, expiry_str] if parse_expiry_date(expiry_str, DEFAULT_EXPIRY_FORMAT).is_some() => {
+                Ok(InstrumentType::Future {
+                    base: parse_base(Exchange::Aevo, base)?,
+                    quote: Currency::new(Cow::Borrowed(DEFAULT_QUOTE_CURRENCY)),
+                    expiry: self.parse_expiry(expiry_str)?,
+                })
+            }
+
+            // Spot: e.g., AEVO-USDC
+            [base, quote] => {
+                Ok(InstrumentType::Spot {
+                    base: parse_base(Exchange::Aevo, base)?,
+                    quote: parse_listed_quote(Exchange::Aevo, instrument_name, quote, &SPOT_QUOTE_CURRENCIES)?,
+                })
             }
     
             // Option: e.g., BTC-28MAR25-100000-C
-            [base, expiry, strike_str, kind_str] => {
+            [base, expiry_str, strike_str, kind_str] => {
+                let base = parse_base(Exchange::Aevo, base)?;
+                
                 // Validate the expiry date
-                if parse_expiry_date(expiry, DEFAULT_EXPIRY_FORMAT).is_none() {
-                    error!(name: LOG_CTX, "normalize::Invalid expiry date format: {}", expiry);
-                    return None;
-                }
+                let expiry = self.parse_expiry(expiry_str)?;
                 
                 // Parse strike price
-                let strike = match strike_str.parse::<u64>() {
-                    Ok(s) => s,
-                    Err(_) => {
-                        error!(name: LOG_CTX, "normalize::Invalid strike price: {}", strike_str);
-                        return None;
-                    }
-                };
+                let strike = Strike::parse_with_separator(strike_str, STRIKE_DECIMAL_SEPARATOR)
+                    .ok_or_else(|| InstrumentError::invalid_strike(Exchange::Aevo, strike_str))?;
                 
                 // Parse option kind
-                let kind = match OptionKind::try_from(*kind_str) {
-                    Ok(k) => k,
-                    Err(e) => {
-                        error!(name: LOG_CTX, "normalize::Invalid option kind: {}", e);
-                        return None;
-                    }
-                };
+                let kind = OptionKind::try_from(*kind_str)
+                    .map_err(|_| InstrumentError::invalid_option_kind(Exchange::Aevo, kind_str))?;
                 
-                let normalized_expiry = normalize_expiry(expiry)?;
+                Ok(InstrumentType::Option {
+                    base, 
+                    quote: Currency::new(Cow::Owned(DEFAULT_QUOTE_CURRENCY.to_string())),
+                    expiry, 
+                    strike, 
+                    kind
+                })
+            }
+    
+            // Price index: e.g., index:BTC
+            [index] if index.starts_with(INDEX_CHANNEL_PREFIX) => {
+                let base = &index[INDEX_CHANNEL_PREFIX.len()..];
                 
-                Some(Instrument::new(
-                    Exchange::Aevo,
-                    market_type,
-                    InstrumentType::Option {
-                        base: Currency::new(Cow::Owned(base.to_string())), 
-                        quote: Currency::new(Cow::Owned(DEFAULT_QUOTE_CURRENCY.to_string())),
-                        expiry: Cow::Owned(normalized_expiry), 
-                        strike, 
-                        kind
-                    }
-                ))
+                Ok(InstrumentType::Index {
+                    base: parse_base(Exchange::Aevo, base)?, 
+                    quote: Currency::new(Cow::Borrowed(INDEX_QUOTE_CURRENCY)),
+                    kind: IndexKind::Price,
+                })
             }
     
             // No matching format
-            _ => {
-                error!(name: LOG_CTX, "normalize::Unexpected instrument format: {:?}", instrument_name);
-                None
-            }
+            _ => Err(InstrumentError::unrecognized(Exchange::Aevo, instrument_name)),
         }
     }
+}
 
-    fn denormalize(&self, instrument: &Instrument) -> Option<String> {
+impl ExchangeHandler for Aevohandler {
+    fn try_normalize(&self, market_type: MarketType, instrument_name: &str) -> InstrumentResult<Instrument> {
+        let instrument_type = self.normalize_type(instrument_name)?;
+        if market_type == MarketType::Funding && !self.supports_funding(&instrument_type) {
+            return Err(InstrumentError::UnsupportedMarketType { exchange: Exchange::Aevo, market_type });
+        }
+
+        Ok(Instrument::new(Exchange::Aevo, market_type, instrument_type))
+    }
+
+    fn try_denormalize(&self, instrument: &Instrument) -> InstrumentResult<String> {
         // Check if this is the right exchange handler
         if instrument.exchange != Exchange::Aevo {
-            error!(name: LOG_CTX, "denormalize::Attempted to use Aevo handler for {:?}", instrument.exchange);
-            return None;
+            return Err(InstrumentError::ExchangeMismatch { expected: Exchange::Aevo, found: instrument.exchange });
         }
 
         if !self.supports_instrument_type(&instrument.instrument_type) {
-            error!(name: LOG_CTX, "denormalize::Instrument Type for {:?} is unsupported", instrument.instrument_type);
-            return None;
+            return Err(InstrumentError::unsupported_instrument_type(Exchange::Aevo, &instrument.instrument_type));
         }
-        if !self.supports_market_type(&instrument.market_type) {
-            error!(name: LOG_CTX, "denormalize::Market Type for {:?} is unsupported",instrument.market_type);
-            return None;
+        if instrument.market_type == MarketType::Funding && !self.supports_funding(&instrument.instrument_type) {
+            return Err(InstrumentError::UnsupportedMarketType { exchange: Exchange::Aevo, market_type: instrument.market_type.clone() });
         }
         
         match &instrument.instrument_type {
             
-            InstrumentType::Option { base, quote: _, expiry, strike, kind } => {
+            InstrumentType::Option { base, quote, expiry, strike, kind } if self.is_default_quote(quote) => {
                 let denormalized_expiry = denormalize_expiry(expiry, DEFAULT_EXPIRY_FORMAT);
-                Some(format!("{}-{}-{}-{}", 
+                Ok(format!("{}-{}-{}-{}", 
                     base.as_ref(), 
                     denormalized_expiry, 
-                    strike, 
-                    kind.to_string()))
+                    strike.format_with_separator(STRIKE_DECIMAL_SEPARATOR), 
+                    kind))
+            },
+
+            InstrumentType::Future { base, quote, expiry } if self.is_default_quote(quote) => {
+                Ok(format!("{}-{}", base.as_ref(), denormalize_expiry(expiry, DEFAULT_EXPIRY_FORMAT)))
+            },
+            
+            InstrumentType::Perpetual { base, quote } if PERPETUAL_QUOTE_CURRENCIES.iter().any(|listed| listed.eq_ignore_ascii_case(quote.as_ref())) => {
+                if self.is_default_quote(quote) {
+                    Ok(format!("{}-{}", base.as_ref(), PERPETUAL_SUFFIX))
+                } else {
+                    Ok(format!("{}_{}-{}", base.as_ref(), quote.as_ref(), PERPETUAL_SUFFIX))
+                }
+            },
+
+            InstrumentType::Spot { base, quote } if SPOT_QUOTE_CURRENCIES.contains(&quote.as_ref()) => {
+                Ok(format!("{}-{}", base.as_ref(), quote.as_ref()))
             },
             
-            InstrumentType::Perpetual { base, .. } => {
-                Some(format!("{}-PERP", base.as_ref()))
+            InstrumentType::Index { base, quote, kind: IndexKind::Price } if quote.as_ref().eq_ignore_ascii_case(INDEX_QUOTE_CURRENCY) => {
+                Ok(format!("{}{}", INDEX_CHANNEL_PREFIX, base.as_ref()))
             },
-            _ => None
+            _ => Err(InstrumentError::unsupported_instrument_type(Exchange::Aevo, &instrument.instrument_type)),
         }
     }
 
-    fn supports_market_type(&self, market_type: &MarketType) -> bool {
-        matches!(market_type, MarketType::OrderBook) || matches!(market_type, MarketType::Ticker)
+    fn supports_instrument_type(&self, instrument_type: &InstrumentType) -> bool {
+        matches!(instrument_type,
+            InstrumentType::Perpetual { .. } | InstrumentType::Future { .. } | InstrumentType::Option { .. } | InstrumentType::Spot { .. })
+        || matches!(instrument_type, InstrumentType::Index { kind: IndexKind::Price, .. })
     }
 
-    fn supports_instrument_type(&self, instrument_type: &InstrumentType) -> bool {
-        matches!(instrument_type, InstrumentType::Perpetual { .. }) || matches!(instrument_type, InstrumentType::Option { .. })
+    fn settlement_time(&self) -> NaiveTime {
+        SETTLEMENT_TIME
+    }
+
+    fn contract_spec(&self, _instrument: &Instrument) -> InstrumentResult<ContractSpec> {
+        Ok(ContractSpec::linear(Currency::new(Cow::Borrowed(SETTLEMENT_CURRENCY)), Decimal::from(1)))
     }
 }
 
@@ -138,13 +226,16 @@ impl ExchangeHandler for Aevohandler {
 mod deribit_normalize_tests{
     use std::borrow::Cow;
 
-    use crate::{exchange::aevo::Aevohandler, Currency, Exchange, ExchangeHandler, Instrument, InstrumentType, MarketType, OptionKind};
+    use chrono::NaiveDate;
+
+    use super::SETTLEMENT_TIME;
+    use crate::{exchange::aevo::{Aevohandler, AEVO_HANDLER}, parse_standard_format, Currency, Exchange, ExchangeHandler, IndexKind, Instrument, InstrumentError, Expiry, InstrumentType, MarketType, OptionKind, Strike};
 
 
     #[test]
     fn test_normalize_option() {
         let instrument_name = "BTC-28MAR25-100000-C";
-        let exchange = Aevohandler;
+        let exchange = &AEVO_HANDLER;
         let market_type = MarketType::OrderBook;
         let expected_instrument = Instrument::new(
             Exchange::Aevo, 
@@ -152,18 +243,36 @@ mod deribit_normalize_tests{
             InstrumentType::Option{
                 base: Currency::new(Cow::Borrowed("BTC")), 
                 quote: Currency::new(Cow::Borrowed("USDC")), 
-                expiry: Cow::Borrowed("20250328"),
-                strike: 100000, 
+                expiry: Expiry::new(NaiveDate::from_ymd_opt(2025, 3, 28).unwrap(), SETTLEMENT_TIME),
+                strike: Strike::from(100000), 
                 kind: OptionKind::Call});
         let result = exchange.normalize(MarketType::OrderBook,instrument_name);
         println!("{:?}", result);
         assert_eq!(result, Some(expected_instrument));
     }
 
+    #[test]
+    fn test_normalize_option_fractional_strike() {
+        let instrument_name = "DOGE-28MAR25-0.15-P";
+        let exchange = &AEVO_HANDLER;
+        let expected_instrument = Instrument::new(
+            Exchange::Aevo, 
+            MarketType::OrderBook, 
+            InstrumentType::Option{
+                base: Currency::new(Cow::Borrowed("DOGE")), 
+                quote: Currency::new(Cow::Borrowed("USDC")), 
+                expiry: Expiry::new(NaiveDate::from_ymd_opt(2025, 3, 28).unwrap(), SETTLEMENT_TIME),
+                strike: Strike::new(15, 2).unwrap(), 
+                kind: OptionKind::Put});
+        let result = exchange.normalize(MarketType::OrderBook, instrument_name);
+        assert_eq!(result, Some(expected_instrument.clone()));
+        assert_eq!(exchange.denormalize(&expected_instrument), Some(String::from(instrument_name)));
+    }
+
     #[test]
     fn test_normalize_perpetual1() {
         let instrument_name = "BTC-PERP";
-        let exchange = Aevohandler;
+        let exchange = &AEVO_HANDLER;
         let market_type = MarketType::OrderBook;
         let expected_instrument = Instrument::new(
             Exchange::Aevo, 
@@ -177,11 +286,91 @@ mod deribit_normalize_tests{
         assert_eq!(result, Some(expected_instrument));
     }
 
+    #[test]
+    fn test_normalize_index() {
+        let instrument_name = "index:ETH";
+        let exchange = &AEVO_HANDLER;
+        let expected_instrument = Instrument::new(
+            Exchange::Aevo, 
+            MarketType::Ticker, 
+            InstrumentType::Index{
+                base: Currency::new(Cow::Borrowed("ETH")), 
+                quote: Currency::new(Cow::Borrowed("USD")), 
+                kind: IndexKind::Price,
+            });
+        let result = exchange.normalize(MarketType::Ticker, instrument_name);
+        assert_eq!(result, Some(expected_instrument.clone()));
+        assert_eq!(exchange.denormalize(&expected_instrument), Some(String::from(instrument_name)));
+    }
+
+    #[test]
+    fn test_normalize_spot_and_future() {
+        let exchange = &AEVO_HANDLER;
+        let spot = exchange.normalize(MarketType::OrderBook, "AEVO-USDC").unwrap();
+        assert_eq!(spot.instrument_type, InstrumentType::Spot{
+            base: Currency::new(Cow::Borrowed("AEVO")), 
+            quote: Currency::new(Cow::Borrowed("USDC")), 
+        });
+        
+        let future = exchange.normalize(MarketType::PublicTrade, "ETH-27JUN25").unwrap();
+        assert_eq!(future, Instrument::new(
+            Exchange::Aevo, 
+            MarketType::PublicTrade, 
+            InstrumentType::Future{
+                base: Currency::new(Cow::Borrowed("ETH")), 
+                quote: Currency::new(Cow::Borrowed("USDC")), 
+                expiry: Expiry::new(NaiveDate::from_ymd_opt(2025, 6, 27).unwrap(), SETTLEMENT_TIME),
+            }));
+        assert_eq!(exchange.denormalize(&future), Some(String::from("ETH-27JUN25")));
+    }
+
+    #[test]
+    fn test_normalize_funding() {
+        let exchange = &AEVO_HANDLER;
+        assert_eq!(exchange.normalize(MarketType::Funding, "BTC-PERP").unwrap().to_string(), "f.p.BTC-USDC.aevo");
+        assert!(matches!(exchange.try_normalize(MarketType::Funding, "BTC-28MAR25-100000-C"), 
+            Err(InstrumentError::UnsupportedMarketType { exchange: Exchange::Aevo, market_type: MarketType::Funding })));
+    }
+
+    #[test]
+    fn test_normalize_prelaunch() {
+        let exchange = Aevohandler::default().with_prelaunch([Currency::new(Cow::Borrowed("XYZ"))]);
+        let instrument = exchange.normalize(MarketType::OrderBook, "XYZ-PERP").unwrap();
+        assert!(exchange.is_prelaunch(&Currency::new(Cow::Borrowed("XYZ"))));
+        assert_eq!(instrument.instrument_type.base().to_string(), "XYZ");
+        
+        // Pre-launch perpetuals pay no funding
+        assert_eq!(exchange.normalize(MarketType::Funding, "XYZ-PERP"), None);
+        assert!(exchange.normalize(MarketType::Funding, "BTC-PERP").is_some());
+        assert!(AEVO_HANDLER.normalize(MarketType::Funding, "XYZ-PERP").is_some());
+    }
+
+    #[test]
+    fn test_prelaunch_only_through_configured_handler() {
+        let exchange = Aevohandler::new().with_prelaunch([Currency::new(Cow::Borrowed("XYZ"))]);
+
+        // Unless the configured handler is registered, the standard format resolves to the
+        // built-in handler, which skips the check
+        let instrument = parse_standard_format("f.p.XYZ-USDC.aevo").unwrap();
+        assert!(Exchange::Aevo.handler().try_denormalize(&instrument).is_ok());
+        assert!(matches!(exchange.try_denormalize(&instrument),
+            Err(InstrumentError::UnsupportedMarketType { exchange: Exchange::Aevo, market_type: MarketType::Funding })));
+        assert!(matches!(exchange.try_normalize(MarketType::Funding, "XYZ-PERP"),
+            Err(InstrumentError::UnsupportedMarketType { exchange: Exchange::Aevo, market_type: MarketType::Funding })));
+    }
+
     #[test]
     fn test_normalize_unknown() {
         let instrument_name = "BTC-USD-20250528";
-        let exchange = Aevohandler;
+        let exchange = &AEVO_HANDLER;
         assert_eq!(exchange.normalize(MarketType::OrderBook, instrument_name), None);
+        // Other venues' perpetuals and unlisted quotes are not spot pairs
+        assert_eq!(exchange.normalize(MarketType::OrderBook, "BTC-PERPETUAL"), None);
+        assert_eq!(exchange.normalize(MarketType::OrderBook, "BTC-EUR"), None);
+        // Perpetual quotes are listed too
+        assert_eq!(exchange.normalize(MarketType::OrderBook, "SOL_USDT-PERP").unwrap().to_string(), "o.p.SOL-USDT.aevo");
+        assert_eq!(exchange.normalize(MarketType::OrderBook, "BTC_-PERP"), None);
+        assert_eq!(exchange.normalize(MarketType::OrderBook, "BTC_XYZ-PERP"), None);
     }
 }
 
@@ -189,7 +378,10 @@ mod deribit_normalize_tests{
 mod deribit_denormalize_tests{
     use std::borrow::Cow;
 
-    use crate::{exchange::aevo::Aevohandler, Currency, Exchange, ExchangeHandler, Instrument, InstrumentType, MarketType, OptionKind};
+    use chrono::NaiveDate;
+
+    use super::SETTLEMENT_TIME;
+    use crate::{exchange::aevo::AEVO_HANDLER, Currency, Exchange, ExchangeHandler, Instrument, Expiry, InstrumentType, MarketType, OptionKind, Strike};
 
     #[test]
     fn test_denorm_option() {
@@ -199,10 +391,10 @@ mod deribit_denormalize_tests{
             InstrumentType::Option{
                 base: Currency::new(Cow::Borrowed("BTC")), 
                 quote: Currency::new(Cow::Borrowed("USDC")), 
-                expiry: Cow::Borrowed("20250328"), 
-                strike: 100000, 
+                expiry: Expiry::new(NaiveDate::from_ymd_opt(2025, 3, 28).unwrap(), SETTLEMENT_TIME), 
+                strike: Strike::from(100000), 
                 kind: OptionKind::Call});
-        let exchange = Aevohandler;
+        let exchange = &AEVO_HANDLER;
         assert_eq!(exchange.denormalize(&instrument), Some(String::from("BTC-28MAR25-100000-C")));
     }
 
@@ -215,7 +407,35 @@ mod deribit_denormalize_tests{
                 base: Currency::new(Cow::Borrowed("BTC")), 
                 quote: Currency::new(Cow::Borrowed("USDC")), 
             });
-        let exchange = Aevohandler;
+        let exchange = &AEVO_HANDLER;
         assert_eq!(exchange.denormalize(&instrument), Some(String::from("BTC-PERP")));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_denorm_perp_quote() {
+        let instrument = Instrument::new(
+            Exchange::Aevo, 
+            MarketType::PublicTrade, 
+            InstrumentType::Perpetual{
+                base: Currency::new(Cow::Borrowed("SOL")), 
+                quote: Currency::new(Cow::Borrowed("USDT")), 
+            });
+        let exchange = &AEVO_HANDLER;
+        assert_eq!(exchange.denormalize(&instrument), Some(String::from("SOL_USDT-PERP")));
+    }
+
+    #[test]
+    fn test_denorm_option_quote() {
+        let instrument = Instrument::new(
+            Exchange::Aevo, 
+            MarketType::OrderBook, 
+            InstrumentType::Option{
+                base: Currency::new(Cow::Borrowed("BTC")), 
+                quote: Currency::new(Cow::Borrowed("USDT")), 
+                expiry: Expiry::new(NaiveDate::from_ymd_opt(2025, 3, 28).unwrap(), SETTLEMENT_TIME), 
+                strike: Strike::from(100000), 
+                kind: OptionKind::Call});
+        let exchange = &AEVO_HANDLER;
+        assert_eq!(exchange.denormalize(&instrument), None);
+    }
+}
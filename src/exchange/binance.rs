@@ -0,0 +1,447 @@
+use chrono::NaiveTime;
+use std::borrow::Cow;
+
+use crate::exchange::split_pair;
+use crate::{denormalize_expiry, parse_base, ContractSpec, ContractStyle, Currency, Decimal, Exchange, ExchangeHandler, Expiry, Instrument, InstrumentError, InstrumentResult, InstrumentType, MarketType, OptionKind, Strike};
+
+/// Quote of COIN-M contracts, which are inverse and settled in the base currency
+const COIN_MARGINED_QUOTE: &str = "USD";
+/// Quotes of USDⓈ-M contracts, which are linear and settled in the quote currency
+const USD_MARGINED_QUOTES: [&str; 2] = ["USDT", "USDC"];
+/// European options are quoted and settled in USDT
+const OPTIONS_QUOTE_CURRENCY: &str = "USDT";
+const DEFAULT_EXPIRY_FORMAT: &str = "%y%m%d";
+const STRIKE_DECIMAL_SEPARATOR: char = '.';
+/// Suffix of COIN-M perpetuals (e.g., BTCUSD_PERP)
+const COIN_MARGINED_PERPETUAL_SUFFIX: &str = "PERP";
+/// Face value in USD of BTC COIN-M contracts (other currencies use 10 USD)
+const BTC_INVERSE_CONTRACT_SIZE: u64 = 100;
+const INVERSE_CONTRACT_SIZE: u64 = 10;
+/// Expiring contracts settle at 08:00 UTC
+const SETTLEMENT_TIME: NaiveTime = NaiveTime::from_hms_opt(8, 0, 0).unwrap();
+
+/// Binance sub-markets. The same symbol means different things depending on the
+/// sub-market (e.g., BTCUSDT is a spot pair on Spot and a perpetual on USDⓈ-M).
+#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash)]
+pub enum BinanceMarket {
+    Spot,
+    /// USDⓈ-M futures and perpetuals, linear in USDT or USDC
+    UsdM,
+    /// COIN-M futures and perpetuals, inverse in the base currency
+    CoinM,
+    /// European options
+    Options,
+}
+
+/// Handler for Binance spot pairs, USDⓈ-M and COIN-M contracts and European options.
+///
+/// `Exchange::Binance.handler()` only accepts names that are unambiguous across sub-markets
+/// and rejects bare symbols such as BTCUSDT; normalize those through `BinanceHandler::for_market`.
+pub struct BinanceHandler {
+    /// Sub-market the instrument names come from, or None to accept every unambiguous name
+    market: Option<BinanceMarket>,
+}
+
+impl BinanceHandler {
+    pub const fn new(market: Option<BinanceMarket>) -> Self {
+        Self { market }
+    }
+
+    /// Returns the handler restricted to `market`
+    pub fn for_market(market: BinanceMarket) -> &'static BinanceHandler {
+        match market {
+            BinanceMarket::Spot => &BINANCE_SPOT_HANDLER,
+            BinanceMarket::UsdM => &BINANCE_USDM_HANDLER,
+            BinanceMarket::CoinM => &BINANCE_COINM_HANDLER,
+            BinanceMarket::Options => &BINANCE_OPTIONS_HANDLER,
+        }
+    }
+
+    /// Sub-market the handler is restricted to, if any
+    pub fn market(&self) -> Option<BinanceMarket> {
+        self.market
+    }
+
+    fn accepts(&self, market: BinanceMarket) -> bool {
+        self.market.is_none_or(|m| m == market)
+    }
+
+    /// Futures sub-market of contracts quoted in `quote`, or None if none is listed
+    fn contract_market(quote: &str) -> Option<BinanceMarket> {
+        if quote.eq_ignore_ascii_case(COIN_MARGINED_QUOTE) {
+            Some(BinanceMarket::CoinM)
+        } else if USD_MARGINED_QUOTES.iter().any(|listed| listed.eq_ignore_ascii_case(quote)) {
+            Some(BinanceMarket::UsdM)
+        } else {
+            None
+        }
+    }
+
+    /// Sub-market an instrument type is listed on, or None if Binance does not list it
+    fn market_of(instrument_type: &InstrumentType) -> Option<BinanceMarket> {
+        match instrument_type {
+            InstrumentType::Spot { .. } => Some(BinanceMarket::Spot),
+            InstrumentType::Future { quote, .. } | InstrumentType::Perpetual { quote, .. } => {
+                Self::contract_market(quote.as_ref())
+            },
+            InstrumentType::Option { .. } => Some(BinanceMarket::Options),
+            _ => None,
+        }
+    }
+
+    /// Parses a `YYMMDD` expiry
+    fn parse_expiry(&self, expiry: &str) -> InstrumentResult<Expiry> {
+        Expiry::parse(expiry, DEFAULT_EXPIRY_FORMAT, self.settlement_time())
+            .ok_or_else(|| InstrumentError::invalid_expiry(Exchange::Binance, expiry))
+    }
+
+    /// Splits a concatenated symbol (e.g., BTCUSDT) into base and quote
+    fn split_symbol(&self, instrument_name: &str, symbol: &str) -> InstrumentResult<(Currency, Currency)> {
+        let (base, quote) = split_pair(symbol)
+            .ok_or_else(|| InstrumentError::unrecognized(Exchange::Binance, instrument_name))?;
+        Ok((parse_base(Exchange::Binance, base)?, Currency::new(Cow::Owned(quote.to_string()))))
+    }
+
+    /// Normalizes a futures symbol with a suffix: an expiry (e.g., BTCUSDT_250328) or PERP for COIN-M perpetuals
+    fn normalize_contract(&self, instrument_name: &str, pair: &str, suffix: &str) -> InstrumentResult<InstrumentType> {
+        let (base, quote) = self.split_symbol(instrument_name, pair)?;
+        let market = Self::contract_market(quote.as_ref())
+            .filter(|market| self.accepts(*market))
+            .ok_or_else(|| InstrumentError::unrecognized(Exchange::Binance, instrument_name))?;
+        let coin_margined = market == BinanceMarket::CoinM;
+
+        if suffix.eq_ignore_ascii_case(COIN_MARGINED_PERPETUAL_SUFFIX) && coin_margined {
+            Ok(InstrumentType::Perpetual { base, quote })
+        } else {
+            Ok(InstrumentType::Future { base, quote, expiry: self.parse_expiry(suffix)? })
+        }
+    }
+
+    /// Normalizes a bare symbol, which is a spot pair on Spot and a perpetual on USDⓈ-M
+    fn normalize_pair(&self, instrument_name: &str, symbol: &str) -> InstrumentResult<InstrumentType> {
+        let (base, quote) = self.split_symbol(instrument_name, symbol)?;
+
+        match self.market {
+            Some(BinanceMarket::Spot) => Ok(InstrumentType::Spot { base, quote }),
+            Some(BinanceMarket::UsdM) if Self::contract_market(quote.as_ref()) == Some(BinanceMarket::UsdM) => {
+                Ok(InstrumentType::Perpetual { base, quote })
+            },
+            None => Err(InstrumentError::AmbiguousInstrument { exchange: Exchange::Binance, value: instrument_name.to_string() }),
+            _ => Err(InstrumentError::unrecognized(Exchange::Binance, instrument_name)),
+        }
+    }
+}
+
+// Create static instances to avoid allocations
+/// Accepts every name that is unambiguous across sub-markets
+pub static BINANCE_HANDLER: BinanceHandler = BinanceHandler::new(None);
+pub static BINANCE_SPOT_HANDLER: BinanceHandler = BinanceHandler::new(Some(BinanceMarket::Spot));
+pub static BINANCE_USDM_HANDLER: BinanceHandler = BinanceHandler::new(Some(BinanceMarket::UsdM));
+pub static BINANCE_COINM_HANDLER: BinanceHandler = BinanceHandler::new(Some(BinanceMarket::CoinM));
+pub static BINANCE_OPTIONS_HANDLER: BinanceHandler = BinanceHandler::new(Some(BinanceMarket::Options));
+
+impl ExchangeHandler for BinanceHandler {
+    fn try_normalize(&self, market_type: MarketType, instrument_name: &str) -> InstrumentResult<Instrument> {
+        let parts: Vec<&str> = instrument_name.split('-').collect();
+
+        let instrument_type = match parts.as_slice() {
+            // Option: e.g., BTC-250328-100000-C
+            [base, expiry_str, strike_str, kind_str] if self.accepts(BinanceMarket::Options) => {
+                let base = parse_base(Exchange::Binance, base)?;
+                let expiry = self.parse_expiry(expiry_str)?;
+
+                let strike = Strike::parse_with_separator(strike_str, STRIKE_DECIMAL_SEPARATOR)
+                    .ok_or_else(|| InstrumentError::invalid_strike(Exchange::Binance, strike_str))?;
+
+                let kind = OptionKind::try_from(*kind_str)
+                    .map_err(|_| InstrumentError::invalid_option_kind(Exchange::Binance, kind_str))?;
+
+                InstrumentType::Option {
+                    base,
+                    quote: Currency::new(Cow::Borrowed(OPTIONS_QUOTE_CURRENCY)),
+                    expiry,
+                    strike,
+                    kind,
+                }
+            },
+
+            // Futures and COIN-M perpetuals (e.g., BTCUSDT_250328, BTCUSD_PERP), spot pairs
+            // and USDⓈ-M perpetuals (e.g., BTCUSDT)
+            [symbol] => match symbol.split_once('_') {
+                Some((pair, suffix)) => self.normalize_contract(instrument_name, pair, suffix)?,
+                None => self.normalize_pair(instrument_name, symbol)?,
+            },
+
+            // No matching format
+            _ => return Err(InstrumentError::unrecognized(Exchange::Binance, instrument_name)),
+        };
+
+        Ok(Instrument::new(Exchange::Binance, market_type, instrument_type))
+    }
+
+    fn try_denormalize(&self, instrument: &Instrument) -> InstrumentResult<String> {
+        // Check if this is the right exchange handler
+        if instrument.exchange != Exchange::Binance {
+            return Err(InstrumentError::ExchangeMismatch { expected: Exchange::Binance, found: instrument.exchange });
+        }
+
+        if !self.supports_instrument_type(&instrument.instrument_type) {
+            return Err(InstrumentError::unsupported_instrument_type(Exchange::Binance, &instrument.instrument_type));
+        }
+
+        match &instrument.instrument_type {
+            InstrumentType::Spot { base, quote } => Ok(format!("{}{}", base.as_ref(), quote.as_ref())),
+
+            InstrumentType::Perpetual { base, quote } if quote.as_ref() == COIN_MARGINED_QUOTE => {
+                Ok(format!("{}{}_{}", base.as_ref(), quote.as_ref(), COIN_MARGINED_PERPETUAL_SUFFIX))
+            },
+
+            InstrumentType::Perpetual { base, quote } => Ok(format!("{}{}", base.as_ref(), quote.as_ref())),
+
+            InstrumentType::Future { base, quote, expiry } => {
+                Ok(format!("{}{}_{}", base.as_ref(), quote.as_ref(), denormalize_expiry(expiry, DEFAULT_EXPIRY_FORMAT)))
+            },
+
+            InstrumentType::Option { base, quote, expiry, strike, kind } => {
+                if !quote.as_ref().eq_ignore_ascii_case(OPTIONS_QUOTE_CURRENCY) {
+                    return Err(InstrumentError::unsupported_instrument_type(Exchange::Binance, &instrument.instrument_type));
+                }
+
+                Ok(format!("{}-{}-{}-{}",
+                    base.as_ref(),
+                    denormalize_expiry(expiry, DEFAULT_EXPIRY_FORMAT),
+                    strike.format_with_separator(STRIKE_DECIMAL_SEPARATOR),
+                    kind))
+            },
+
+            _ => Err(InstrumentError::unsupported_instrument_type(Exchange::Binance, &instrument.instrument_type)),
+        }
+    }
+
+    fn supports_instrument_type(&self, instrument_type: &InstrumentType) -> bool {
+        Self::market_of(instrument_type).is_some_and(|market| self.accepts(market))
+    }
+
+    fn settlement_time(&self) -> NaiveTime {
+        SETTLEMENT_TIME
+    }
+
+    fn contract_style(&self, instrument_type: &InstrumentType) -> ContractStyle {
+        // COIN-M contracts are inverse
+        match Self::market_of(instrument_type) {
+            Some(BinanceMarket::CoinM) => ContractStyle::Inverse,
+            _ => ContractStyle::Linear,
+        }
+    }
+
+    fn contract_spec(&self, instrument: &Instrument) -> InstrumentResult<ContractSpec> {
+        let instrument_type = &instrument.instrument_type;
+        let base = Currency::new(Cow::Owned(instrument_type.base().to_string()));
+        let quote = Currency::new(Cow::Owned(instrument_type.quote().to_string()));
+
+        Ok(match (instrument.contract_style(), Self::market_of(instrument_type)) {
+            // Inverse contracts are sized in USD
+            (ContractStyle::Inverse, _) => {
+                let face_value = if base.as_ref() == "BTC" { BTC_INVERSE_CONTRACT_SIZE } else { INVERSE_CONTRACT_SIZE };
+                ContractSpec::inverse(base, Decimal::from(face_value))
+            },
+            (_, Some(BinanceMarket::Options)) => {
+                ContractSpec::linear(Currency::new(Cow::Borrowed(OPTIONS_QUOTE_CURRENCY)), Decimal::from(1))
+            },
+            _ => ContractSpec::linear(quote, Decimal::from(1)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod binance_normalize_tests {
+    use std::borrow::Cow;
+
+    use chrono::NaiveDate;
+
+    use super::{SETTLEMENT_TIME, BINANCE_COINM_HANDLER, BINANCE_HANDLER, BINANCE_OPTIONS_HANDLER, BINANCE_SPOT_HANDLER, BINANCE_USDM_HANDLER};
+    use crate::{ContractSpec, Currency, Decimal, Exchange, ExchangeHandler, Expiry, Instrument, InstrumentError, InstrumentType, MarketType, OptionKind, Strike};
+
+    #[test]
+    fn test_normalize_spot_and_usdm_perpetual() {
+        let spot = Instrument::new(
+            Exchange::Binance,
+            MarketType::OrderBook,
+            InstrumentType::Spot {
+                base: Currency::new(Cow::Borrowed("BTC")),
+                quote: Currency::new(Cow::Borrowed("USDT")),
+            });
+        let perpetual = Instrument::new(
+            Exchange::Binance,
+            MarketType::OrderBook,
+            InstrumentType::Perpetual {
+                base: Currency::new(Cow::Borrowed("BTC")),
+                quote: Currency::new(Cow::Borrowed("USDT")),
+            });
+        assert_eq!(BINANCE_SPOT_HANDLER.normalize(MarketType::OrderBook, "BTCUSDT"), Some(spot.clone()));
+        assert_eq!(BINANCE_USDM_HANDLER.normalize(MarketType::OrderBook, "BTCUSDT"), Some(perpetual.clone()));
+        // Streams use lowercase symbols
+        assert_eq!(BINANCE_SPOT_HANDLER.normalize(MarketType::OrderBook, "btcusdt"), Some(spot.clone()));
+
+        assert_eq!(BINANCE_HANDLER.denormalize(&spot), Some(String::from("BTCUSDT")));
+        assert_eq!(BINANCE_HANDLER.denormalize(&perpetual), Some(String::from("BTCUSDT")));
+    }
+
+    #[test]
+    fn test_normalize_ambiguous_symbol() {
+        assert!(matches!(
+            BINANCE_HANDLER.try_normalize(MarketType::OrderBook, "BTCUSDT"),
+            Err(InstrumentError::AmbiguousInstrument { exchange: Exchange::Binance, .. })
+        ));
+    }
+
+    #[test]
+    fn test_normalize_usdm_future() {
+        let expected_instrument = Instrument::new(
+            Exchange::Binance,
+            MarketType::OrderBook,
+            InstrumentType::Future {
+                base: Currency::new(Cow::Borrowed("BTC")),
+                quote: Currency::new(Cow::Borrowed("USDT")),
+                expiry: Expiry::new(NaiveDate::from_ymd_opt(2025, 3, 28).unwrap(), SETTLEMENT_TIME),
+            });
+        assert_eq!(BINANCE_HANDLER.normalize(MarketType::OrderBook, "BTCUSDT_250328"), Some(expected_instrument.clone()));
+        assert_eq!(BINANCE_USDM_HANDLER.normalize(MarketType::OrderBook, "BTCUSDT_250328"), Some(expected_instrument.clone()));
+        assert_eq!(BINANCE_COINM_HANDLER.normalize(MarketType::OrderBook, "BTCUSDT_250328"), None);
+        assert_eq!(expected_instrument.contract_spec().unwrap(), ContractSpec::linear(Currency::new(Cow::Borrowed("USDT")), Decimal::from(1)));
+    }
+
+    #[test]
+    fn test_normalize_coinm() {
+        let perpetual = Instrument::new(
+            Exchange::Binance,
+            MarketType::OrderBook,
+            InstrumentType::Perpetual {
+                base: Currency::new(Cow::Borrowed("ETH")),
+                quote: Currency::new(Cow::Borrowed("USD")),
+            });
+        assert_eq!(BINANCE_HANDLER.normalize(MarketType::OrderBook, "ETHUSD_PERP"), Some(perpetual.clone()));
+        assert_eq!(BINANCE_COINM_HANDLER.normalize(MarketType::OrderBook, "ETHUSD_PERP"), Some(perpetual.clone()));
+        assert_eq!(BINANCE_USDM_HANDLER.normalize(MarketType::OrderBook, "ETHUSD_PERP"), None);
+        assert_eq!(perpetual.contract_spec().unwrap(), ContractSpec::inverse(Currency::new(Cow::Borrowed("ETH")), Decimal::from(10)));
+
+        let future = BINANCE_HANDLER.normalize(MarketType::OrderBook, "BTCUSD_250328").unwrap();
+        assert_eq!(future.instrument_type, InstrumentType::Future {
+            base: Currency::new(Cow::Borrowed("BTC")),
+            quote: Currency::new(Cow::Borrowed("USD")),
+            expiry: Expiry::new(NaiveDate::from_ymd_opt(2025, 3, 28).unwrap(), SETTLEMENT_TIME),
+        });
+        assert_eq!(future.contract_spec().unwrap(), ContractSpec::inverse(Currency::new(Cow::Borrowed("BTC")), Decimal::from(100)));
+    }
+
+    #[test]
+    fn test_normalize_option() {
+        let expected_instrument = Instrument::new(
+            Exchange::Binance,
+            MarketType::OrderBook,
+            InstrumentType::Option {
+                base: Currency::new(Cow::Borrowed("BTC")),
+                quote: Currency::new(Cow::Borrowed("USDT")),
+                expiry: Expiry::new(NaiveDate::from_ymd_opt(2025, 3, 28).unwrap(), SETTLEMENT_TIME),
+                strike: Strike::from(100000),
+                kind: OptionKind::Call,
+            });
+        assert_eq!(BINANCE_HANDLER.normalize(MarketType::OrderBook, "BTC-250328-100000-C"), Some(expected_instrument.clone()));
+        assert_eq!(BINANCE_OPTIONS_HANDLER.normalize(MarketType::OrderBook, "BTC-250328-100000-C"), Some(expected_instrument));
+        assert_eq!(BINANCE_SPOT_HANDLER.normalize(MarketType::OrderBook, "BTC-250328-100000-C"), None);
+    }
+
+    #[test]
+    fn test_normalize_unknown() {
+        assert_eq!(BINANCE_SPOT_HANDLER.normalize(MarketType::OrderBook, "BTCXYZ"), None);
+        assert_eq!(BINANCE_HANDLER.normalize(MarketType::OrderBook, "BTCUSDT_PERP"), None);
+        // USDⓈ-M contracts are only quoted in USDT and USDC
+        assert_eq!(BINANCE_HANDLER.normalize(MarketType::OrderBook, "ETHBTC_250328"), None);
+        assert_eq!(BINANCE_HANDLER.normalize(MarketType::OrderBook, "BTCEUR_250328"), None);
+        assert_eq!(BINANCE_USDM_HANDLER.normalize(MarketType::OrderBook, "BTCETH"), None);
+        assert!(BINANCE_SPOT_HANDLER.normalize(MarketType::OrderBook, "BTCETH").is_some());
+        assert!(matches!(
+            BINANCE_HANDLER.try_normalize(MarketType::OrderBook, "BTCUSDT_250230"),
+            Err(InstrumentError::InvalidExpiry { exchange: Exchange::Binance, .. })
+        ));
+    }
+}
+
+#[cfg(test)]
+mod binance_denormalize_tests {
+    use std::borrow::Cow;
+
+    use chrono::NaiveDate;
+
+    use super::{BinanceHandler, BinanceMarket, SETTLEMENT_TIME, BINANCE_COINM_HANDLER, BINANCE_HANDLER, BINANCE_SPOT_HANDLER};
+    use crate::{Currency, Exchange, ExchangeHandler, Expiry, Instrument, InstrumentError, InstrumentType, MarketType, OptionKind, Strike};
+
+    #[test]
+    fn test_denorm_option_fractional_strike() {
+        let instrument = Instrument::new(
+            Exchange::Binance,
+            MarketType::OrderBook,
+            InstrumentType::Option {
+                base: Currency::new(Cow::Borrowed("DOGE")),
+                quote: Currency::new(Cow::Borrowed("USDT")),
+                expiry: Expiry::new(NaiveDate::from_ymd_opt(2025, 3, 28).unwrap(), SETTLEMENT_TIME),
+                strike: Strike::new(15, 2).unwrap(),
+                kind: OptionKind::Put,
+            });
+        assert_eq!(BINANCE_HANDLER.denormalize(&instrument), Some(String::from("DOGE-250328-0.15-P")));
+    }
+
+    #[test]
+    fn test_denorm_option_non_usdt_quote() {
+        let instrument = Instrument::new(
+            Exchange::Binance,
+            MarketType::OrderBook,
+            InstrumentType::Option {
+                base: Currency::new(Cow::Borrowed("BTC")),
+                quote: Currency::new(Cow::Borrowed("USDC")),
+                expiry: Expiry::new(NaiveDate::from_ymd_opt(2025, 3, 28).unwrap(), SETTLEMENT_TIME),
+                strike: Strike::new(100000, 0).unwrap(),
+                kind: OptionKind::Call,
+            });
+        assert!(matches!(
+            BINANCE_HANDLER.try_denormalize(&instrument),
+            Err(InstrumentError::UnsupportedInstrumentType { exchange: Exchange::Binance, .. })
+        ));
+    }
+
+    #[test]
+    fn test_sub_market_handler() {
+        let spot = BinanceHandler::for_market(BinanceMarket::Spot).normalize(MarketType::OrderBook, "BTCUSDT").unwrap();
+        assert!(matches!(spot.instrument_type, InstrumentType::Spot { .. }));
+        assert_eq!(BinanceHandler::for_market(BinanceMarket::Options).market(), Some(BinanceMarket::Options));
+        assert_eq!(Exchange::Binance.handler().normalize(MarketType::OrderBook, "BTCUSDT"), None);
+    }
+
+    #[test]
+    fn test_denorm_outside_sub_market() {
+        let instrument = Instrument::new(
+            Exchange::Binance,
+            MarketType::OrderBook,
+            InstrumentType::Perpetual {
+                base: Currency::new(Cow::Borrowed("BTC")),
+                quote: Currency::new(Cow::Borrowed("USD")),
+            });
+        assert_eq!(BINANCE_COINM_HANDLER.denormalize(&instrument), Some(String::from("BTCUSD_PERP")));
+        assert_eq!(BINANCE_SPOT_HANDLER.denormalize(&instrument), None);
+    }
+
+    #[test]
+    fn test_denorm_unlisted_contract_quote() {
+        let instrument = Instrument::new(
+            Exchange::Binance,
+            MarketType::OrderBook,
+            InstrumentType::Perpetual {
+                base: Currency::new(Cow::Borrowed("ETH")),
+                quote: Currency::new(Cow::Borrowed("BTC")),
+            });
+        assert!(matches!(
+            BINANCE_HANDLER.try_denormalize(&instrument),
+            Err(InstrumentError::UnsupportedInstrumentType { exchange: Exchange::Binance, .. })
+        ));
+    }
+}
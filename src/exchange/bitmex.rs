@@ -0,0 +1,259 @@
+use chrono::NaiveTime;
+use std::borrow::Cow;
+
+use crate::exchange::{denormalize_xbt, normalize_xbt, strip_suffix_ignore_case};
+use crate::{denormalize_month_code_expiry, normalize_month_code_expiry, parse_base, split_month_code, ContractSpec, ContractStyle, Currency, Decimal, Exchange, ExchangeHandler, Expiry, Instrument, InstrumentError, InstrumentResult, InstrumentType, MarketType};
+
+/// Quote currencies of BitMEX symbols, checked in order (e.g., XBTUSDT before XBTUSD)
+const QUOTE_CURRENCIES: [&str; 4] = ["USDT", "USDC", "USD", "EUR"];
+/// Quote of inverse contracts on XBT (e.g., XBTUSD), and of USD quanto contracts on other bases (e.g., ETHUSD)
+const USD_QUOTE_CURRENCY: &str = "USD";
+/// Base of the inverse contracts, which are margined and settled in XBT
+const INVERSE_BASE_CURRENCY: &str = "BTC";
+/// Quanto contracts pay 0.000001 XBT per USD of price movement
+const QUANTO_MULTIPLIER_SCALE: u32 = 6;
+/// Futures expire at 12:00 UTC on the last Friday of their month
+const SETTLEMENT_TIME: NaiveTime = NaiveTime::from_hms_opt(12, 0, 0).unwrap();
+
+/// Handler for BitMEX perpetuals (e.g., XBTUSD, ETHUSDT) and month-coded futures (e.g., XBTH25, ETHUSDTZ25).
+///
+/// Futures without a quote are quoted in USD on XBT (e.g., XBTH25) and in XBT on other bases (e.g., ETHH25).
+pub struct BitmexHandler;
+
+// Create a static instance to avoid allocations
+pub static BITMEX_HANDLER: BitmexHandler = BitmexHandler;
+
+impl BitmexHandler {
+    /// Splits a concatenated pair (e.g., XBTUSD), mapping XBT to BTC
+    fn parse_pair(&self, pair: &str) -> InstrumentResult<(Currency, Option<Currency>)> {
+        match QUOTE_CURRENCIES.iter().find_map(|quote| Some((strip_suffix_ignore_case(pair, quote)?, *quote))) {
+            Some((base, quote)) => Ok((
+                parse_base(Exchange::Bitmex, normalize_xbt(base))?,
+                Some(Currency::new(Cow::Borrowed(quote))),
+            )),
+            None => Ok((parse_base(Exchange::Bitmex, normalize_xbt(pair))?, None)),
+        }
+    }
+
+    /// Splits a month-coded future (e.g., XBTH25) into its pair and expiry
+    fn split_month_code<'a>(&self, instrument_name: &'a str) -> Option<(&'a str, Expiry)> {
+        let (pair, code) = split_month_code(instrument_name)?;
+        Some((pair, normalize_month_code_expiry(code, self.settlement_time())?))
+    }
+
+    /// Checks that BitMEX lists contracts in `quote`
+    fn check_quote(&self, instrument: &Instrument, quote: &Currency) -> InstrumentResult<()> {
+        if QUOTE_CURRENCIES.contains(&quote.as_ref()) {
+            Ok(())
+        } else {
+            Err(InstrumentError::unsupported_instrument_type(Exchange::Bitmex, &instrument.instrument_type))
+        }
+    }
+}
+
+impl ExchangeHandler for BitmexHandler {
+    fn try_normalize(&self, market_type: MarketType, instrument_name: &str) -> InstrumentResult<Instrument> {
+        let instrument_type = match self.split_month_code(instrument_name) {
+            // Future: e.g., XBTH25, ETHH25 or ETHUSDTZ25
+            Some((pair, expiry)) => {
+                let (base, quote) = self.parse_pair(pair)?;
+                let quote = quote.unwrap_or_else(|| if base.as_ref() == INVERSE_BASE_CURRENCY {
+                    Currency::new(Cow::Borrowed(USD_QUOTE_CURRENCY))
+                } else {
+                    Currency::new(Cow::Borrowed(INVERSE_BASE_CURRENCY))
+                });
+                InstrumentType::Future { base, quote, expiry }
+            },
+
+            // Perpetual: e.g., XBTUSD or ETHUSDT
+            None => match self.parse_pair(instrument_name)? {
+                (base, Some(quote)) => InstrumentType::Perpetual { base, quote },
+                _ => return Err(InstrumentError::unrecognized(Exchange::Bitmex, instrument_name)),
+            },
+        };
+
+        Ok(Instrument::new(Exchange::Bitmex, market_type, instrument_type))
+    }
+
+    fn try_denormalize(&self, instrument: &Instrument) -> InstrumentResult<String> {
+        // Check if this is the right exchange handler
+        if instrument.exchange != Exchange::Bitmex {
+            return Err(InstrumentError::ExchangeMismatch { expected: Exchange::Bitmex, found: instrument.exchange });
+        }
+
+        match &instrument.instrument_type {
+            InstrumentType::Perpetual { base, quote } => {
+                self.check_quote(instrument, quote)?;
+                Ok(format!("{}{}", denormalize_xbt(base), quote.as_ref()))
+            },
+
+            InstrumentType::Future { base, quote, expiry } => {
+                let code = denormalize_month_code_expiry(expiry)
+                    .ok_or_else(|| InstrumentError::invalid_expiry(Exchange::Bitmex, &expiry.to_string()))?;
+                // The implied quote is left out (e.g., XBTH25 or ETHH25)
+                let implied = if base.as_ref() == INVERSE_BASE_CURRENCY { USD_QUOTE_CURRENCY } else { INVERSE_BASE_CURRENCY };
+                let quote = if quote.as_ref() == implied {
+                    ""
+                } else {
+                    self.check_quote(instrument, quote)?;
+                    quote.as_ref()
+                };
+                Ok(format!("{}{}{}", denormalize_xbt(base), quote, code))
+            },
+
+            _ => Err(InstrumentError::unsupported_instrument_type(Exchange::Bitmex, &instrument.instrument_type)),
+        }
+    }
+
+    fn supports_instrument_type(&self, instrument_type: &InstrumentType) -> bool {
+        matches!(instrument_type, InstrumentType::Perpetual { .. } | InstrumentType::Future { .. })
+    }
+
+    fn settlement_time(&self) -> NaiveTime {
+        SETTLEMENT_TIME
+    }
+
+    fn contract_style(&self, instrument_type: &InstrumentType) -> ContractStyle {
+        match instrument_type.quote() {
+            // XBT contracts are inverse (e.g., XBTUSD)
+            USD_QUOTE_CURRENCY if instrument_type.base() == INVERSE_BASE_CURRENCY => ContractStyle::Inverse,
+            // USD contracts on other bases are quanto (e.g., ETHUSD)
+            USD_QUOTE_CURRENCY => ContractStyle::Quanto,
+            _ => ContractStyle::Linear,
+        }
+    }
+
+    fn contract_spec(&self, instrument: &Instrument) -> InstrumentResult<ContractSpec> {
+        let quote = Currency::new(Cow::Owned(instrument.instrument_type.quote().to_string()));
+        let xbt = Currency::new(Cow::Borrowed(INVERSE_BASE_CURRENCY));
+
+        Ok(match instrument.contract_style() {
+            // Inverse contracts are sized in 1 USD, settled in XBT
+            ContractStyle::Inverse => ContractSpec::inverse(xbt, Decimal::from(1)),
+            // Quanto contracts are settled in XBT
+            ContractStyle::Quanto => ContractSpec::quanto(xbt, Decimal::new(1, QUANTO_MULTIPLIER_SCALE).unwrap()),
+            ContractStyle::Linear => ContractSpec::linear(quote, Decimal::from(1)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod bitmex_normalize_tests {
+    use std::borrow::Cow;
+
+    use chrono::NaiveDate;
+
+    use super::{BITMEX_HANDLER, SETTLEMENT_TIME};
+    use crate::{ContractStyle, Currency, Exchange, ExchangeHandler, Expiry, Instrument, InstrumentError, InstrumentType, MarketType};
+
+    #[test]
+    fn test_normalize_perpetuals() {
+        let expected_instrument = Instrument::new(
+            Exchange::Bitmex,
+            MarketType::OrderBook,
+            InstrumentType::Perpetual {
+                base: Currency::new(Cow::Borrowed("BTC")),
+                quote: Currency::new(Cow::Borrowed("USD")),
+            });
+        assert_eq!(BITMEX_HANDLER.normalize(MarketType::OrderBook, "XBTUSD"), Some(expected_instrument.clone()));
+        assert_eq!(expected_instrument.contract_spec().unwrap().style, ContractStyle::Inverse);
+
+        let linear = BITMEX_HANDLER.normalize(MarketType::OrderBook, "ETHUSDT").unwrap();
+        assert_eq!(linear.instrument_type, InstrumentType::Perpetual {
+            base: Currency::new(Cow::Borrowed("ETH")),
+            quote: Currency::new(Cow::Borrowed("USDT")),
+        });
+        assert_eq!(linear.contract_spec().unwrap().style, ContractStyle::Linear);
+
+        let quanto = BITMEX_HANDLER.normalize(MarketType::OrderBook, "ETHUSD").unwrap();
+        assert_eq!(quanto.contract_spec().unwrap().style, ContractStyle::Quanto);
+        assert_eq!(quanto.contract_spec().unwrap().settlement.as_ref(), "BTC");
+    }
+
+    #[test]
+    fn test_normalize_month_code_futures() {
+        let expected_instrument = Instrument::new(
+            Exchange::Bitmex,
+            MarketType::OrderBook,
+            InstrumentType::Future {
+                base: Currency::new(Cow::Borrowed("BTC")),
+                quote: Currency::new(Cow::Borrowed("USD")),
+                expiry: Expiry::new(NaiveDate::from_ymd_opt(2025, 3, 28).unwrap(), SETTLEMENT_TIME),
+            });
+        assert_eq!(BITMEX_HANDLER.normalize(MarketType::OrderBook, "XBTH25"), Some(expected_instrument));
+
+        let instrument = BITMEX_HANDLER.normalize(MarketType::OrderBook, "ETHUSDTZ25").unwrap();
+        assert_eq!(instrument.instrument_type, InstrumentType::Future {
+            base: Currency::new(Cow::Borrowed("ETH")),
+            quote: Currency::new(Cow::Borrowed("USDT")),
+            expiry: Expiry::new(NaiveDate::from_ymd_opt(2025, 12, 26).unwrap(), SETTLEMENT_TIME),
+        });
+
+        // Futures on other bases are quoted in XBT
+        let instrument = BITMEX_HANDLER.normalize(MarketType::OrderBook, "ETHH25").unwrap();
+        assert_eq!(instrument.instrument_type.quote().to_string(), "BTC");
+    }
+
+    #[test]
+    fn test_normalize_unknown() {
+        assert_eq!(BITMEX_HANDLER.normalize(MarketType::OrderBook, "XBT"), None);
+        assert_eq!(BITMEX_HANDLER.normalize(MarketType::OrderBook, "XBT-PERP"), None);
+        assert!(matches!(
+            BITMEX_HANDLER.try_normalize(MarketType::OrderBook, "XBTUSDA25"),
+            Err(InstrumentError::UnrecognizedInstrument { exchange: Exchange::Bitmex, .. })
+        ));
+    }
+}
+
+#[cfg(test)]
+mod bitmex_denormalize_tests {
+    use std::borrow::Cow;
+
+    use chrono::NaiveDate;
+
+    use super::{BITMEX_HANDLER, SETTLEMENT_TIME};
+    use crate::{Currency, Exchange, ExchangeHandler, Expiry, Instrument, InstrumentError, InstrumentType, MarketType};
+
+    fn future(base: &'static str, quote: &'static str, date: NaiveDate) -> Instrument {
+        Instrument::new(
+            Exchange::Bitmex,
+            MarketType::OrderBook,
+            InstrumentType::Future {
+                base: Currency::new(Cow::Borrowed(base)),
+                quote: Currency::new(Cow::Borrowed(quote)),
+                expiry: Expiry::new(date, SETTLEMENT_TIME),
+            })
+    }
+
+    #[test]
+    fn test_denorm_futures() {
+        let march = NaiveDate::from_ymd_opt(2025, 3, 28).unwrap();
+        assert_eq!(BITMEX_HANDLER.denormalize(&future("BTC", "USD", march)), Some(String::from("XBTH25")));
+        assert_eq!(BITMEX_HANDLER.denormalize(&future("ETH", "BTC", march)), Some(String::from("ETHH25")));
+        assert_eq!(BITMEX_HANDLER.denormalize(&future("BTC", "USDT", march)), Some(String::from("XBTUSDTH25")));
+        assert_eq!(BITMEX_HANDLER.denormalize(&future("ETH", "USD", march)), Some(String::from("ETHUSDH25")));
+        assert_eq!(BITMEX_HANDLER.denormalize(&future("ETH", "DAI", march)), None);
+    }
+
+    #[test]
+    fn test_denorm_unlisted_perpetual_quote() {
+        let instrument = Instrument::new(
+            Exchange::Bitmex,
+            MarketType::OrderBook,
+            InstrumentType::Perpetual {
+                base: Currency::new(Cow::Borrowed("ETH")),
+                quote: Currency::new(Cow::Borrowed("BTC")),
+            });
+        assert!(matches!(
+            BITMEX_HANDLER.try_denormalize(&instrument),
+            Err(InstrumentError::UnsupportedInstrumentType { exchange: Exchange::Bitmex, .. })
+        ));
+    }
+
+    #[test]
+    fn test_denorm_off_cycle_expiry() {
+        // Only last-Friday expiries have a month code
+        let instrument = future("BTC", "USD", NaiveDate::from_ymd_opt(2025, 3, 21).unwrap());
+        assert_eq!(BITMEX_HANDLER.denormalize(&instrument), None);
+    }
+}
@@ -0,0 +1,403 @@
+use chrono::NaiveTime;
+use std::borrow::Cow;
+
+use crate::exchange::{split_pair, strip_suffix_ignore_case};
+use crate::{denormalize_expiry, denormalize_month_code_expiry, normalize_expiry, normalize_month_code_expiry, parse_base, parse_expiry_date, split_month_code, ContractStyle, Currency, Exchange, ExchangeHandler, Expiry, Instrument, InstrumentError, InstrumentResult, InstrumentType, MarketType, OptionKind, Strike};
+
+/// Quote of inverse contracts, which are margined and settled in the base currency
+const INVERSE_QUOTE_CURRENCY: &str = "USD";
+/// Quote of USDC perpetuals (e.g., BTCPERP), dated futures (e.g., BTC-28MAR25) and options
+const USDC_QUOTE_CURRENCY: &str = "USDC";
+/// Options settled in USDT carry their quote as a suffix (e.g., BTC-28MAR25-100000-C-USDT)
+const USDT_QUOTE_CURRENCY: &str = "USDT";
+/// Suffix of USDC perpetuals (e.g., BTCPERP)
+const USDC_PERPETUAL_SUFFIX: &str = "PERP";
+const DEFAULT_EXPIRY_FORMAT: &str = "%d%b%y";
+const STRIKE_DECIMAL_SEPARATOR: char = '.';
+/// Expiring contracts settle at 08:00 UTC
+const SETTLEMENT_TIME: NaiveTime = NaiveTime::from_hms_opt(8, 0, 0).unwrap();
+
+/// Bybit product categories, as used by its v5 API. Symbols are only unique within
+/// a category (e.g., BTCUSDT is both a spot pair and a linear perpetual).
+#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash)]
+pub enum BybitCategory {
+    Spot,
+    /// USDT and USDC perpetuals and dated futures
+    Linear,
+    /// Inverse perpetuals and futures, margined in the base currency
+    Inverse,
+    Option,
+}
+
+impl TryFrom<&str> for BybitCategory {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.trim() {
+            s if s.eq_ignore_ascii_case("spot") => Ok(BybitCategory::Spot),
+            s if s.eq_ignore_ascii_case("linear") => Ok(BybitCategory::Linear),
+            s if s.eq_ignore_ascii_case("inverse") => Ok(BybitCategory::Inverse),
+            s if s.eq_ignore_ascii_case("option") => Ok(BybitCategory::Option),
+            _ => Err(format!("Invalid Bybit category: {}", value)),
+        }
+    }
+}
+
+pub struct BybitHandler {
+    /// Category the symbols come from, or None to accept only symbols that are unique across categories
+    category: Option<BybitCategory>,
+}
+
+impl BybitHandler {
+    pub const fn new(category: Option<BybitCategory>) -> Self {
+        Self { category }
+    }
+
+    /// Returns the handler restricted to `category`
+    pub fn for_category(category: BybitCategory) -> &'static BybitHandler {
+        match category {
+            BybitCategory::Spot => &BYBIT_SPOT_HANDLER,
+            BybitCategory::Linear => &BYBIT_LINEAR_HANDLER,
+            BybitCategory::Inverse => &BYBIT_INVERSE_HANDLER,
+            BybitCategory::Option => &BYBIT_OPTION_HANDLER,
+        }
+    }
+
+    /// Category the handler is restricted to, if any
+    pub fn category(&self) -> Option<BybitCategory> {
+        self.category
+    }
+
+    fn accepts(&self, category: BybitCategory) -> bool {
+        self.category.is_none_or(|c| c == category)
+    }
+
+    /// Category an instrument type is listed in, or None if Bybit does not list it
+    fn category_of(instrument_type: &InstrumentType) -> Option<BybitCategory> {
+        match instrument_type {
+            InstrumentType::Spot { quote, .. } if quote.as_ref() != INVERSE_QUOTE_CURRENCY => Some(BybitCategory::Spot),
+            InstrumentType::Perpetual { quote, .. } if quote.as_ref() == INVERSE_QUOTE_CURRENCY => Some(BybitCategory::Inverse),
+            InstrumentType::Perpetual { .. } => Some(BybitCategory::Linear),
+            InstrumentType::Future { quote, .. } if quote.as_ref() == INVERSE_QUOTE_CURRENCY => Some(BybitCategory::Inverse),
+            InstrumentType::Future { quote, .. } if quote.as_ref() == USDC_QUOTE_CURRENCY => Some(BybitCategory::Linear),
+            InstrumentType::Option { quote, .. }
+                if quote.as_ref() == USDC_QUOTE_CURRENCY || quote.as_ref() == USDT_QUOTE_CURRENCY => Some(BybitCategory::Option),
+            _ => None,
+        }
+    }
+
+    /// Parses a `DDMMMYY` expiry
+    fn parse_expiry(&self, expiry: &str) -> InstrumentResult<Expiry> {
+        parse_expiry_date(expiry, DEFAULT_EXPIRY_FORMAT)
+            .and_then(|_| normalize_expiry(expiry, self.settlement_time()))
+            .ok_or_else(|| InstrumentError::invalid_expiry(Exchange::Bybit, expiry))
+    }
+
+    /// Normalizes a symbol without dashes: perpetuals, inverse futures and spot pairs
+    fn normalize_symbol(&self, instrument_name: &str) -> InstrumentResult<InstrumentType> {
+        // USDC perpetual: e.g., BTCPERP
+        if let Some(base) = strip_suffix_ignore_case(instrument_name, USDC_PERPETUAL_SUFFIX) {
+            if self.accepts(BybitCategory::Linear) {
+                return Ok(InstrumentType::Perpetual {
+                    base: parse_base(Exchange::Bybit, base)?,
+                    quote: Currency::new(Cow::Borrowed(USDC_QUOTE_CURRENCY)),
+                });
+            }
+        }
+
+        // Inverse future: e.g., BTCUSDH25
+        let inverse_future = split_month_code(instrument_name)
+            .and_then(|(pair, code)| Some((
+                strip_suffix_ignore_case(pair, INVERSE_QUOTE_CURRENCY)?,
+                normalize_month_code_expiry(code, self.settlement_time())?,
+            )));
+        if let Some((base, expiry)) = inverse_future.filter(|_| self.accepts(BybitCategory::Inverse)) {
+            return Ok(InstrumentType::Future {
+                base: parse_base(Exchange::Bybit, base)?,
+                quote: Currency::new(Cow::Borrowed(INVERSE_QUOTE_CURRENCY)),
+                expiry,
+            });
+        }
+
+        // Spot pair, linear or inverse perpetual: e.g., BTCUSDT or BTCUSD
+        let (base, quote) = split_pair(instrument_name)
+            .ok_or_else(|| InstrumentError::unrecognized(Exchange::Bybit, instrument_name))?;
+        let base = parse_base(Exchange::Bybit, base)?;
+        let quote = Currency::new(Cow::Owned(quote.to_string()));
+        let inverse = quote.as_ref() == INVERSE_QUOTE_CURRENCY;
+
+        match self.category {
+            // Spot pairs are never quoted in USD, so USD symbols are only ever inverse perpetuals
+            Some(BybitCategory::Spot) if !inverse => Ok(InstrumentType::Spot { base, quote }),
+            Some(BybitCategory::Linear) if !inverse => Ok(InstrumentType::Perpetual { base, quote }),
+            Some(BybitCategory::Inverse) | None if inverse => Ok(InstrumentType::Perpetual { base, quote }),
+            None => Err(InstrumentError::AmbiguousInstrument { exchange: Exchange::Bybit, value: instrument_name.to_string() }),
+            _ => Err(InstrumentError::unrecognized(Exchange::Bybit, instrument_name)),
+        }
+    }
+}
+
+// Create static instances to avoid allocations
+/// Accepts only symbols that are unique across categories
+pub static BYBIT_HANDLER: BybitHandler = BybitHandler::new(None);
+pub static BYBIT_SPOT_HANDLER: BybitHandler = BybitHandler::new(Some(BybitCategory::Spot));
+pub static BYBIT_LINEAR_HANDLER: BybitHandler = BybitHandler::new(Some(BybitCategory::Linear));
+pub static BYBIT_INVERSE_HANDLER: BybitHandler = BybitHandler::new(Some(BybitCategory::Inverse));
+pub static BYBIT_OPTION_HANDLER: BybitHandler = BybitHandler::new(Some(BybitCategory::Option));
+
+impl ExchangeHandler for BybitHandler {
+    fn try_normalize(&self, market_type: MarketType, instrument_name: &str) -> InstrumentResult<Instrument> {
+        let parts: Vec<&str> = instrument_name.split('-').collect();
+
+        let instrument_type = match parts.as_slice() {
+            // USDC dated future: e.g., BTC-28MAR25
+            [base, expiry_str] if self.accepts(BybitCategory::Linear) => InstrumentType::Future {
+                base: parse_base(Exchange::Bybit, base)?,
+                quote: Currency::new(Cow::Borrowed(USDC_QUOTE_CURRENCY)),
+                expiry: self.parse_expiry(expiry_str)?,
+            },
+
+            // Option: e.g., BTC-28MAR25-100000-C (USDC) or BTC-28MAR25-100000-C-USDT
+            [base, expiry_str, strike_str, kind_str, quote @ ..] if quote.len() <= 1 && self.accepts(BybitCategory::Option) => {
+                let quote = match quote {
+                    [] => USDC_QUOTE_CURRENCY,
+                    [quote] if quote.eq_ignore_ascii_case(USDT_QUOTE_CURRENCY) => USDT_QUOTE_CURRENCY,
+                    _ => return Err(InstrumentError::unrecognized(Exchange::Bybit, instrument_name)),
+                };
+
+                let strike = Strike::parse_with_separator(strike_str, STRIKE_DECIMAL_SEPARATOR)
+                    .ok_or_else(|| InstrumentError::invalid_strike(Exchange::Bybit, strike_str))?;
+
+                let kind = OptionKind::try_from(*kind_str)
+                    .map_err(|_| InstrumentError::invalid_option_kind(Exchange::Bybit, kind_str))?;
+
+                InstrumentType::Option {
+                    base: parse_base(Exchange::Bybit, base)?,
+                    quote: Currency::new(Cow::Borrowed(quote)),
+                    expiry: self.parse_expiry(expiry_str)?,
+                    strike,
+                    kind,
+                }
+            },
+
+            [symbol] => self.normalize_symbol(symbol)?,
+
+            // No matching format
+            _ => return Err(InstrumentError::unrecognized(Exchange::Bybit, instrument_name)),
+        };
+
+        Ok(Instrument::new(Exchange::Bybit, market_type, instrument_type))
+    }
+
+    fn try_denormalize(&self, instrument: &Instrument) -> InstrumentResult<String> {
+        // Check if this is the right exchange handler
+        if instrument.exchange != Exchange::Bybit {
+            return Err(InstrumentError::ExchangeMismatch { expected: Exchange::Bybit, found: instrument.exchange });
+        }
+
+        if !self.supports_instrument_type(&instrument.instrument_type) {
+            return Err(InstrumentError::unsupported_instrument_type(Exchange::Bybit, &instrument.instrument_type));
+        }
+
+        match &instrument.instrument_type {
+            InstrumentType::Spot { base, quote } => Ok(format!("{}{}", base.as_ref(), quote.as_ref())),
+
+            InstrumentType::Perpetual { base, quote } if quote.as_ref() == USDC_QUOTE_CURRENCY => {
+                Ok(format!("{}{}", base.as_ref(), USDC_PERPETUAL_SUFFIX))
+            },
+
+            InstrumentType::Perpetual { base, quote } => Ok(format!("{}{}", base.as_ref(), quote.as_ref())),
+
+            InstrumentType::Future { base, quote, expiry } if quote.as_ref() == INVERSE_QUOTE_CURRENCY => {
+                let code = denormalize_month_code_expiry(expiry)
+                    .ok_or_else(|| InstrumentError::invalid_expiry(Exchange::Bybit, &expiry.to_string()))?;
+                Ok(format!("{}{}{}", base.as_ref(), quote.as_ref(), code))
+            },
+
+            InstrumentType::Future { base, expiry, .. } => {
+                Ok(format!("{}-{}", base.as_ref(), denormalize_expiry(expiry, DEFAULT_EXPIRY_FORMAT)))
+            },
+
+            InstrumentType::Option { base, quote, expiry, strike, kind } => {
+                let name = format!("{}-{}-{}-{}",
+                    base.as_ref(),
+                    denormalize_expiry(expiry, DEFAULT_EXPIRY_FORMAT),
+                    strike.format_with_separator(STRIKE_DECIMAL_SEPARATOR),
+                    kind);
+                if quote.as_ref() == USDT_QUOTE_CURRENCY {
+                    Ok(format!("{}-{}", name, USDT_QUOTE_CURRENCY))
+                } else {
+                    Ok(name)
+                }
+            },
+
+            _ => Err(InstrumentError::unsupported_instrument_type(Exchange::Bybit, &instrument.instrument_type)),
+        }
+    }
+
+    fn supports_instrument_type(&self, instrument_type: &InstrumentType) -> bool {
+        Self::category_of(instrument_type).is_some_and(|category| self.accepts(category))
+    }
+
+    fn settlement_time(&self) -> NaiveTime {
+        SETTLEMENT_TIME
+    }
+
+    fn contract_style(&self, instrument_type: &InstrumentType) -> ContractStyle {
+        // Inverse contracts are sized in 1 USD, which the default contract spec assumes
+        match Self::category_of(instrument_type) {
+            Some(BybitCategory::Inverse) => ContractStyle::Inverse,
+            _ => ContractStyle::Linear,
+        }
+    }
+}
+
+#[cfg(test)]
+mod bybit_normalize_tests {
+    use std::borrow::Cow;
+
+    use chrono::NaiveDate;
+
+    use super::{BybitCategory, BybitHandler, SETTLEMENT_TIME, BYBIT_HANDLER, BYBIT_INVERSE_HANDLER, BYBIT_LINEAR_HANDLER, BYBIT_OPTION_HANDLER, BYBIT_SPOT_HANDLER};
+    use crate::{ContractSpec, Currency, Decimal, Exchange, ExchangeHandler, Expiry, Instrument, InstrumentError, InstrumentType, MarketType, OptionKind, Strike};
+
+    fn perpetual(base: &'static str, quote: &'static str) -> InstrumentType {
+        InstrumentType::Perpetual {
+            base: Currency::new(Cow::Borrowed(base)),
+            quote: Currency::new(Cow::Borrowed(quote)),
+        }
+    }
+
+    #[test]
+    fn test_normalize_by_category() {
+        let spot = BYBIT_SPOT_HANDLER.normalize(MarketType::OrderBook, "BTCUSDT").unwrap();
+        assert_eq!(spot.instrument_type, InstrumentType::Spot {
+            base: Currency::new(Cow::Borrowed("BTC")),
+            quote: Currency::new(Cow::Borrowed("USDT")),
+        });
+
+        let linear = BYBIT_LINEAR_HANDLER.normalize(MarketType::OrderBook, "BTCUSDT").unwrap();
+        assert_eq!(linear.instrument_type, perpetual("BTC", "USDT"));
+        assert_eq!(BybitHandler::for_category(BybitCategory::Linear).category(), Some(BybitCategory::Linear));
+        assert_eq!(BybitCategory::try_from("inverse"), Ok(BybitCategory::Inverse));
+
+        assert!(matches!(
+            BYBIT_HANDLER.try_normalize(MarketType::OrderBook, "BTCUSDT"),
+            Err(InstrumentError::AmbiguousInstrument { exchange: Exchange::Bybit, .. })
+        ));
+    }
+
+    #[test]
+    fn test_normalize_perpetuals() {
+        let usdc = BYBIT_LINEAR_HANDLER.normalize(MarketType::OrderBook, "BTCPERP").unwrap();
+        assert_eq!(usdc.instrument_type, perpetual("BTC", "USDC"));
+        assert_eq!(BYBIT_HANDLER.normalize(MarketType::OrderBook, "ETHPERP").unwrap().instrument_type, perpetual("ETH", "USDC"));
+
+        let inverse = BYBIT_INVERSE_HANDLER.normalize(MarketType::OrderBook, "BTCUSD").unwrap();
+        assert_eq!(inverse.instrument_type, perpetual("BTC", "USD"));
+        assert_eq!(inverse.contract_spec().unwrap(), ContractSpec::inverse(Currency::new(Cow::Borrowed("BTC")), Decimal::from(1)));
+        assert_eq!(BYBIT_LINEAR_HANDLER.normalize(MarketType::OrderBook, "BTCUSD"), None);
+    }
+
+    #[test]
+    fn test_normalize_usd_symbol_by_category() {
+        // USD symbols are inverse perpetuals, never spot pairs or linear perpetuals
+        assert_eq!(BYBIT_HANDLER.normalize(MarketType::OrderBook, "BTCUSD").unwrap().instrument_type, perpetual("BTC", "USD"));
+        assert_eq!(BYBIT_INVERSE_HANDLER.normalize(MarketType::OrderBook, "BTCUSD").unwrap().instrument_type, perpetual("BTC", "USD"));
+        assert_eq!(BYBIT_SPOT_HANDLER.normalize(MarketType::OrderBook, "BTCUSD"), None);
+        assert_eq!(BYBIT_LINEAR_HANDLER.normalize(MarketType::OrderBook, "BTCUSD"), None);
+        assert_eq!(BYBIT_OPTION_HANDLER.normalize(MarketType::OrderBook, "BTCUSD"), None);
+    }
+
+    #[test]
+    fn test_normalize_inverse_future() {
+        let expected_instrument = Instrument::new(
+            Exchange::Bybit,
+            MarketType::OrderBook,
+            InstrumentType::Future {
+                base: Currency::new(Cow::Borrowed("BTC")),
+                quote: Currency::new(Cow::Borrowed("USD")),
+                expiry: Expiry::new(NaiveDate::from_ymd_opt(2025, 3, 28).unwrap(), SETTLEMENT_TIME),
+            });
+        assert_eq!(BYBIT_INVERSE_HANDLER.normalize(MarketType::OrderBook, "BTCUSDH25"), Some(expected_instrument.clone()));
+        assert_eq!(BYBIT_HANDLER.normalize(MarketType::OrderBook, "BTCUSDH25"), Some(expected_instrument));
+        assert_eq!(BYBIT_SPOT_HANDLER.normalize(MarketType::OrderBook, "BTCUSDH25"), None);
+    }
+
+    #[test]
+    fn test_normalize_linear_future() {
+        let expected_instrument = Instrument::new(
+            Exchange::Bybit,
+            MarketType::OrderBook,
+            InstrumentType::Future {
+                base: Currency::new(Cow::Borrowed("BTC")),
+                quote: Currency::new(Cow::Borrowed("USDC")),
+                expiry: Expiry::new(NaiveDate::from_ymd_opt(2025, 3, 28).unwrap(), SETTLEMENT_TIME),
+            });
+        assert_eq!(BYBIT_LINEAR_HANDLER.normalize(MarketType::OrderBook, "BTC-28MAR25"), Some(expected_instrument));
+        assert_eq!(BYBIT_OPTION_HANDLER.normalize(MarketType::OrderBook, "BTC-28MAR25"), None);
+    }
+
+    #[test]
+    fn test_normalize_options() {
+        let usdc = Instrument::new(
+            Exchange::Bybit,
+            MarketType::OrderBook,
+            InstrumentType::Option {
+                base: Currency::new(Cow::Borrowed("BTC")),
+                quote: Currency::new(Cow::Borrowed("USDC")),
+                expiry: Expiry::new(NaiveDate::from_ymd_opt(2025, 3, 28).unwrap(), SETTLEMENT_TIME),
+                strike: Strike::from(100000),
+                kind: OptionKind::Call,
+            });
+        assert_eq!(BYBIT_OPTION_HANDLER.normalize(MarketType::OrderBook, "BTC-28MAR25-100000-C"), Some(usdc));
+
+        let usdt = BYBIT_HANDLER.normalize(MarketType::OrderBook, "BTC-28MAR25-100000-C-USDT").unwrap();
+        assert_eq!(usdt.instrument_type.quote().to_string(), "USDT");
+        assert_eq!(BYBIT_HANDLER.normalize(MarketType::OrderBook, "BTC-28MAR25-100000-C-EUR"), None);
+    }
+}
+
+#[cfg(test)]
+mod bybit_denormalize_tests {
+    use std::borrow::Cow;
+
+    use chrono::NaiveDate;
+
+    use super::{SETTLEMENT_TIME, BYBIT_HANDLER, BYBIT_SPOT_HANDLER};
+    use crate::{Currency, Exchange, ExchangeHandler, Expiry, Instrument, InstrumentError, InstrumentType, MarketType};
+
+    #[test]
+    fn test_denorm_inverse_future() {
+        let mut instrument = Instrument::new(
+            Exchange::Bybit,
+            MarketType::OrderBook,
+            InstrumentType::Future {
+                base: Currency::new(Cow::Borrowed("ETH")),
+                quote: Currency::new(Cow::Borrowed("USD")),
+                expiry: Expiry::new(NaiveDate::from_ymd_opt(2025, 6, 27).unwrap(), SETTLEMENT_TIME),
+            });
+        assert_eq!(BYBIT_HANDLER.denormalize(&instrument), Some(String::from("ETHUSDM25")));
+        assert_eq!(BYBIT_SPOT_HANDLER.denormalize(&instrument), None);
+
+        // Inverse futures only expire on the last Friday of the month
+        instrument.instrument_type = InstrumentType::Future {
+            base: Currency::new(Cow::Borrowed("ETH")),
+            quote: Currency::new(Cow::Borrowed("USD")),
+            expiry: Expiry::new(NaiveDate::from_ymd_opt(2025, 6, 20).unwrap(), SETTLEMENT_TIME),
+        };
+        assert!(matches!(BYBIT_HANDLER.try_denormalize(&instrument), Err(InstrumentError::InvalidExpiry { .. })));
+    }
+
+    #[test]
+    fn test_denorm_usdc_perpetual() {
+        let instrument = Instrument::new(
+            Exchange::Bybit,
+            MarketType::Funding,
+            InstrumentType::Perpetual {
+                base: Currency::new(Cow::Borrowed("SOL")),
+                quote: Currency::new(Cow::Borrowed("USDC")),
+            });
+        assert_eq!(BYBIT_HANDLER.denormalize(&instrument), Some(String::from("SOLPERP")));
+    }
+}
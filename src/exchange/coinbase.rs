@@ -0,0 +1,273 @@
+use chrono::NaiveTime;
+use std::borrow::Cow;
+
+use crate::exchange::{asset_contract_size, is_known_quote, parse_known_quote, AssetContractSize};
+use crate::{denormalize_expiry, normalize_expiry, parse_base, parse_expiry_date, ContractSpec, Currency, Decimal, Exchange, ExchangeHandler, Expiry, Instrument, InstrumentError, InstrumentResult, InstrumentType, MarketType};
+
+/// Coinbase International perpetuals are quoted and settled in USDC
+const PERPETUAL_QUOTE_CURRENCY: &str = "USDC";
+/// Coinbase Derivatives futures are quoted and settled in USD
+const FUTURE_QUOTE_CURRENCY: &str = "USD";
+/// Suffix of perpetuals (e.g., BTC-PERP)
+const PERPETUAL_SUFFIX: &str = "PERP";
+/// Venue suffix of Coinbase International products (e.g., BTC-PERP-INTX)
+const INTERNATIONAL_SUFFIX: &str = "INTX";
+/// Venue suffix of Coinbase Derivatives products (e.g., BIT-28MAR25-CDE)
+const DERIVATIVES_SUFFIX: &str = "CDE";
+const DEFAULT_EXPIRY_FORMAT: &str = "%d%b%y";
+/// Coinbase Derivatives futures expire at 16:00 UTC
+const SETTLEMENT_TIME: NaiveTime = NaiveTime::from_hms_opt(16, 0, 0).unwrap();
+
+/// A Coinbase Derivatives nano future product code
+struct ProductCode {
+    code: &'static str,
+    asset: &'static str,
+}
+
+/// Product codes of the Coinbase Derivatives nano futures
+const PRODUCT_CODES: [ProductCode; 4] = [
+    ProductCode { code: "BIT", asset: "BTC" },
+    ProductCode { code: "ET", asset: "ETH" },
+    ProductCode { code: "SLP", asset: "SOL" },
+    ProductCode { code: "XPP", asset: "XRP" },
+];
+
+/// Contract sizes of the nano futures, by asset
+const CONTRACT_SIZES: [AssetContractSize; 4] = [
+    AssetContractSize { asset: "BTC", size: (1, 2) },
+    AssetContractSize { asset: "ETH", size: (1, 1) },
+    AssetContractSize { asset: "SOL", size: (5, 0) },
+    AssetContractSize { asset: "XRP", size: (500, 0) },
+];
+
+/// Handler for Coinbase Advanced spot pairs (e.g., BTC-USD), Coinbase International perpetuals
+/// (e.g., BTC-PERP-INTX) and Coinbase Derivatives nano futures (e.g., BIT-28MAR25-CDE)
+pub struct CoinbaseHandler;
+
+// Create a static instance to avoid allocations
+pub static COINBASE_HANDLER: CoinbaseHandler = CoinbaseHandler;
+
+impl CoinbaseHandler {
+    /// Parses a `DDMMMYY` expiry
+    fn parse_expiry(&self, expiry: &str) -> InstrumentResult<Expiry> {
+        parse_expiry_date(expiry, DEFAULT_EXPIRY_FORMAT)
+            .and_then(|_| normalize_expiry(expiry, self.settlement_time()))
+            .ok_or_else(|| InstrumentError::invalid_expiry(Exchange::Coinbase, expiry))
+    }
+
+    /// Product code entry of a code (e.g., BIT) or an asset (e.g., BTC)
+    fn product_code(&self, predicate: impl Fn(&ProductCode) -> bool) -> Option<&'static ProductCode> {
+        PRODUCT_CODES.iter().find(|product| predicate(product))
+    }
+}
+
+impl ExchangeHandler for CoinbaseHandler {
+    fn try_normalize(&self, market_type: MarketType, instrument_name: &str) -> InstrumentResult<Instrument> {
+        // Split the product ID into parts
+        let parts: Vec<&str> = instrument_name.split('-').collect();
+
+        let instrument_type = match parts.as_slice() {
+            // International perpetual: e.g., BTC-PERP or BTC-PERP-INTX
+            [base, perp] | [base, perp, _] if perp.eq_ignore_ascii_case(PERPETUAL_SUFFIX)
+                && parts.get(2).is_none_or(|venue| venue.eq_ignore_ascii_case(INTERNATIONAL_SUFFIX)) => {
+                InstrumentType::Perpetual {
+                    base: parse_base(Exchange::Coinbase, base)?,
+                    quote: Currency::new(Cow::Borrowed(PERPETUAL_QUOTE_CURRENCY)),
+                }
+            },
+
+            // Spot: e.g., BTC-USD
+            [base, quote] => InstrumentType::Spot {
+                base: parse_base(Exchange::Coinbase, base)?,
+                quote: parse_known_quote(Exchange::Coinbase, instrument_name, quote)?,
+            },
+
+            // Derivatives nano future: e.g., BIT-28MAR25-CDE
+            [code, expiry_str, venue] if venue.eq_ignore_ascii_case(DERIVATIVES_SUFFIX) => {
+                let product = self.product_code(|product| product.code.eq_ignore_ascii_case(code))
+                    .ok_or_else(|| InstrumentError::invalid_base(Exchange::Coinbase, code))?;
+                InstrumentType::Future {
+                    base: Currency::new(Cow::Borrowed(product.asset)),
+                    quote: Currency::new(Cow::Borrowed(FUTURE_QUOTE_CURRENCY)),
+                    expiry: self.parse_expiry(expiry_str)?,
+                }
+            },
+
+            // No matching format
+            _ => return Err(InstrumentError::unrecognized(Exchange::Coinbase, instrument_name)),
+        };
+
+        Ok(Instrument::new(Exchange::Coinbase, market_type, instrument_type))
+    }
+
+    fn try_denormalize(&self, instrument: &Instrument) -> InstrumentResult<String> {
+        // Check if this is the right exchange handler
+        if instrument.exchange != Exchange::Coinbase {
+            return Err(InstrumentError::ExchangeMismatch { expected: Exchange::Coinbase, found: instrument.exchange });
+        }
+
+        match &instrument.instrument_type {
+            InstrumentType::Spot { base, quote } if is_known_quote(quote.as_ref()) => {
+                Ok(format!("{}-{}", base.as_ref(), quote.as_ref()))
+            },
+
+            InstrumentType::Perpetual { base, quote } if quote.as_ref() == PERPETUAL_QUOTE_CURRENCY => {
+                Ok(format!("{}-{}-{}", base.as_ref(), PERPETUAL_SUFFIX, INTERNATIONAL_SUFFIX))
+            },
+
+            InstrumentType::Future { base, quote, expiry } if quote.as_ref() == FUTURE_QUOTE_CURRENCY => {
+                let product = self.product_code(|product| product.asset == base.as_ref())
+                    .ok_or_else(|| InstrumentError::invalid_base(Exchange::Coinbase, base.as_ref()))?;
+                Ok(format!("{}-{}-{}", product.code, denormalize_expiry(expiry, DEFAULT_EXPIRY_FORMAT), DERIVATIVES_SUFFIX))
+            },
+
+            _ => Err(InstrumentError::unsupported_instrument_type(Exchange::Coinbase, &instrument.instrument_type)),
+        }
+    }
+
+    fn supports_instrument_type(&self, instrument_type: &InstrumentType) -> bool {
+        matches!(instrument_type,
+            InstrumentType::Spot { .. } | InstrumentType::Perpetual { .. } | InstrumentType::Future { .. })
+    }
+
+    fn settlement_time(&self) -> NaiveTime {
+        SETTLEMENT_TIME
+    }
+
+    fn contract_spec(&self, instrument: &Instrument) -> InstrumentResult<ContractSpec> {
+        let instrument_type = &instrument.instrument_type;
+        let quote = Currency::new(Cow::Owned(instrument_type.quote().to_string()));
+        let base = instrument_type.base().to_string();
+
+        Ok(match instrument_type {
+            // Nano futures are sized in a fraction (or multiple) of the asset
+            InstrumentType::Future { .. } => {
+                let multiplier = asset_contract_size(&CONTRACT_SIZES, &base)
+                    .ok_or_else(|| InstrumentError::unknown_contract_size(Exchange::Coinbase, instrument_type))?;
+                ContractSpec::linear(quote, multiplier)
+            },
+            _ => ContractSpec::linear(quote, Decimal::from(1)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod coinbase_normalize_tests {
+    use std::borrow::Cow;
+
+    use chrono::NaiveDate;
+
+    use super::{COINBASE_HANDLER, SETTLEMENT_TIME};
+    use crate::{ContractSpec, Currency, Decimal, Exchange, ExchangeHandler, Expiry, Instrument, InstrumentError, InstrumentType, MarketType};
+
+    #[test]
+    fn test_normalize_spot() {
+        let expected_instrument = Instrument::new(
+            Exchange::Coinbase,
+            MarketType::OrderBook,
+            InstrumentType::Spot {
+                base: Currency::new(Cow::Borrowed("BTC")),
+                quote: Currency::new(Cow::Borrowed("USD")),
+            });
+        assert_eq!(COINBASE_HANDLER.normalize(MarketType::OrderBook, "BTC-USD"), Some(expected_instrument));
+    }
+
+    #[test]
+    fn test_normalize_perpetual() {
+        let expected_type = InstrumentType::Perpetual {
+            base: Currency::new(Cow::Borrowed("BTC")),
+            quote: Currency::new(Cow::Borrowed("USDC")),
+        };
+        let instrument = COINBASE_HANDLER.normalize(MarketType::OrderBook, "BTC-PERP-INTX").unwrap();
+        assert_eq!(instrument.instrument_type, expected_type);
+        assert_eq!(COINBASE_HANDLER.normalize(MarketType::OrderBook, "BTC-PERP").unwrap().instrument_type, expected_type);
+        assert_eq!(COINBASE_HANDLER.denormalize(&instrument), Some(String::from("BTC-PERP-INTX")));
+    }
+
+    #[test]
+    fn test_normalize_nano_futures() {
+        let expected_instrument = Instrument::new(
+            Exchange::Coinbase,
+            MarketType::OrderBook,
+            InstrumentType::Future {
+                base: Currency::new(Cow::Borrowed("BTC")),
+                quote: Currency::new(Cow::Borrowed("USD")),
+                expiry: Expiry::new(NaiveDate::from_ymd_opt(2025, 3, 28).unwrap(), SETTLEMENT_TIME),
+            });
+        let instrument = COINBASE_HANDLER.normalize(MarketType::OrderBook, "BIT-28MAR25-CDE").unwrap();
+        assert_eq!(instrument, expected_instrument);
+        assert_eq!(instrument.contract_spec().unwrap(), ContractSpec::linear(Currency::new(Cow::Borrowed("USD")), Decimal::new(1, 2).unwrap()));
+
+        let instrument = COINBASE_HANDLER.normalize(MarketType::OrderBook, "ET-28MAR25-CDE").unwrap();
+        assert_eq!(instrument.instrument_type.base().to_string(), "ETH");
+        assert_eq!(instrument.contract_spec().unwrap().multiplier, Decimal::new(1, 1).unwrap());
+    }
+
+    #[test]
+    fn test_normalize_unknown() {
+        assert!(matches!(
+            COINBASE_HANDLER.try_normalize(MarketType::OrderBook, "BTC-28MAR25-CDE"),
+            Err(InstrumentError::InvalidBase { exchange: Exchange::Coinbase, .. })
+        ));
+        assert_eq!(COINBASE_HANDLER.normalize(MarketType::OrderBook, "BTC-PERP-CDE"), None);
+        assert_eq!(COINBASE_HANDLER.normalize(MarketType::OrderBook, "BTC-USD-PERP"), None);
+        // Other venues' futures and perpetuals are not spot pairs
+        assert_eq!(COINBASE_HANDLER.normalize(MarketType::OrderBook, "BTC-28MAR25"), None);
+        assert_eq!(COINBASE_HANDLER.normalize(MarketType::OrderBook, "BTC-PERPETUAL"), None);
+    }
+}
+
+#[cfg(test)]
+mod coinbase_denormalize_tests {
+    use std::borrow::Cow;
+
+    use chrono::NaiveDate;
+
+    use super::{COINBASE_HANDLER, SETTLEMENT_TIME};
+    use crate::{Currency, Exchange, ExchangeHandler, Expiry, Instrument, InstrumentError, InstrumentType, MarketType};
+
+    #[test]
+    fn test_denorm_nano_future() {
+        let instrument = Instrument::new(
+            Exchange::Coinbase,
+            MarketType::OrderBook,
+            InstrumentType::Future {
+                base: Currency::new(Cow::Borrowed("ETH")),
+                quote: Currency::new(Cow::Borrowed("USD")),
+                expiry: Expiry::new(NaiveDate::from_ymd_opt(2025, 6, 27).unwrap(), SETTLEMENT_TIME),
+            });
+        assert_eq!(COINBASE_HANDLER.denormalize(&instrument), Some(String::from("ET-27JUN25-CDE")));
+    }
+
+    #[test]
+    fn test_denorm_unlisted_asset() {
+        let instrument = Instrument::new(
+            Exchange::Coinbase,
+            MarketType::OrderBook,
+            InstrumentType::Future {
+                base: Currency::new(Cow::Borrowed("PEPE")),
+                quote: Currency::new(Cow::Borrowed("USD")),
+                expiry: Expiry::new(NaiveDate::from_ymd_opt(2025, 6, 27).unwrap(), SETTLEMENT_TIME),
+            });
+        assert_eq!(COINBASE_HANDLER.denormalize(&instrument), None);
+        assert!(matches!(
+            instrument.contract_spec(),
+            Err(InstrumentError::UnknownContractSize { exchange: Exchange::Coinbase, .. })
+        ));
+    }
+
+    #[test]
+    fn test_denorm_spot_unknown_quote() {
+        let instrument = Instrument::new(
+            Exchange::Coinbase,
+            MarketType::OrderBook,
+            InstrumentType::Spot {
+                base: Currency::new(Cow::Borrowed("BTC")),
+                quote: Currency::new(Cow::Borrowed("28MAR25")),
+            });
+        assert!(matches!(
+            COINBASE_HANDLER.try_denormalize(&instrument),
+            Err(InstrumentError::UnsupportedInstrumentType { exchange: Exchange::Coinbase, .. })
+        ));
+    }
+}
@@ -0,0 +1,275 @@
+use chrono::NaiveTime;
+use std::borrow::Cow;
+
+use crate::exchange::{asset_contract_size, parse_listed_quote, split_pair, AssetContractSize};
+use crate::{denormalize_expiry, parse_base, ContractSpec, Currency, Decimal, Exchange, ExchangeHandler, Expiry, Instrument, InstrumentError, InstrumentResult, InstrumentType, MarketType, OptionKind, Strike};
+
+/// Options and MOVE contracts are quoted and settled in USD
+const DEFAULT_QUOTE_CURRENCY: &str = "USD";
+/// Quote currencies of perpetuals (e.g., BTCUSD, BTCUSDT)
+const PERPETUAL_QUOTE_CURRENCIES: [&str; 2] = ["USDT", "USD"];
+const DEFAULT_EXPIRY_FORMAT: &str = "%d%m%y";
+const STRIKE_DECIMAL_SEPARATOR: char = '.';
+/// Prefix of MOVE contracts (e.g., MV-BTC-100000-280325)
+const MOVE_PREFIX: &str = "MV";
+/// Expiring contracts settle at 12:00 UTC
+const SETTLEMENT_TIME: NaiveTime = NaiveTime::from_hms_opt(12, 0, 0).unwrap();
+
+/// Contract sizes of the listed assets, shared by their perpetuals, options and MOVE contracts; other assets have their own sizes, so their contract spec is unknown
+const CONTRACT_SIZES: [AssetContractSize; 2] = [
+    AssetContractSize { asset: "BTC", size: (1, 3) },
+    AssetContractSize { asset: "ETH", size: (1, 2) },
+];
+
+/// Handler for Delta Exchange perpetuals (e.g., BTCUSD), options (e.g., C-BTC-100000-280325)
+/// and MOVE contracts (e.g., MV-BTC-100000-280325)
+pub struct DeltaHandler;
+
+// Create a static instance to avoid allocations
+pub static DELTA_HANDLER: DeltaHandler = DeltaHandler;
+
+impl DeltaHandler {
+    /// Parses a `DDMMYY` expiry, which would read as a valid `YYMMDD` date in most other formats
+    fn parse_expiry(&self, expiry: &str) -> InstrumentResult<Expiry> {
+        Expiry::parse(expiry, DEFAULT_EXPIRY_FORMAT, self.settlement_time())
+            .ok_or_else(|| InstrumentError::invalid_expiry(Exchange::Delta, expiry))
+    }
+
+    fn parse_strike(&self, strike: &str) -> InstrumentResult<Strike> {
+        Strike::parse_with_separator(strike, STRIKE_DECIMAL_SEPARATOR)
+            .ok_or_else(|| InstrumentError::invalid_strike(Exchange::Delta, strike))
+    }
+
+    /// Contract size of `asset`, or None if it is not listed
+    fn contract_size(&self, asset: &str) -> Option<Decimal> {
+        asset_contract_size(&CONTRACT_SIZES, asset)
+    }
+}
+
+impl ExchangeHandler for DeltaHandler {
+    fn try_normalize(&self, market_type: MarketType, instrument_name: &str) -> InstrumentResult<Instrument> {
+        // Split the symbol into parts
+        let parts: Vec<&str> = instrument_name.split('-').collect();
+
+        let instrument_type = match parts.as_slice() {
+            // Perpetual: e.g., BTCUSD or BTCUSDT
+            [symbol] => {
+                let (base, quote) = split_pair(symbol)
+                    .ok_or_else(|| InstrumentError::unrecognized(Exchange::Delta, instrument_name))?;
+                InstrumentType::Perpetual {
+                    base: parse_base(Exchange::Delta, base)?,
+                    quote: parse_listed_quote(Exchange::Delta, instrument_name, quote, &PERPETUAL_QUOTE_CURRENCIES)?,
+                }
+            },
+
+            // MOVE contract: e.g., MV-BTC-100000-280325
+            [prefix, base, strike_str, expiry_str] if prefix.eq_ignore_ascii_case(MOVE_PREFIX) => InstrumentType::Move {
+                base: parse_base(Exchange::Delta, base)?,
+                quote: Currency::new(Cow::Borrowed(DEFAULT_QUOTE_CURRENCY)),
+                expiry: self.parse_expiry(expiry_str)?,
+                strike: self.parse_strike(strike_str)?,
+            },
+
+            // Option, kind first: e.g., C-BTC-100000-280325
+            [kind_str, base, strike_str, expiry_str] => {
+                let kind = OptionKind::try_from(*kind_str)
+                    .map_err(|_| InstrumentError::invalid_option_kind(Exchange::Delta, kind_str))?;
+
+                InstrumentType::Option {
+                    base: parse_base(Exchange::Delta, base)?,
+                    quote: Currency::new(Cow::Borrowed(DEFAULT_QUOTE_CURRENCY)),
+                    expiry: self.parse_expiry(expiry_str)?,
+                    strike: self.parse_strike(strike_str)?,
+                    kind,
+                }
+            },
+
+            // No matching format
+            _ => return Err(InstrumentError::unrecognized(Exchange::Delta, instrument_name)),
+        };
+
+        Ok(Instrument::new(Exchange::Delta, market_type, instrument_type))
+    }
+
+    fn try_denormalize(&self, instrument: &Instrument) -> InstrumentResult<String> {
+        // Check if this is the right exchange handler
+        if instrument.exchange != Exchange::Delta {
+            return Err(InstrumentError::ExchangeMismatch { expected: Exchange::Delta, found: instrument.exchange });
+        }
+
+        match &instrument.instrument_type {
+            InstrumentType::Perpetual { base, quote } if PERPETUAL_QUOTE_CURRENCIES.contains(&quote.as_ref()) => {
+                Ok(format!("{}{}", base.as_ref(), quote.as_ref()))
+            },
+
+            InstrumentType::Option { base, quote, expiry, strike, kind } if quote.as_ref() == DEFAULT_QUOTE_CURRENCY => {
+                Ok(format!("{}-{}-{}-{}",
+                    kind,
+                    base.as_ref(),
+                    strike.format_with_separator(STRIKE_DECIMAL_SEPARATOR),
+                    denormalize_expiry(expiry, DEFAULT_EXPIRY_FORMAT)))
+            },
+
+            InstrumentType::Move { base, quote, expiry, strike } if quote.as_ref() == DEFAULT_QUOTE_CURRENCY => {
+                Ok(format!("{}-{}-{}-{}",
+                    MOVE_PREFIX,
+                    base.as_ref(),
+                    strike.format_with_separator(STRIKE_DECIMAL_SEPARATOR),
+                    denormalize_expiry(expiry, DEFAULT_EXPIRY_FORMAT)))
+            },
+
+            _ => Err(InstrumentError::unsupported_instrument_type(Exchange::Delta, &instrument.instrument_type)),
+        }
+    }
+
+    fn supports_instrument_type(&self, instrument_type: &InstrumentType) -> bool {
+        matches!(instrument_type,
+            InstrumentType::Perpetual { .. } | InstrumentType::Option { .. } | InstrumentType::Move { .. })
+    }
+
+    fn settlement_time(&self) -> NaiveTime {
+        SETTLEMENT_TIME
+    }
+
+    fn contract_spec(&self, instrument: &Instrument) -> InstrumentResult<ContractSpec> {
+        // Every contract settles in its quote currency and is sized in a fraction of its base
+        let instrument_type = &instrument.instrument_type;
+        let contract_size = self.contract_size(instrument_type.base())
+            .ok_or_else(|| InstrumentError::unknown_contract_size(Exchange::Delta, instrument_type))?;
+        Ok(ContractSpec::linear(Currency::new(Cow::Owned(instrument_type.quote().to_string())), contract_size))
+    }
+}
+
+#[cfg(test)]
+mod delta_normalize_tests {
+    use std::borrow::Cow;
+
+    use chrono::NaiveDate;
+
+    use super::{DELTA_HANDLER, SETTLEMENT_TIME};
+    use crate::{ContractSpec, Currency, Decimal, Exchange, ExchangeHandler, Expiry, Instrument, InstrumentError, InstrumentType, MarketType, OptionKind, Strike};
+
+    #[test]
+    fn test_normalize_perpetual() {
+        let expected_instrument = Instrument::new(
+            Exchange::Delta,
+            MarketType::OrderBook,
+            InstrumentType::Perpetual {
+                base: Currency::new(Cow::Borrowed("BTC")),
+                quote: Currency::new(Cow::Borrowed("USD")),
+            });
+        assert_eq!(DELTA_HANDLER.normalize(MarketType::OrderBook, "BTCUSD"), Some(expected_instrument));
+    }
+
+    #[test]
+    fn test_contract_spec() {
+        let perpetual = DELTA_HANDLER.normalize(MarketType::OrderBook, "BTCUSD").unwrap();
+        assert_eq!(perpetual.contract_spec().unwrap(), ContractSpec::linear(Currency::new(Cow::Borrowed("USD")), Decimal::new(1, 3).unwrap()));
+        let option = DELTA_HANDLER.normalize(MarketType::OrderBook, "C-BTC-100000-280325").unwrap();
+        assert_eq!(option.contract_spec().unwrap().multiplier, Decimal::new(1, 3).unwrap());
+        let eth = DELTA_HANDLER.normalize(MarketType::OrderBook, "ETHUSDT").unwrap();
+        assert_eq!(eth.contract_spec().unwrap(), ContractSpec::linear(Currency::new(Cow::Borrowed("USDT")), Decimal::new(1, 2).unwrap()));
+        let sol = DELTA_HANDLER.normalize(MarketType::OrderBook, "SOLUSD").unwrap();
+        assert!(matches!(sol.contract_spec(), Err(InstrumentError::UnknownContractSize { exchange: Exchange::Delta, .. })));
+    }
+
+    #[test]
+    fn test_normalize_option() {
+        let expected_instrument = Instrument::new(
+            Exchange::Delta,
+            MarketType::OrderBook,
+            InstrumentType::Option {
+                base: Currency::new(Cow::Borrowed("BTC")),
+                quote: Currency::new(Cow::Borrowed("USD")),
+                expiry: Expiry::new(NaiveDate::from_ymd_opt(2025, 3, 28).unwrap(), SETTLEMENT_TIME),
+                strike: Strike::from(100000),
+                kind: OptionKind::Call,
+            });
+        assert_eq!(DELTA_HANDLER.normalize(MarketType::OrderBook, "C-BTC-100000-280325"), Some(expected_instrument));
+        assert_eq!(
+            DELTA_HANDLER.normalize(MarketType::OrderBook, "P-ETH-2500.5-280325").unwrap().instrument_type.to_string(),
+            "o.ETH-USD-20250328-2500d5-P"
+        );
+    }
+
+    #[test]
+    fn test_normalize_move() {
+        let instrument = DELTA_HANDLER.normalize(MarketType::OrderBook, "MV-BTC-100000-280325").unwrap();
+        assert_eq!(instrument.instrument_type, InstrumentType::Move {
+            base: Currency::new(Cow::Borrowed("BTC")),
+            quote: Currency::new(Cow::Borrowed("USD")),
+            expiry: Expiry::new(NaiveDate::from_ymd_opt(2025, 3, 28).unwrap(), SETTLEMENT_TIME),
+            strike: Strike::from(100000),
+        });
+        assert_eq!(instrument.to_string(), "o.m.BTC-USD-20250328-100000.delta");
+    }
+
+    #[test]
+    fn test_normalize_field_order() {
+        // Expiry and strike are not interchangeable
+        assert!(matches!(
+            DELTA_HANDLER.try_normalize(MarketType::OrderBook, "C-BTC-280325-100000"),
+            Err(InstrumentError::InvalidExpiry { exchange: Exchange::Delta, .. })
+        ));
+        assert!(matches!(
+            DELTA_HANDLER.try_normalize(MarketType::OrderBook, "X-BTC-100000-280325"),
+            Err(InstrumentError::InvalidOptionKind { exchange: Exchange::Delta, .. })
+        ));
+        assert_eq!(DELTA_HANDLER.normalize(MarketType::OrderBook, "BTC-28MAR25-100000-C"), None);
+    }
+}
+
+#[cfg(test)]
+mod delta_denormalize_tests {
+    use std::borrow::Cow;
+
+    use chrono::NaiveDate;
+
+    use super::{DELTA_HANDLER, SETTLEMENT_TIME};
+    use crate::{Currency, Exchange, ExchangeHandler, Expiry, Instrument, InstrumentError, InstrumentType, MarketType, OptionKind, Strike};
+
+    #[test]
+    fn test_denorm_option() {
+        let instrument = Instrument::new(
+            Exchange::Delta,
+            MarketType::OrderBook,
+            InstrumentType::Option {
+                base: Currency::new(Cow::Borrowed("ETH")),
+                quote: Currency::new(Cow::Borrowed("USD")),
+                expiry: Expiry::new(NaiveDate::from_ymd_opt(2025, 6, 5).unwrap(), SETTLEMENT_TIME),
+                strike: Strike::from(3000),
+                kind: OptionKind::Put,
+            });
+        assert_eq!(DELTA_HANDLER.denormalize(&instrument), Some(String::from("P-ETH-3000-050625")));
+    }
+
+    #[test]
+    fn test_denorm_move() {
+        let instrument = Instrument::new(
+            Exchange::Delta,
+            MarketType::OrderBook,
+            InstrumentType::Move {
+                base: Currency::new(Cow::Borrowed("BTC")),
+                quote: Currency::new(Cow::Borrowed("USD")),
+                expiry: Expiry::new(NaiveDate::from_ymd_opt(2025, 3, 28).unwrap(), SETTLEMENT_TIME),
+                strike: Strike::from(100000),
+            });
+        assert_eq!(DELTA_HANDLER.denormalize(&instrument), Some(String::from("MV-BTC-100000-280325")));
+    }
+
+    #[test]
+    fn test_denorm_perpetual_quotes() {
+        let perpetual = |quote: &'static str| Instrument::new(
+            Exchange::Delta,
+            MarketType::OrderBook,
+            InstrumentType::Perpetual {
+                base: Currency::new(Cow::Borrowed("BTC")),
+                quote: Currency::new(Cow::Borrowed(quote)),
+            });
+        assert_eq!(DELTA_HANDLER.denormalize(&perpetual("USDT")), Some(String::from("BTCUSDT")));
+        assert!(matches!(
+            DELTA_HANDLER.try_denormalize(&perpetual("EUR")),
+            Err(InstrumentError::UnsupportedInstrumentType { exchange: Exchange::Delta, .. })
+        ));
+    }
+}
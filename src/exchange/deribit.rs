@@ -1,19 +1,85 @@
-use tracing::error;
+use chrono::NaiveTime;
 use std::borrow::Cow;
 
-use crate::{denormalize_expiry, normalize_expiry, parse_expiry_date, Currency, Exchange, ExchangeHandler, Instrument, InstrumentType, MarketType, OptionKind};
+use crate::{denormalize_expiry, normalize_expiry, parse_base, parse_expiry_date, ComboKind, ContractSpec, ContractStyle, Currency, Decimal, Exchange, ExchangeHandler, Expiry, IndexKind, Instrument, InstrumentError, InstrumentResult, InstrumentType, MarketType, OptionKind, Strike};
 
-const LOG_CTX: &str = "normify::exchange#deribit";
 const DEFAULT_QUOTE_CURRENCY: &str = "usd";
 const DEFAULT_EXPIRY_FORMAT: &str = "%d%b%y";
+const STRIKE_DECIMAL_SEPARATOR: char = 'd';
+/// Channel prefix of price indices (e.g., deribit_price_index.btc_usd)
+const PRICE_INDEX_PREFIX: &str = "deribit_price_index.";
+/// Channel prefix of options mark prices (e.g., markprice.options.btc_usd)
+const MARK_PRICE_PREFIX: &str = "markprice.options.";
+/// Suffix of the DVOL volatility index (e.g., BTC-DVOL)
+const VOLATILITY_INDEX_SUFFIX: &str = "DVOL";
+/// Face value in USD of BTC inverse futures and perpetuals (other currencies use 1 USD)
+const BTC_INVERSE_CONTRACT_SIZE: u64 = 10;
+/// Placeholder for the perpetual leg of a future spread (e.g., BTC-FS-28MAR25_PERP)
+const COMBO_PERPETUAL_LEG: &str = "PERP";
+/// Expiring contracts settle at 08:00 UTC
+const SETTLEMENT_TIME: NaiveTime = NaiveTime::from_hms_opt(8, 0, 0).unwrap();
 
 pub struct DeribitHandler;
         
 // Create a static instance to avoid allocations
 pub static DERIBIT_HANDLER: DeribitHandler = DeribitHandler;
 
-impl ExchangeHandler for DeribitHandler {
-    fn normalize(&self, market_type: MarketType, instrument_name: &str) -> Option<Instrument> {
+impl DeribitHandler {
+    /// Normalizes a combo name split into its code, `_`-joined expiries and optional `_`-joined strikes
+    fn normalize_combo(&self, exchange: Exchange, instrument_name: &str, base: &str, code: &str, expiries: &str, strikes: Option<&str>) -> InstrumentResult<InstrumentType> {
+        let kind = ComboKind::try_from(code)
+            .map_err(|_| InstrumentError::unrecognized(exchange, instrument_name))?;
+        let base = parse_base(exchange, base)?;
+        
+        let mut leg_expiries = Vec::new();
+        for expiry in expiries.split('_') {
+            if expiry.eq_ignore_ascii_case(COMBO_PERPETUAL_LEG) {
+                leg_expiries.push(None);
+            } else {
+                leg_expiries.push(Some(self.parse_expiry(exchange, expiry)?));
+            }
+        }
+        
+        let mut leg_strikes = Vec::new();
+        for strike in strikes.into_iter().flat_map(|s| s.split('_')) {
+            leg_strikes.push(Strike::parse_with_separator(strike, STRIKE_DECIMAL_SEPARATOR)
+                .ok_or_else(|| InstrumentError::invalid_strike(exchange, strike))?);
+        }
+        
+        let quote = Currency::new(Cow::Borrowed(DEFAULT_QUOTE_CURRENCY));
+        let legs = kind.build_legs(&base, &quote, &leg_expiries, &leg_strikes)
+            .ok_or_else(|| InstrumentError::unrecognized(exchange, instrument_name))?;
+        
+        Ok(InstrumentType::Combo { kind, legs })
+    }
+
+    /// Parses the `BASE` or `BASE_QUOTE` prefix of a perpetual, future or option name; the quote defaults to USD
+    fn parse_base_quote(&self, exchange: Exchange, base_quote: &str) -> InstrumentResult<(Currency, Currency)> {
+        // Use split_once to avoid additional allocations
+        let (base, quote) = base_quote.split_once('_').unwrap_or((base_quote, DEFAULT_QUOTE_CURRENCY));
+        let quote = parse_base(exchange, quote)
+            .map_err(|_| InstrumentError::unrecognized(exchange, base_quote))?;
+        Ok((parse_base(exchange, base)?, quote))
+    }
+
+    /// Writes the `BASE` or `BASE_QUOTE` prefix of a perpetual, future or option name
+    fn denormalize_base_quote(&self, base: &Currency, quote: &Currency) -> String {
+        if quote.as_ref().eq_ignore_ascii_case(DEFAULT_QUOTE_CURRENCY) {
+            base.to_string()
+        } else {
+            format!("{}_{}", base.as_ref(), quote.as_ref())
+        }
+    }
+
+    /// Parses a `DDMMMYY` expiry
+    fn parse_expiry(&self, exchange: Exchange, expiry: &str) -> InstrumentResult<Expiry> {
+        parse_expiry_date(expiry, DEFAULT_EXPIRY_FORMAT)
+            .and_then(|_| normalize_expiry(expiry, self.settlement_time()))
+            .ok_or_else(|| InstrumentError::invalid_expiry(exchange, expiry))
+    }
+
+    /// Normalizes a Deribit-style instrument name as an instrument of `exchange`
+    pub(crate) fn normalize_as(&self, exchange: Exchange, market_type: MarketType, instrument_name: &str) -> InstrumentResult<Instrument> {
         
         // Split the instrument name into parts
         let parts: Vec<&str> = instrument_name.split('-').collect();
@@ -21,149 +87,253 @@ impl ExchangeHandler for DeribitHandler {
         match parts.as_slice() {
             // Perpetual: e.g., BTC-PERPETUAL or SOL_USDC-PERPETUAL (Non USD quote)
             [base_quote, perpetual] if perpetual.eq_ignore_ascii_case("perpetual") => {
-                // Use split_once to avoid additional allocations
-                let (base, quote) = if let Some((b, q)) = base_quote.split_once('_') {
-                    (b, q)
-                } else {
-                    (*base_quote, DEFAULT_QUOTE_CURRENCY)
-                };
+                let (base, quote) = self.parse_base_quote(exchange, base_quote)?;
                 
-                Some(Instrument::new(
-                    Exchange::Deribit, 
+                Ok(Instrument::new(
+                    exchange, 
                     market_type, 
-                    InstrumentType::Perpetual {
-                        base: Currency::new(Cow::Owned(base.to_string())), 
-                        quote: Currency::new(Cow::Owned(quote.to_string())), 
-                    }
+                    InstrumentType::Perpetual { base, quote }
                 ))
             }
     
-            // Future: e.g., BTC-28MAR25
-            [base, expiry] if parse_expiry_date(expiry, DEFAULT_EXPIRY_FORMAT).is_some() => {
-                let normalized_expiry = normalize_expiry(expiry)?;
+            // Future: e.g., BTC-28MAR25 or BTC_USDC-28MAR25 (Non USD quote)
+            [base_quote, expiry] if parse_expiry_date(expiry, DEFAULT_EXPIRY_FORMAT).is_some() => {
+                let (base, quote) = self.parse_base_quote(exchange, base_quote)?;
                 
-                Some(Instrument::new(
-                    Exchange::Deribit, 
+                Ok(Instrument::new(
+                    exchange, 
                     market_type, 
                     InstrumentType::Future {
-                        base: Currency::new(Cow::Owned(base.to_string())),  
-                        quote: Currency::new(Cow::Owned(DEFAULT_QUOTE_CURRENCY.to_string())),
-                        expiry: Cow::Owned(normalized_expiry)
+                        base,  
+                        quote,
+                        expiry: self.parse_expiry(exchange, expiry)?
                     }
                 ))
             }
     
-            // Option: e.g., BTC-28MAR25-100000-C
-            [base, expiry, strike_str, kind_str] => {
-                // Validate the expiry date
-                if parse_expiry_date(expiry, DEFAULT_EXPIRY_FORMAT).is_none() {
-                    error!(name: LOG_CTX, "normalize::Invalid expiry date format: {}", expiry);
-                    return None;
-                }
+            // Combo: e.g., BTC-CS-28MAR25-90000_100000, BTC-FS-28MAR25_PERP or BTC-STRD-28MAR25-100000
+            [base, code, expiries, strikes @ ..] if strikes.len() <= 1 && ComboKind::try_from(*code).is_ok() => {
+                let instrument_type = self.normalize_combo(exchange, instrument_name, base, code, expiries, strikes.first().copied())?;
+                Ok(Instrument::new(exchange, market_type, instrument_type))
+            }
+    
+            // Option: e.g., BTC-28MAR25-100000-C or BTC_USDC-28MAR25-100000-C (Non USD quote)
+            [base_quote, expiry, strike_str, kind_str] => {
+                let (base, quote) = self.parse_base_quote(exchange, base_quote)?;
+                let expiry = self.parse_expiry(exchange, expiry)?;
                 
                 // Parse strike price
-                let strike = match strike_str.parse::<u64>() {
-                    Ok(s) => s,
-                    Err(_) => {
-                        error!(name: LOG_CTX, "normalize::Invalid strike price: {}", strike_str);
-                        return None;
-                    }
-                };
+                let strike = Strike::parse_with_separator(strike_str, STRIKE_DECIMAL_SEPARATOR)
+                    .ok_or_else(|| InstrumentError::invalid_strike(exchange, strike_str))?;
                 
                 // Parse option kind
-                let kind = match OptionKind::try_from(*kind_str) {
-                    Ok(k) => k,
-                    Err(e) => {
-                        error!(name: LOG_CTX, "normalize::Invalid option kind: {}", e);
-                        return None;
-                    }
-                };
+                let kind = OptionKind::try_from(*kind_str)
+                    .map_err(|_| InstrumentError::invalid_option_kind(exchange, kind_str))?;
                 
-                let normalized_expiry = normalize_expiry(expiry)?;
-                
-                Some(Instrument::new(
-                    Exchange::Deribit,
+                Ok(Instrument::new(
+                    exchange,
                     market_type,
                     InstrumentType::Option {
-                        base: Currency::new(Cow::Owned(base.to_string())), 
-                        quote: Currency::new(Cow::Owned(DEFAULT_QUOTE_CURRENCY.to_string())),
-                        expiry: Cow::Owned(normalized_expiry), 
+                        base, 
+                        quote,
+                        expiry, 
                         strike, 
                         kind
                     }
                 ))
             }
     
+            // Volatility index: e.g., BTC-DVOL
+            [base, dvol] if dvol.eq_ignore_ascii_case(VOLATILITY_INDEX_SUFFIX) => {
+                Ok(Instrument::new(
+                    exchange, 
+                    market_type, 
+                    InstrumentType::Index {
+                        base: parse_base(exchange, base)?, 
+                        quote: Currency::new(Cow::Borrowed(DEFAULT_QUOTE_CURRENCY)), 
+                        kind: IndexKind::Volatility,
+                    }
+                ))
+            }
+    
+            // Price or mark index: e.g., deribit_price_index.btc_usd or markprice.options.btc_usd
+            [index] if index.contains('.') => {
+                let (kind, index_name) = if let Some(name) = index.strip_prefix(PRICE_INDEX_PREFIX) {
+                    (IndexKind::Price, name)
+                } else if let Some(name) = index.strip_prefix(MARK_PRICE_PREFIX) {
+                    (IndexKind::Mark, name)
+                } else {
+                    return Err(InstrumentError::unrecognized(exchange, instrument_name));
+                };
+                
+                let (base, quote) = index_name.split_once('_')
+                    .ok_or_else(|| InstrumentError::unrecognized(exchange, instrument_name))?;
+                
+                Ok(Instrument::new(
+                    exchange, 
+                    market_type, 
+                    InstrumentType::Index {
+                        base: parse_base(exchange, base)?, 
+                        quote: Currency::new(Cow::Owned(quote.to_string())), 
+                        kind,
+                    }
+                ))
+            }
+    
             // Spot: e.g., BTC_USD
             [spot] => {
                 let parts: Vec<&str> = spot.split('_').collect();
                 if parts.len() != 2 {
-                    error!(name: LOG_CTX, "normalize::Invalid spot format: {}", spot);
-                    return None;
+                    return Err(InstrumentError::unrecognized(exchange, instrument_name));
                 }
                 
-                Some(Instrument::new(
-                    Exchange::Deribit, 
+                Ok(Instrument::new(
+                    exchange, 
                     market_type, 
                     InstrumentType::Spot {
-                        base: Currency::new(Cow::Owned(parts[0].to_string())), 
+                        base: parse_base(exchange, parts[0])?, 
                         quote: Currency::new(Cow::Owned(parts[1].to_string())), 
                     }
                 ))
             }
     
             // No matching format
-            _ => {
-                error!(name: LOG_CTX, "normalize::Unexpected instrument format: {:?}", instrument_name);
-                None
-            }
+            _ => Err(InstrumentError::unrecognized(exchange, instrument_name)),
         }
     }
+}
+
+impl ExchangeHandler for DeribitHandler {
+    fn try_normalize(&self, market_type: MarketType, instrument_name: &str) -> InstrumentResult<Instrument> {
+        self.normalize_as(Exchange::Deribit, market_type, instrument_name)
+    }
 
-    fn denormalize(&self, instrument: &Instrument) -> Option<String> {
+    fn try_denormalize(&self, instrument: &Instrument) -> InstrumentResult<String> {
         // Check if this is the right exchange handler
         if instrument.exchange != Exchange::Deribit {
-            error!(name: LOG_CTX, "denormalize::Attempted to use Deribit handler for {:?}", instrument.exchange);
-            return None;
+            return Err(InstrumentError::ExchangeMismatch { expected: Exchange::Deribit, found: instrument.exchange });
         }
         
         match &instrument.instrument_type {
-            InstrumentType::Future { base, quote: _, expiry } => {
+            InstrumentType::Future { base, quote, expiry } => {
                 let denormalized_expiry = denormalize_expiry(expiry, DEFAULT_EXPIRY_FORMAT);
-                Some(format!("{}-{}", base.as_ref(), denormalized_expiry))
+                Ok(format!("{}-{}", self.denormalize_base_quote(base, quote), denormalized_expiry))
             },
             
-            InstrumentType::Option { base, quote: _, expiry, strike, kind } => {
+            InstrumentType::Option { base, quote, expiry, strike, kind } => {
                 let denormalized_expiry = denormalize_expiry(expiry, DEFAULT_EXPIRY_FORMAT);
-                Some(format!("{}-{}-{}-{}", 
-                    base.as_ref(), 
+                Ok(format!("{}-{}-{}-{}", 
+                    self.denormalize_base_quote(base, quote), 
                     denormalized_expiry, 
-                    strike, 
-                    kind.to_string()))
+                    strike.format_with_separator(STRIKE_DECIMAL_SEPARATOR), 
+                    kind))
             },
             
             InstrumentType::Spot { base, quote } => {
-                Some(format!("{}_{}", base.as_ref(), quote.as_ref()))
+                Ok(format!("{}_{}", base.as_ref(), quote.as_ref()))
             },
             
             InstrumentType::Perpetual { base, quote } => {
-                if quote.as_ref().eq_ignore_ascii_case(DEFAULT_QUOTE_CURRENCY) {
-                    Some(format!("{}-PERPETUAL", base.as_ref()))
+                Ok(format!("{}-PERPETUAL", self.denormalize_base_quote(base, quote)))
+            },
+            
+            InstrumentType::Index { base, quote, kind } => {
+                let index_name = format!("{}_{}", base.as_ref(), quote.as_ref()).to_lowercase();
+                match kind {
+                    IndexKind::Price => Ok(format!("{}{}", PRICE_INDEX_PREFIX, index_name)),
+                    IndexKind::Mark => Ok(format!("{}{}", MARK_PRICE_PREFIX, index_name)),
+                    // DVOL is only published against USD
+                    IndexKind::Volatility if quote.as_ref().eq_ignore_ascii_case(DEFAULT_QUOTE_CURRENCY) => 
+                        Ok(format!("{}-{}", base.as_ref(), VOLATILITY_INDEX_SUFFIX)),
+                    IndexKind::Volatility => 
+                        Err(InstrumentError::unsupported_instrument_type(Exchange::Deribit, &instrument.instrument_type)),
+                }
+            },
+            
+            InstrumentType::Combo { kind, legs } => {
+                // Combos are only listed on the inverse (USD) contracts
+                if legs.iter().any(|leg| !leg.instrument_type.quote().eq_ignore_ascii_case(DEFAULT_QUOTE_CURRENCY)) {
+                    return Err(InstrumentError::unsupported_instrument_type(Exchange::Deribit, &instrument.instrument_type));
+                }
+                
+                let (expiries, strikes) = kind.decompose_legs(legs)
+                    .ok_or_else(|| InstrumentError::unsupported_instrument_type(Exchange::Deribit, &instrument.instrument_type))?;
+                
+                let expiries = expiries.iter()
+                    .map(|expiry| match expiry {
+                        Some(expiry) => denormalize_expiry(expiry, DEFAULT_EXPIRY_FORMAT),
+                        None => COMBO_PERPETUAL_LEG.to_string(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join("_");
+                
+                if strikes.is_empty() {
+                    Ok(format!("{}-{}-{}", instrument.instrument_type.base(), kind, expiries))
                 } else {
-                    Some(format!("{}_{}-PERPETUAL", 
-                        base.as_ref(), 
-                        quote.as_ref()))
+                    let strikes = strikes.iter()
+                        .map(|strike| strike.format_with_separator(STRIKE_DECIMAL_SEPARATOR))
+                        .collect::<Vec<_>>()
+                        .join("_");
+                    Ok(format!("{}-{}-{}-{}", instrument.instrument_type.base(), kind, expiries, strikes))
                 }
             }
+            
+            InstrumentType::Move { .. } | InstrumentType::PerpetualOption { .. } =>
+                Err(InstrumentError::unsupported_instrument_type(Exchange::Deribit, &instrument.instrument_type)),
         }
     }
+
+
+    fn settlement_time(&self) -> NaiveTime {
+        SETTLEMENT_TIME
+    }
+
+    fn contract_style(&self, instrument_type: &InstrumentType) -> ContractStyle {
+        match instrument_type {
+            // Combos settle like their legs
+            InstrumentType::Combo { legs, .. } => legs.first()
+                .map_or(ContractStyle::Linear, |leg| self.contract_style(&leg.instrument_type)),
+            
+            // USD quoted futures, perpetuals and options are inverse
+            InstrumentType::Future { quote, .. } | InstrumentType::Perpetual { quote, .. } | InstrumentType::Option { quote, .. }
+                if quote.as_ref().eq_ignore_ascii_case(DEFAULT_QUOTE_CURRENCY) => ContractStyle::Inverse,
+            
+            _ => ContractStyle::Linear,
+        }
+    }
+
+    fn contract_spec(&self, instrument: &Instrument) -> InstrumentResult<ContractSpec> {
+        let instrument_type = match &instrument.instrument_type {
+            // Combos are sized like their legs
+            InstrumentType::Combo { legs, .. } => legs.first().map_or(&instrument.instrument_type, |leg| &leg.instrument_type),
+            instrument_type => instrument_type,
+        };
+        let base = Currency::new(Cow::Owned(instrument_type.base().to_string()));
+        let quote = Currency::new(Cow::Owned(instrument_type.quote().to_string()));
+        
+        Ok(match (instrument.contract_style(), instrument_type) {
+            // Inverse futures and perpetuals are sized in USD
+            (ContractStyle::Inverse, InstrumentType::Future { .. } | InstrumentType::Perpetual { .. }) => {
+                let face_value = if base.as_ref() == "BTC" { BTC_INVERSE_CONTRACT_SIZE } else { 1 };
+                ContractSpec::inverse(base, Decimal::from(face_value))
+            },
+            
+            // Inverse options settle in the base currency, one contract per base unit
+            (ContractStyle::Inverse, _) => ContractSpec::inverse(base, Decimal::from(1)),
+            
+            _ => ContractSpec::linear(quote, Decimal::from(1)),
+        })
+    }
 }
 
 #[cfg(test)]
 mod deribit_normalize_tests{
     use std::borrow::Cow;
 
-    use crate::{exchange::deribit::DeribitHandler, Currency, Exchange, ExchangeHandler, Instrument, InstrumentType, MarketType, OptionKind};
+    use chrono::NaiveDate;
+
+    use super::SETTLEMENT_TIME;
+    use crate::{exchange::deribit::DeribitHandler, ComboKind, ComboLeg, ContractSpec, ContractStyle, Currency, Decimal, Exchange, ExchangeHandler, IndexKind, Instrument, InstrumentError, Expiry, InstrumentType, MarketType, OptionKind, Strike};
 
     #[test]
     fn test_normalize_future() {
@@ -176,7 +346,7 @@ mod deribit_normalize_tests{
             InstrumentType::Future{
                 base: Currency::new(Cow::Borrowed("BTC")), 
                 quote: Currency::new(Cow::Borrowed("USD")), 
-                expiry: Cow::Borrowed("20250328")
+                expiry: Expiry::new(NaiveDate::from_ymd_opt(2025, 3, 28).unwrap(), SETTLEMENT_TIME)
             });
         let result = exchange.normalize(MarketType::OrderBook,instrument_name);
         println!("{:?}", result);
@@ -194,14 +364,47 @@ mod deribit_normalize_tests{
             InstrumentType::Option{
                 base: Currency::new(Cow::Borrowed("BTC")), 
                 quote: Currency::new(Cow::Borrowed("USD")), 
-                expiry: Cow::Borrowed("20250328"),
-                strike: 100000, 
+                expiry: Expiry::new(NaiveDate::from_ymd_opt(2025, 3, 28).unwrap(), SETTLEMENT_TIME),
+                strike: Strike::from(100000), 
                 kind: OptionKind::Call});
         let result = exchange.normalize(MarketType::OrderBook,instrument_name);
         println!("{:?}", result);
         assert_eq!(result, Some(expected_instrument));
     }
 
+    #[test]
+    fn test_normalize_option_fractional_strike() {
+        let instrument_name = "XRP-30MAY25-2d5-C";
+        let exchange = DeribitHandler;
+        let result = exchange.normalize(MarketType::OrderBook, instrument_name);
+        match result.map(|i| i.instrument_type) {
+            Some(InstrumentType::Option { strike, .. }) => assert_eq!(strike, Strike::new(25, 1).unwrap()),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_normalize_linear_option() {
+        let instrument_name = "BTC_USDC-28MAR25-100000-C";
+        let exchange = DeribitHandler;
+        let expected_instrument = Instrument::new(
+            Exchange::Deribit, 
+            MarketType::OrderBook, 
+            InstrumentType::Option{
+                base: Currency::new(Cow::Borrowed("BTC")), 
+                quote: Currency::new(Cow::Borrowed("USDC")), 
+                expiry: Expiry::new(NaiveDate::from_ymd_opt(2025, 3, 28).unwrap(), SETTLEMENT_TIME),
+                strike: Strike::from(100000), 
+                kind: OptionKind::Call});
+        let result = exchange.normalize(MarketType::OrderBook, instrument_name).unwrap();
+        assert_eq!(result, expected_instrument);
+        assert_eq!(result.contract_spec().unwrap(), ContractSpec::linear(Currency::new(Cow::Borrowed("USDC")), Decimal::from(1)));
+        
+        let future = exchange.normalize(MarketType::OrderBook, "ETH_USDC-28MAR25").unwrap();
+        assert_eq!(future.instrument_type.quote().to_string(), "USDC");
+        assert_eq!(future.contract_spec().unwrap().style, ContractStyle::Linear);
+    }
+
     #[test]
     fn test_normalize_perpetual1() {
         let instrument_name = "BTC-PERPETUAL";
@@ -253,6 +456,116 @@ mod deribit_normalize_tests{
         assert_eq!(result, Some(expected_instrument));
     }
 
+    #[test]
+    fn test_normalize_call_spread() {
+        let instrument_name = "BTC-CS-28MAR25-90000_100000";
+        let exchange = DeribitHandler;
+        let expiry = Expiry::new(NaiveDate::from_ymd_opt(2025, 3, 28).unwrap(), SETTLEMENT_TIME);
+        let option = |strike: u64| InstrumentType::Option{
+            base: Currency::new(Cow::Borrowed("BTC")), 
+            quote: Currency::new(Cow::Borrowed("USD")), 
+            expiry,
+            strike: Strike::from(strike), 
+            kind: OptionKind::Call};
+        let expected_instrument = Instrument::new(
+            Exchange::Deribit, 
+            MarketType::OrderBook, 
+            InstrumentType::Combo{
+                kind: ComboKind::CallSpread,
+                legs: vec![
+                    ComboLeg { instrument_type: option(90000), ratio: 1 },
+                    ComboLeg { instrument_type: option(100000), ratio: -1 },
+                ]});
+        let result = exchange.normalize(MarketType::OrderBook, instrument_name);
+        assert_eq!(result, Some(expected_instrument));
+    }
+
+    #[test]
+    fn test_normalize_future_spread() {
+        let instrument_name = "BTC-FS-28MAR25_PERP";
+        let exchange = DeribitHandler;
+        let result = exchange.normalize(MarketType::OrderBook, instrument_name).unwrap();
+        let legs = result.legs().unwrap();
+        assert_eq!(legs.len(), 2);
+        assert_eq!(legs[0].0, exchange.normalize(MarketType::OrderBook, "BTC-28MAR25").unwrap());
+        assert_eq!(legs[0].1, 1);
+        assert_eq!(legs[1].0, exchange.normalize(MarketType::OrderBook, "BTC-PERPETUAL").unwrap());
+        assert_eq!(legs[1].1, -1);
+    }
+
+    #[test]
+    fn test_normalize_straddle() {
+        let instrument_name = "BTC-STRD-28MAR25-100000";
+        let exchange = DeribitHandler;
+        let result = exchange.normalize(MarketType::OrderBook, instrument_name).unwrap();
+        let legs: Vec<_> = result.legs().unwrap().into_iter()
+            .map(|(leg, ratio)| (exchange.denormalize(&leg).unwrap(), ratio))
+            .collect();
+        assert_eq!(legs, vec![
+            (String::from("BTC-28MAR25-100000-C"), 1),
+            (String::from("BTC-28MAR25-100000-P"), 1),
+        ]);
+    }
+
+    #[test]
+    fn test_normalize_invalid_combo_legs() {
+        let exchange = DeribitHandler;
+        assert_eq!(exchange.normalize(MarketType::OrderBook, "BTC-CS-28MAR25-90000"), None);
+        assert_eq!(exchange.normalize(MarketType::OrderBook, "BTC-FS-28MAR25_28MAR25"), None);
+    }
+
+    #[test]
+    fn test_contract_spec_inverse_vs_linear() {
+        let exchange = DeribitHandler;
+        let inverse = exchange.normalize(MarketType::OrderBook, "BTC-PERPETUAL").unwrap();
+        assert_eq!(inverse.contract_spec().unwrap(), ContractSpec::inverse(Currency::new(Cow::Borrowed("BTC")), Decimal::from(10)));
+        
+        let linear = exchange.normalize(MarketType::OrderBook, "BTC_USDC-PERPETUAL").unwrap();
+        assert_eq!(linear.contract_spec().unwrap(), ContractSpec::linear(Currency::new(Cow::Borrowed("USDC")), Decimal::from(1)));
+        
+        let option = exchange.normalize(MarketType::OrderBook, "ETH-28MAR25-3000-P").unwrap();
+        let spec = option.contract_spec().unwrap();
+        assert_eq!(spec.style, ContractStyle::Inverse);
+        assert_eq!(spec.settlement, Currency::new(Cow::Borrowed("ETH")));
+        
+        let combo = exchange.normalize(MarketType::OrderBook, "ETH-FS-28MAR25_PERP").unwrap();
+        assert_eq!(combo.contract_spec().unwrap(), ContractSpec::inverse(Currency::new(Cow::Borrowed("ETH")), Decimal::from(1)));
+    }
+
+    #[test]
+    fn test_normalize_index() {
+        let exchange = DeribitHandler;
+        let index = |base: &'static str, kind| Some(Instrument::new(
+            Exchange::Deribit, 
+            MarketType::Ticker, 
+            InstrumentType::Index{
+                base: Currency::new(Cow::Borrowed(base)), 
+                quote: Currency::new(Cow::Borrowed("USD")), 
+                kind,
+            }));
+        assert_eq!(exchange.normalize(MarketType::Ticker, "deribit_price_index.btc_usd"), index("BTC", IndexKind::Price));
+        assert_eq!(exchange.normalize(MarketType::Ticker, "markprice.options.eth_usd"), index("ETH", IndexKind::Mark));
+        assert_eq!(exchange.normalize(MarketType::Ticker, "BTC-DVOL"), index("BTC", IndexKind::Volatility));
+        assert_eq!(exchange.normalize(MarketType::Ticker, "deribit_volume.btc_usd"), None);
+    }
+
+    #[test]
+    fn test_try_normalize_errors() {
+        let exchange = DeribitHandler;
+        let error = |name: &str| exchange.try_normalize(MarketType::OrderBook, name).unwrap_err();
+        
+        assert!(matches!(error("BTC-28XYZ25-100000-C"), 
+            InstrumentError::InvalidExpiry { exchange: Exchange::Deribit, value } if value == "28XYZ25"));
+        assert!(matches!(error("BTC-28MAR25-10O000-C"), 
+            InstrumentError::InvalidStrike { exchange: Exchange::Deribit, value } if value == "10O000"));
+        assert!(matches!(error("BTC-28MAR25-100000-X"), 
+            InstrumentError::InvalidOptionKind { exchange: Exchange::Deribit, value } if value == "X"));
+        assert!(matches!(error("B$C-PERPETUAL"), 
+            InstrumentError::InvalidBase { exchange: Exchange::Deribit, value } if value == "B$C"));
+        assert!(matches!(error("BTC-USD-20250528"), 
+            InstrumentError::UnrecognizedInstrument { exchange: Exchange::Deribit, .. }));
+    }
+
     #[test]
     fn test_normalize_unknown() {
         let instrument_name = "BTC-USD-20250528";
@@ -265,14 +578,17 @@ mod deribit_normalize_tests{
 mod deribit_denormalize_tests{
     use std::borrow::Cow;
 
-    use crate::{exchange::deribit::DeribitHandler, Currency, Exchange, ExchangeHandler, Instrument, InstrumentType, MarketType, OptionKind};
+    use chrono::NaiveDate;
+
+    use super::SETTLEMENT_TIME;
+    use crate::{exchange::deribit::DeribitHandler, parse_standard_format, Currency, Exchange, ExchangeHandler, Instrument, InstrumentError, Expiry, InstrumentType, MarketType, OptionKind, Strike};
 
     #[test]
     fn test_denorm_future() {
         let instrument = Instrument::new(Exchange::Deribit, MarketType::OrderBook, InstrumentType::Future{
             base: Currency::new(Cow::Borrowed("BTC")), 
             quote: Currency::new(Cow::Borrowed("USD")),
-            expiry: Cow::Borrowed("20250328")});
+            expiry: Expiry::new(NaiveDate::from_ymd_opt(2025, 3, 28).unwrap(), SETTLEMENT_TIME)});
         let exchange = DeribitHandler;
         assert_eq!(exchange.denormalize(&instrument), Some(String::from("BTC-28MAR25")));
     }
@@ -285,13 +601,28 @@ mod deribit_denormalize_tests{
             InstrumentType::Option{
                 base: Currency::new(Cow::Borrowed("BTC")), 
                 quote: Currency::new(Cow::Borrowed("USD")), 
-                expiry: Cow::Borrowed("20250328"), 
-                strike: 100000, 
+                expiry: Expiry::new(NaiveDate::from_ymd_opt(2025, 3, 28).unwrap(), SETTLEMENT_TIME), 
+                strike: Strike::from(100000), 
                 kind: OptionKind::Call});
         let exchange = DeribitHandler;
         assert_eq!(exchange.denormalize(&instrument), Some(String::from("BTC-28MAR25-100000-C")));
     }
 
+    #[test]
+    fn test_denorm_option_fractional_strike() {
+        let instrument = Instrument::new(
+            Exchange::Deribit, 
+            MarketType::OrderBook, 
+            InstrumentType::Option{
+                base: Currency::new(Cow::Borrowed("SOL")), 
+                quote: Currency::new(Cow::Borrowed("USD")), 
+                expiry: Expiry::new(NaiveDate::from_ymd_opt(2025, 3, 28).unwrap(), SETTLEMENT_TIME), 
+                strike: Strike::new(1425, 1).unwrap(), 
+                kind: OptionKind::Put});
+        let exchange = DeribitHandler;
+        assert_eq!(exchange.denormalize(&instrument), Some(String::from("SOL-28MAR25-142d5-P")));
+    }
+
     #[test]
     fn test_denorm_perp1() {
         let instrument = Instrument::new(
@@ -313,6 +644,66 @@ mod deribit_denormalize_tests{
         let exchange = DeribitHandler;
         assert_eq!(exchange.denormalize(&instrument), Some(String::from("SOL_USDC-PERPETUAL")));
     }
+    #[test]
+    fn test_denorm_linear() {
+        let exchange = DeribitHandler;
+        for instrument_name in ["BTC_USDC-28MAR25-100000-C", "XRP_USDC-30MAY25-2d5-P", "ETH_USDC-28MAR25", "ETH_USDC-PERPETUAL"] {
+            let instrument = exchange.normalize(MarketType::OrderBook, instrument_name).unwrap();
+            assert_eq!(exchange.denormalize(&instrument), Some(String::from(instrument_name)));
+        }
+    }
+
+    #[test]
+    fn test_denorm_combo() {
+        let exchange = DeribitHandler;
+        for instrument_name in [
+            "BTC-CS-28MAR25-90000_100000",
+            "BTC-FS-28MAR25_27JUN25",
+            "ETH-IC-28MAR25-2000_2200_2800_3000",
+            "ETH-PCAL-28MAR25_27JUN25-2500",
+            "SOL-RR-28MAR25-120_142d5",
+        ] {
+            let instrument = exchange.normalize(MarketType::OrderBook, instrument_name).unwrap();
+            assert_eq!(exchange.denormalize(&instrument), Some(String::from(instrument_name)));
+        }
+    }
+
+    #[test]
+    fn test_denorm_linear_combo() {
+        let exchange = DeribitHandler;
+        let linear = "c.CS_+1o:BTC-USDC-20250328-90000-C_-1o:BTC-USDC-20250328-100000-C";
+        let instrument = Instrument::new(Exchange::Deribit, MarketType::OrderBook, linear.parse().unwrap());
+        assert!(matches!(exchange.try_denormalize(&instrument), 
+            Err(InstrumentError::UnsupportedInstrumentType { exchange: Exchange::Deribit, .. })));
+        assert!(parse_standard_format(&format!("o.{}.deribit", linear)).is_err());
+        
+        let instrument = parse_standard_format("o.c.CS_+1o:BTC-USD-20250328-90000-C_-1o:BTC-USD-20250328-100000-C.deribit").unwrap();
+        assert_eq!(exchange.denormalize(&instrument), Some(String::from("BTC-CS-28MAR25-90000_100000")));
+    }
+
+    #[test]
+    fn test_denorm_index() {
+        let exchange = DeribitHandler;
+        for instrument_name in ["deribit_price_index.sol_usdc", "markprice.options.btc_usd", "ETH-DVOL"] {
+            let instrument = exchange.normalize(MarketType::Ticker, instrument_name).unwrap();
+            assert_eq!(exchange.denormalize(&instrument), Some(String::from(instrument_name)));
+        }
+    }
+
+    #[test]
+    fn test_try_denormalize_wrong_exchange() {
+        let instrument = Instrument::new(
+            Exchange::Aevo, 
+            MarketType::OrderBook, 
+            InstrumentType::Perpetual{
+                base: Currency::new(Cow::Borrowed("BTC")), 
+                quote: Currency::new(Cow::Borrowed("USD")), 
+            });
+        let exchange = DeribitHandler;
+        assert!(matches!(exchange.try_denormalize(&instrument), 
+            Err(InstrumentError::ExchangeMismatch { expected: Exchange::Deribit, found: Exchange::Aevo })));
+    }
+
     #[test]
     fn test_denorm_spot() {
         let instrument = Instrument::new(
@@ -1,109 +1,192 @@
 use std::borrow::Cow;
 
-use tracing::error;
+use chrono::NaiveTime;
 
-use crate::{denormalize_expiry, normalize_expiry, parse_expiry_date, Currency, Exchange, ExchangeHandler, Instrument, InstrumentType, MarketType, OptionKind};
+use crate::exchange::parse_listed_quote;
+use crate::{denormalize_expiry, normalize_expiry, parse_base, parse_expiry_date, ContractSpec, ContractStyle, Currency, Decimal, Exchange, ExchangeHandler, Instrument, InstrumentError, InstrumentResult, InstrumentType, MarketType, OptionKind, Strike};
 
-const LOG_CTX: &str = "normify::exchange#derive";
-const DEFAULT_QUOTE_CURRENCY: &str = "usd";
+/// Every market is margined and settled in USDC
+const SETTLEMENT_CURRENCY: &str = "USDC";
+/// Perpetual and option names carry no quote (e.g., BTC-PERP, BTC-20250328-100000-C). Their
+/// prices and strikes are in USD, marked against Derive's USD index, while margin and
+/// settlement are in USDC, so their quote is USD rather than the settlement currency.
+const DEFAULT_QUOTE_CURRENCY: &str = "USD";
 const DEFAULT_EXPIRY_FORMAT: &str = "%Y%m%d";
+const STRIKE_DECIMAL_SEPARATOR: char = '.';
+/// Quote currencies of spot ERC20 markets (e.g., ETH-USDC)
+const SPOT_QUOTE_CURRENCIES: [&str; 1] = [SETTLEMENT_CURRENCY];
+/// Suffix of perpetuals (e.g., BTC-PERP)
+const PERPETUAL_SUFFIX: &str = "PERP";
+/// Expiring contracts settle at 08:00 UTC
+const SETTLEMENT_TIME: NaiveTime = NaiveTime::from_hms_opt(8, 0, 0).unwrap();
+
+/// Handler for Derive perpetuals (e.g., BTC-PERP), options (e.g., BTC-20250328-100000-C)
+/// and spot ERC20 markets (e.g., ETH-USDC)
 pub struct DeriveHandler;
-        
+
 // Create a static instance to avoid allocations
 pub static DERIVE_HANDLER: DeriveHandler = DeriveHandler;
 
+impl DeriveHandler {
+    fn is_default_quote(&self, quote: &Currency) -> bool {
+        quote.as_ref().eq_ignore_ascii_case(DEFAULT_QUOTE_CURRENCY)
+    }
+}
+
+/// Handler for a Derive portfolio margin sub-account, which only trades the options of its own
+/// currency. Perpetuals and spot markets are handled like `DeriveHandler`.
+pub struct DeriveSubAccountHandler {
+    /// Currency of the options traded by the sub-account
+    option_currency: Currency,
+}
+
+impl DeriveSubAccountHandler {
+    pub fn new(option_currency: Currency) -> Self {
+        Self { option_currency }
+    }
+
+    /// Currency of the options traded by the sub-account
+    pub fn option_currency(&self) -> &Currency {
+        &self.option_currency
+    }
+}
+
+impl ExchangeHandler for DeriveSubAccountHandler {
+    fn try_normalize(&self, market_type: MarketType, instrument_name: &str) -> InstrumentResult<Instrument> {
+        let instrument = DERIVE_HANDLER.try_normalize(market_type, instrument_name)?;
+        if !self.supports_instrument_type(&instrument.instrument_type) {
+            return Err(InstrumentError::unrecognized(Exchange::Derive, instrument_name));
+        }
+        Ok(instrument)
+    }
+
+    fn try_denormalize(&self, instrument: &Instrument) -> InstrumentResult<String> {
+        if instrument.exchange == Exchange::Derive && !self.supports_instrument_type(&instrument.instrument_type) {
+            return Err(InstrumentError::unsupported_instrument_type(Exchange::Derive, &instrument.instrument_type));
+        }
+        DERIVE_HANDLER.try_denormalize(instrument)
+    }
+
+    fn supports_instrument_type(&self, instrument_type: &InstrumentType) -> bool {
+        match instrument_type {
+            InstrumentType::Option { base, .. } => *base == self.option_currency,
+            _ => DERIVE_HANDLER.supports_instrument_type(instrument_type),
+        }
+    }
+
+    fn settlement_time(&self) -> NaiveTime {
+        DERIVE_HANDLER.settlement_time()
+    }
+
+    fn contract_style(&self, instrument_type: &InstrumentType) -> ContractStyle {
+        DERIVE_HANDLER.contract_style(instrument_type)
+    }
+
+    fn contract_spec(&self, instrument: &Instrument) -> InstrumentResult<ContractSpec> {
+        DERIVE_HANDLER.contract_spec(instrument)
+    }
+}
+
 impl ExchangeHandler for DeriveHandler {
 
-    fn normalize(&self, market_type: MarketType, instrument_name: &str) -> Option<Instrument> {
+    fn try_normalize(&self, market_type: MarketType, instrument_name: &str) -> InstrumentResult<Instrument> {
 
-    
         let parts: Vec<&str> = instrument_name.split('-').collect();
     
-        match parts.as_slice() {
+        let instrument_type = match parts.as_slice() {
             // Perpetual: e.g., BTC-PERP
-            [base, "perp" | "PERP"] => {
-                Some(Instrument::new(
-                    Exchange::Derive, 
-                    market_type, 
-                    InstrumentType::Perpetual {
-                        base: Currency::new(Cow::Owned(base.to_string())), 
-                        quote: Currency::new(Cow::Owned(DEFAULT_QUOTE_CURRENCY.to_string()))
-                    }
-                ))
+            [base, perpetual] if perpetual.eq_ignore_ascii_case(PERPETUAL_SUFFIX) => {
+                InstrumentType::Perpetual {
+                    base: parse_base(Exchange::Derive, base)?, 
+                    quote: Currency::new(Cow::Borrowed(DEFAULT_QUOTE_CURRENCY))
+                }
+            }
+
+            // Spot ERC20 market: e.g., ETH-USDC
+            [base, quote] => {
+                InstrumentType::Spot {
+                    base: parse_base(Exchange::Derive, base)?,
+                    quote: parse_listed_quote(Exchange::Derive, instrument_name, quote, &SPOT_QUOTE_CURRENCIES)?,
+                }
             }
     
             // Option: e.g., BTC-20250328-100000-C
-            [base, expiry, strike_str, kind_str] => {
+            [base, expiry_str, strike_str, kind_str] => {
+                let base = parse_base(Exchange::Derive, base)?;
+                
                 // Validate the expiry date
-                if parse_expiry_date(expiry, DEFAULT_EXPIRY_FORMAT).is_none() {
-                    error!(name: LOG_CTX, "normalize::Invalid expiry date format: {}", expiry);
-                    return None;
-                }
+                let expiry = parse_expiry_date(expiry_str, DEFAULT_EXPIRY_FORMAT)
+                    .and_then(|_| normalize_expiry(expiry_str, self.settlement_time()))
+                    .ok_or_else(|| InstrumentError::invalid_expiry(Exchange::Derive, expiry_str))?;
                 
                 // Parse strike price
-                let strike = match strike_str.parse::<u64>() {
-                    Ok(s) => s,
-                    Err(_) => {
-                        error!(name: LOG_CTX, "normalize::Invalid strike price: {}", strike_str);
-                        return None;
-                    }
-                };
+                let strike = Strike::parse_with_separator(strike_str, STRIKE_DECIMAL_SEPARATOR)
+                    .ok_or_else(|| InstrumentError::invalid_strike(Exchange::Derive, strike_str))?;
                 
                 // Parse option kind
-                let kind = match OptionKind::try_from(*kind_str) {
-                    Ok(k) => k,
-                    Err(e) => {
-                        error!(name: LOG_CTX, "normalize::Invalid option kind: {}", e);
-                        return None;
-                    }
-                };
-                
-                let normalized_expiry = normalize_expiry(expiry)?;
+                let kind = OptionKind::try_from(*kind_str)
+                    .map_err(|_| InstrumentError::invalid_option_kind(Exchange::Derive, kind_str))?;
                 
-                Some(Instrument::new(
-                    Exchange::Derive,
-                    market_type,
-                    InstrumentType::Option {
-                        base: Currency::new(Cow::Owned(base.to_string())), 
-                        quote: Currency::new(Cow::Owned(DEFAULT_QUOTE_CURRENCY.to_string())), 
-                        expiry: Cow::Owned(normalized_expiry), 
-                        strike, 
-                        kind
-                    }
-                ))
+                InstrumentType::Option {
+                    base, 
+                    quote: Currency::new(Cow::Borrowed(DEFAULT_QUOTE_CURRENCY)), 
+                    expiry, 
+                    strike, 
+                    kind
+                }
             } 
             // No matching format
-            _ => {
-                error!(name: LOG_CTX, "normalize::Unexpected instrument format: {:?}", instrument_name);
-                None
-            }
+            _ => return Err(InstrumentError::unrecognized(Exchange::Derive, instrument_name)),
+        };
+
+        // Funding is only published for perpetuals
+        if market_type == MarketType::Funding && !matches!(instrument_type, InstrumentType::Perpetual { .. }) {
+            return Err(InstrumentError::UnsupportedMarketType { exchange: Exchange::Derive, market_type });
         }
+
+        Ok(Instrument::new(Exchange::Derive, market_type, instrument_type))
     }
 
-    fn denormalize(&self, instrument: &Instrument) -> Option<String> {
+    fn try_denormalize(&self, instrument: &Instrument) -> InstrumentResult<String> {
 
         if instrument.exchange != Exchange::Derive {
-            error!(name: LOG_CTX, "denormalize::Attempted to use Derive handler for {:?}", instrument.exchange);
-            return None;
+            return Err(InstrumentError::ExchangeMismatch { expected: Exchange::Derive, found: instrument.exchange });
         }
         if !self.supports_instrument_type(&instrument.instrument_type) {
-            error!(name: LOG_CTX, "denormalize::Instrument Type {:?} is unsupported", &instrument.instrument_type);
-            return None;
+            return Err(InstrumentError::unsupported_instrument_type(Exchange::Derive, &instrument.instrument_type));
+        }
+        if instrument.market_type == MarketType::Funding && !matches!(instrument.instrument_type, InstrumentType::Perpetual { .. }) {
+            return Err(InstrumentError::UnsupportedMarketType { exchange: Exchange::Derive, market_type: instrument.market_type.clone() });
         }
         match &instrument.instrument_type {
-            InstrumentType::Option{base, quote: _, expiry, strike, kind} => {
-                let denormalize_expiry = denormalize_expiry(&expiry, DEFAULT_EXPIRY_FORMAT);
-                Some(format!("{}-{}-{}-{}", base.as_ref(), denormalize_expiry, strike, kind.to_string()))
+            InstrumentType::Option{base, quote, expiry, strike, kind} if self.is_default_quote(quote) => {
+                let denormalize_expiry = denormalize_expiry(expiry, DEFAULT_EXPIRY_FORMAT);
+                Ok(format!("{}-{}-{}-{}", base.as_ref(), denormalize_expiry, strike.format_with_separator(STRIKE_DECIMAL_SEPARATOR), kind))
             },
-            InstrumentType::Perpetual{base, quote: _} => Some(format!("{}-PERP", base.as_ref())),
-            _ => None
+            InstrumentType::Perpetual{base, quote} if self.is_default_quote(quote) => Ok(format!("{}-{}", base.as_ref(), PERPETUAL_SUFFIX)),
+            InstrumentType::Spot{base, quote} if SPOT_QUOTE_CURRENCIES.contains(&quote.as_ref()) => {
+                Ok(format!("{}-{}", base.as_ref(), quote.as_ref()))
+            },
+            _ => Err(InstrumentError::unsupported_instrument_type(Exchange::Derive, &instrument.instrument_type)),
         }
     }
 
     fn supports_instrument_type(&self, instrument_type: &InstrumentType) -> bool {
         matches!(instrument_type, InstrumentType::Option { base: _, quote: _, expiry: _, strike: _, kind: _ })
         ||
-        matches!(instrument_type, InstrumentType::Perpetual { base: _, quote: _ })
+        matches!(instrument_type, InstrumentType::Perpetual { base: _, quote: _ } | InstrumentType::Spot { base: _, quote: _ })
+    }
+
+    fn settlement_time(&self) -> NaiveTime {
+        SETTLEMENT_TIME
+    }
+
+    fn contract_spec(&self, instrument: &Instrument) -> InstrumentResult<ContractSpec> {
+        Ok(match &instrument.instrument_type {
+            // Spot markets exchange the token itself against the quote
+            InstrumentType::Spot { quote, .. } => ContractSpec::linear(quote.clone(), Decimal::from(1)),
+            _ => ContractSpec::linear(Currency::new(Cow::Borrowed(SETTLEMENT_CURRENCY)), Decimal::from(1)),
+        })
     }
 }
 
@@ -111,7 +194,10 @@ impl ExchangeHandler for DeriveHandler {
 mod derive_normalize_tests{
     use std::borrow::Cow;
 
-    use crate::{exchange::derive::DeriveHandler, Currency, Exchange, ExchangeHandler, Instrument, InstrumentType, MarketType, OptionKind};
+    use chrono::NaiveDate;
+
+    use super::SETTLEMENT_TIME;
+    use crate::{exchange::derive::{DeriveHandler, DeriveSubAccountHandler, DERIVE_HANDLER}, parse_standard_format, Currency, Exchange, ExchangeHandler, Instrument, InstrumentError, Expiry, InstrumentType, MarketType, OptionKind, Strike};
     #[test]
     fn test_normalize_option() {
         let instrument_name = "BTC-20250328-100000-C".to_string();
@@ -123,8 +209,8 @@ mod derive_normalize_tests{
             InstrumentType::Option{
                 base: Currency::new(Cow::Borrowed("BTC")), 
                 quote: Currency::new(Cow::Borrowed("USD")), 
-                expiry: Cow::Borrowed("20250328"), 
-                strike: 100000, 
+                expiry: Expiry::new(NaiveDate::from_ymd_opt(2025, 3, 28).unwrap(), SETTLEMENT_TIME), 
+                strike: Strike::from(100000), 
                 kind: OptionKind::Call
             });
         let result = exchange.normalize(MarketType::OrderBook, &instrument_name);
@@ -147,11 +233,65 @@ mod derive_normalize_tests{
         assert_eq!(exchange.normalize(MarketType::OrderBook, &instrument_name), Some(expected_instrument));
     }
 
+    #[test]
+    fn test_normalize_spot() {
+        let exchange = DeriveHandler;
+        let expected_instrument = Instrument::new(
+            Exchange::Derive, 
+            MarketType::PublicTrade, 
+            InstrumentType::Spot{
+                base: Currency::new(Cow::Borrowed("ETH")), 
+                quote: Currency::new(Cow::Borrowed("USDC"))
+            });
+        let instrument = exchange.normalize(MarketType::PublicTrade, "ETH-USDC").unwrap();
+        assert_eq!(instrument, expected_instrument);
+        assert_eq!(exchange.denormalize(&instrument), Some(String::from("ETH-USDC")));
+    }
+
+    #[test]
+    fn test_normalize_funding() {
+        let exchange = DeriveHandler;
+        assert_eq!(exchange.normalize(MarketType::Funding, "ETH-PERP").unwrap().to_string(), "f.p.ETH-USD.derive");
+        assert!(matches!(exchange.try_normalize(MarketType::Funding, "ETH-USDC"), 
+            Err(InstrumentError::UnsupportedMarketType { exchange: Exchange::Derive, market_type: MarketType::Funding })));
+        assert_eq!(exchange.normalize(MarketType::Funding, "BTC-20250328-100000-C"), None);
+    }
+
+    #[test]
+    fn test_normalize_option_sub_account() {
+        let exchange = DeriveSubAccountHandler::new(Currency::new(Cow::Borrowed("ETH")));
+        assert!(exchange.normalize(MarketType::OrderBook, "ETH-20250328-3000-P").is_some());
+        assert!(matches!(exchange.try_normalize(MarketType::OrderBook, "BTC-20250328-100000-C"), 
+            Err(InstrumentError::UnrecognizedInstrument { exchange: Exchange::Derive, .. })));
+        // Perpetuals and spot markets are not split by currency
+        assert!(exchange.normalize(MarketType::OrderBook, "BTC-PERP").is_some());
+        
+        let option = DERIVE_HANDLER.normalize(MarketType::OrderBook, "BTC-20250328-100000-C").unwrap();
+        assert_eq!(exchange.denormalize(&option), None);
+        assert_eq!(exchange.option_currency().as_ref(), "ETH");
+    }
+
+    #[test]
+    fn test_option_sub_account_only_through_its_handler() {
+        let exchange = DeriveSubAccountHandler::new(Currency::new(Cow::Borrowed("ETH")));
+
+        // Unless this handler is registered, the standard format resolves to the built-in
+        // handler, which accepts every currency
+        let option = parse_standard_format("o.o.BTC-USD-20250328-100000-C.derive").unwrap();
+        assert!(Exchange::Derive.handler().try_denormalize(&option).is_ok());
+        assert!(matches!(exchange.try_denormalize(&option),
+            Err(InstrumentError::UnsupportedInstrumentType { exchange: Exchange::Derive, .. })));
+        assert!(!exchange.supports_instrument_type(&option.instrument_type));
+    }
+
     #[test]
     fn test_normalize_unknown() {
         let instrument_name = "BTC-28MAR25-100000-C".to_string();
         let exchange = DeriveHandler;
         assert_eq!(exchange.normalize(MarketType::OrderBook, &instrument_name), None);
+        // Other venues' futures and perpetuals are not spot pairs
+        assert_eq!(exchange.normalize(MarketType::OrderBook, "BTC-28MAR25"), None);
+        assert_eq!(exchange.normalize(MarketType::OrderBook, "ETH-USD"), None);
     }
 }
 
@@ -159,7 +299,10 @@ mod derive_normalize_tests{
 mod derive_denormalize_tests{
     use std::borrow::Cow;
 
-    use crate::{exchange::derive::DeriveHandler, Currency, Exchange, ExchangeHandler, Instrument, InstrumentType, MarketType, OptionKind};
+    use chrono::NaiveDate;
+
+    use super::SETTLEMENT_TIME;
+    use crate::{exchange::derive::DeriveHandler, Currency, Exchange, ExchangeHandler, Instrument, Expiry, InstrumentType, MarketType, OptionKind, Strike};
 
     #[test]
     fn test_denorm_option() {
@@ -169,8 +312,8 @@ mod derive_denormalize_tests{
             InstrumentType::Option{
                 base: Currency::new(Cow::Borrowed("BTC")), 
                 quote: Currency::new(Cow::Borrowed("USD")), 
-                expiry: Cow::Borrowed("20250328"), 
-                strike: 100000, 
+                expiry: Expiry::new(NaiveDate::from_ymd_opt(2025, 3, 28).unwrap(), SETTLEMENT_TIME), 
+                strike: Strike::from(100000), 
                 kind: OptionKind::Call
             });
         let exchange = DeriveHandler;
@@ -189,4 +332,17 @@ mod derive_denormalize_tests{
         let exchange = DeriveHandler;
         assert_eq!(exchange.denormalize(&instrument), Some(String::from("BTC-PERP")));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_denorm_perp_quote() {
+        let instrument = Instrument::new(
+            Exchange::Derive, 
+            MarketType::OrderBook, 
+            InstrumentType::Perpetual{
+                base: Currency::new(Cow::Borrowed("BTC")), 
+                quote: Currency::new(Cow::Borrowed("USDT")), 
+            });
+        let exchange = DeriveHandler;
+        assert_eq!(exchange.denormalize(&instrument), None);
+    }
+}
@@ -1,59 +1,171 @@
 use std::borrow::Cow;
 
-use tracing::error;
+use crate::exchange::parse_listed_quote;
+use crate::{parse_base, ContractSpec, Currency, Decimal, Exchange, ExchangeHandler, Instrument, InstrumentError, InstrumentResult, InstrumentType, MarketType};
 
-use crate::{Currency, Exchange, ExchangeHandler, Instrument, InstrumentType, MarketType};
+/// Every market is margined and settled in USDC
+const SETTLEMENT_CURRENCY: &str = "USDC";
+/// Perpetuals are quoted in USD (e.g., BTC-USD)
+const QUOTE_CURRENCIES: [&str; 1] = ["USD"];
+/// Atomic resolution of USDC, in which quote quantums are counted
+const QUOTE_ATOMIC_RESOLUTION: i32 = -6;
 
-const LOG_CTX: &str = "normify::exchange#dydx";
+/// A perpetual market, as published by the indexer `perpetualMarkets` endpoint
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DydxMarket {
+    /// Market ticker (e.g., BTC-USD)
+    pub ticker: String,
+    /// Numeric id of the market's order book
+    pub clob_pair_id: u32,
+    /// One quantum of size is 10^atomic_resolution units of the base
+    pub atomic_resolution: i32,
+    /// Scales subticks to quote quantums per base quantum
+    pub quantum_conversion_exponent: i32,
+}
+
+impl DydxMarket {
+    /// Size in base units = quantums * 10^size_exponent
+    pub fn size_exponent(&self) -> i32 {
+        self.atomic_resolution
+    }
+
+    /// Price in USDC = subticks * 10^price_exponent
+    pub fn price_exponent(&self) -> i32 {
+        self.quantum_conversion_exponent - self.atomic_resolution + QUOTE_ATOMIC_RESOLUTION
+    }
+}
+
+/// Perpetual markets listed on dYdX v4, keyed by both ticker and clob pair id
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct DydxMarkets {
+    markets: Vec<DydxMarket>,
+}
+
+impl DydxMarkets {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a market, replacing any market with the same ticker or clob pair id
+    pub fn insert(&mut self, market: DydxMarket) -> &mut Self {
+        self.markets.retain(|m| m.ticker != market.ticker && m.clob_pair_id != market.clob_pair_id);
+        self.markets.push(market);
+        self
+    }
+
+    /// Market listed under `ticker` (e.g., BTC-USD)
+    pub fn by_ticker(&self, ticker: &str) -> Option<&DydxMarket> {
+        self.markets.iter().find(|m| m.ticker.eq_ignore_ascii_case(ticker))
+    }
+
+    /// Market whose order book is `clob_pair_id`
+    pub fn by_clob_pair_id(&self, clob_pair_id: u32) -> Option<&DydxMarket> {
+        self.markets.iter().find(|m| m.clob_pair_id == clob_pair_id)
+    }
+}
+
+/// Handler for dYdX v4 perpetuals, named by ticker (e.g., BTC-USD)
 pub struct DydxHandler;
+
 // Create a static instance to avoid allocations
 pub static DYDX_HANDLER: DydxHandler = DydxHandler;
 
+/// Handler for dYdX v4 perpetuals that also resolves clob pair ids (e.g., 0 for BTC-USD) and
+/// scaling factors through the markets published by the indexer.
+pub struct DydxMarketsHandler {
+    markets: DydxMarkets,
+}
+
+impl DydxMarketsHandler {
+    pub fn new(markets: DydxMarkets) -> Self {
+        Self { markets }
+    }
+
+    pub fn markets(&self) -> &DydxMarkets {
+        &self.markets
+    }
+
+    /// Market metadata of a dYdX instrument, which carries its scaling factors
+    pub fn market(&self, instrument: &Instrument) -> Option<&DydxMarket> {
+        let ticker = DYDX_HANDLER.try_denormalize(instrument).ok()?;
+        self.markets.by_ticker(&ticker)
+    }
+
+    /// Clob pair id of a dYdX instrument
+    pub fn clob_pair_id(&self, instrument: &Instrument) -> Option<u32> {
+        self.market(instrument).map(|market| market.clob_pair_id)
+    }
+}
+
+impl ExchangeHandler for DydxMarketsHandler {
+    fn try_normalize(&self, market_type: MarketType, instrument_name: &str) -> InstrumentResult<Instrument> {
+        // Clob pair id: e.g., 0 for BTC-USD
+        let ticker = match instrument_name.parse::<u32>() {
+            Ok(clob_pair_id) => self.markets.by_clob_pair_id(clob_pair_id)
+                .map(|market| market.ticker.as_str())
+                .ok_or_else(|| InstrumentError::unrecognized(Exchange::Dydx, instrument_name))?,
+            Err(_) => instrument_name,
+        };
+        DYDX_HANDLER.try_normalize(market_type, ticker)
+    }
+
+    fn try_denormalize(&self, instrument: &Instrument) -> InstrumentResult<String> {
+        DYDX_HANDLER.try_denormalize(instrument)
+    }
+
+    fn supports_market_type(&self, market_type: &MarketType) -> bool {
+        DYDX_HANDLER.supports_market_type(market_type)
+    }
+
+    fn supports_instrument_type(&self, instrument_type: &InstrumentType) -> bool {
+        DYDX_HANDLER.supports_instrument_type(instrument_type)
+    }
+
+    fn contract_spec(&self, instrument: &Instrument) -> InstrumentResult<ContractSpec> {
+        DYDX_HANDLER.contract_spec(instrument)
+    }
+}
+
 impl ExchangeHandler for DydxHandler {
 
-    fn normalize(&self, market_type: MarketType, instrument_name: &str) -> Option<Instrument> {
+    fn try_normalize(&self, market_type: MarketType, instrument_name: &str) -> InstrumentResult<Instrument> {
 
         if !self.supports_market_type(&market_type) {
-            error!(name: LOG_CTX, "denormalize::Market Type is unsupported: {:?}", market_type);
-            return None;
+            return Err(InstrumentError::UnsupportedMarketType { exchange: Exchange::Dydx, market_type });
         }
         let parts: Vec<&str> = instrument_name.split('-').collect();
     
         match parts.as_slice() {
             [base, quote] => {
-                Some(Instrument::new(
+                Ok(Instrument::new(
                     Exchange::Dydx, 
                     market_type, 
                     InstrumentType::Perpetual {
-                        base: Currency::new(Cow::Owned(base.to_string())), 
-                        quote: Currency::new(Cow::Owned(quote.to_string())),
+                        base: parse_base(Exchange::Dydx, base)?, 
+                        quote: parse_listed_quote(Exchange::Dydx, instrument_name, quote, &QUOTE_CURRENCIES)?,
                     }
                 ))
             },
-            _ => {
-                error!(name: LOG_CTX, "normalize::Unexpected instrument format: {:?}", instrument_name);
-                None
-            }
+            _ => Err(InstrumentError::unrecognized(Exchange::Dydx, instrument_name)),
         }
     }
 
-    fn denormalize(&self, instrument: &Instrument) -> Option<String> {
+    fn try_denormalize(&self, instrument: &Instrument) -> InstrumentResult<String> {
         if instrument.exchange != Exchange::Dydx {
-            error!(name: LOG_CTX, "denormalize::Attempted to use Dydx handler for {:?}", instrument.exchange);
-            return None;
+            return Err(InstrumentError::ExchangeMismatch { expected: Exchange::Dydx, found: instrument.exchange });
         }
         if !self.supports_instrument_type(&instrument.instrument_type) {
-            error!(name: LOG_CTX, "denormalize::Instrument Type for {:?} is unsupported", instrument.instrument_type);
-            return None;
+            return Err(InstrumentError::unsupported_instrument_type(Exchange::Dydx, &instrument.instrument_type));
         }
 
         if !self.supports_market_type(&instrument.market_type) {
-            error!(name: LOG_CTX, "denormalize::Market Type for {:?} is unsupported", instrument.market_type);
-            return None;
+            return Err(InstrumentError::UnsupportedMarketType { exchange: Exchange::Dydx, market_type: instrument.market_type.clone() });
         }
         match &instrument.instrument_type {
-            InstrumentType::Perpetual{base, quote} => Some(format!("{}-{}", base.as_ref(), quote.as_ref())),
-            _ => None
+            InstrumentType::Perpetual{base, quote} if QUOTE_CURRENCIES.contains(&quote.as_ref()) => {
+                Ok(format!("{}-{}", base.as_ref(), quote.as_ref()))
+            },
+            _ => Err(InstrumentError::unsupported_instrument_type(Exchange::Dydx, &instrument.instrument_type)),
         }
     }
 
@@ -64,13 +176,26 @@ impl ExchangeHandler for DydxHandler {
     fn supports_instrument_type(&self, instrument_type: &InstrumentType) -> bool {
         matches!(instrument_type, InstrumentType::Perpetual { base: _, quote: _ })
     }
+
+    fn contract_spec(&self, _instrument: &Instrument) -> InstrumentResult<ContractSpec> {
+        Ok(ContractSpec::linear(Currency::new(Cow::Borrowed(SETTLEMENT_CURRENCY)), Decimal::from(1)))
+    }
 }
 
 #[cfg(test)]
 mod dydx_normalize_tests{
     use std::borrow::Cow;
 
-    use crate::{exchange::dydx::DydxHandler, Currency, Exchange, ExchangeHandler, Instrument, InstrumentType, MarketType};
+    use super::{DydxMarket, DydxMarkets, DydxMarketsHandler};
+    use crate::{exchange::dydx::{DydxHandler, DYDX_HANDLER}, ContractSpec, Currency, Decimal, Exchange, ExchangeHandler, Instrument, InstrumentError, InstrumentType, MarketType};
+
+    fn markets() -> DydxMarkets {
+        let mut markets = DydxMarkets::new();
+        markets
+            .insert(DydxMarket { ticker: String::from("BTC-USD"), clob_pair_id: 0, atomic_resolution: -10, quantum_conversion_exponent: -9 })
+            .insert(DydxMarket { ticker: String::from("ETH-USD"), clob_pair_id: 1, atomic_resolution: -9, quantum_conversion_exponent: -9 });
+        markets
+    }
 
     #[test]
     fn test_normalize_perpetual() {
@@ -86,11 +211,56 @@ mod dydx_normalize_tests{
         });
         assert_eq!(exchange.normalize(MarketType::OrderBook, instrument_name), Some(expected_instrument));
     }
+    #[test]
+    fn test_contract_spec() {
+        let exchange = DydxHandler;
+        let instrument = exchange.normalize(MarketType::OrderBook, "ETH-USD").unwrap();
+        assert_eq!(instrument.contract_spec().unwrap(), ContractSpec::linear(Currency::new(Cow::Borrowed("USDC")), Decimal::from(1)));
+    }
+    #[test]
+    fn test_try_normalize_unsupported_market_type() {
+        let exchange = DydxHandler;
+        let result = exchange.try_normalize(MarketType::Funding, "BTC-USD");
+        assert!(matches!(result, 
+            Err(InstrumentError::UnsupportedMarketType { exchange: Exchange::Dydx, market_type: MarketType::Funding })));
+    }
+    #[test]
+    fn test_normalize_clob_pair_id() {
+        let exchange = DydxMarketsHandler::new(markets());
+        let instrument = exchange.normalize(MarketType::OrderBook, "1").unwrap();
+        assert_eq!(instrument, exchange.normalize(MarketType::OrderBook, "ETH-USD").unwrap());
+        assert_eq!(exchange.denormalize(&instrument), Some(String::from("ETH-USD")));
+        assert_eq!(exchange.clob_pair_id(&instrument), Some(1));
+        
+        assert_eq!(exchange.normalize(MarketType::OrderBook, "7"), None);
+        // Only the markets-aware handler resolves clob pair ids, unless it is registered
+        // in place of the built-in one
+        assert!(matches!(DYDX_HANDLER.try_normalize(MarketType::OrderBook, "1"), 
+            Err(InstrumentError::UnrecognizedInstrument { exchange: Exchange::Dydx, .. })));
+        assert!(Exchange::Dydx.handler().try_normalize(MarketType::OrderBook, "1").is_err());
+    }
+
+    #[test]
+    fn test_market_scaling() {
+        let exchange = DydxMarketsHandler::new(markets());
+        let instrument = exchange.normalize(MarketType::OrderBook, "BTC-USD").unwrap();
+        let market = exchange.market(&instrument).unwrap();
+        assert_eq!(market.clob_pair_id, 0);
+        assert_eq!(market.size_exponent(), -10);
+        assert_eq!(market.price_exponent(), -5);
+        
+        let unlisted = exchange.normalize(MarketType::OrderBook, "SOL-USD").unwrap();
+        assert_eq!(exchange.market(&unlisted), None);
+    }
+
     #[test]
     fn test_normalize_unknown() {
         let instrument_name = "BTC-PERP".to_string();
         let exchange = DydxHandler;
         assert_eq!(exchange.normalize(MarketType::Ticker, &instrument_name), None);
+        // Tickers are always quoted in USD
+        assert_eq!(exchange.normalize(MarketType::OrderBook, &instrument_name), None);
+        assert_eq!(exchange.normalize(MarketType::OrderBook, "BTC-28MAR25"), None);
     }
 }
 
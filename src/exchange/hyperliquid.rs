@@ -0,0 +1,327 @@
+use std::borrow::Cow;
+
+use crate::{parse_base, ContractSpec, Currency, Decimal, Exchange, ExchangeHandler, Instrument, InstrumentError, InstrumentResult, InstrumentType, MarketType};
+
+/// Every market is margined and settled in USDC
+const SETTLEMENT_CURRENCY: &str = "USDC";
+const DEFAULT_QUOTE_CURRENCY: &str = "USDC";
+/// Prefix of perpetuals quoted per 1000 units of the underlying (e.g., kPEPE)
+const SCALED_TICKER_PREFIX: char = 'k';
+/// Underlying coins only listed as scaled tickers (e.g., kPEPE, never PEPE), unless
+/// the handler is given its own list with `with_scaled_coins`
+const SCALED_COINS: [&str; 7] = ["PEPE", "SHIB", "BONK", "FLOKI", "LUNC", "DOGS", "NEIRO"];
+/// Units of the underlying a scaled ticker contract represents
+const SCALED_TICKER_MULTIPLIER: u64 = 1000;
+/// Separator of named spot pairs (e.g., PURR/USDC)
+const SPOT_PAIR_SEPARATOR: char = '/';
+/// Spot pairs named by their tokens rather than by an index alias
+const NAMED_SPOT_PAIRS: [(&str, &str); 1] = [("PURR", "USDC")];
+/// Prefix of spot index aliases (e.g., @107)
+const SPOT_INDEX_PREFIX: char = '@';
+
+/// Spot pairs listed on Hyperliquid, as published by the `spotMeta` info endpoint.
+/// Most pairs are only reachable through an index alias (e.g., @107), whose
+/// underlying tokens cannot be derived from the name.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct HyperliquidSpotMeta {
+    /// Pair name (e.g., PURR/USDC or @107) with its base and quote tokens
+    pairs: Vec<(String, Currency, Currency)>,
+}
+
+impl HyperliquidSpotMeta {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a pair under its `spotMeta` name (e.g., @107), replacing any pair with the same name
+    pub fn insert(&mut self, name: impl Into<String>, base: Currency, quote: Currency) -> &mut Self {
+        let name = name.into();
+        self.pairs.retain(|(n, _, _)| *n != name);
+        self.pairs.push((name, base, quote));
+        self
+    }
+
+    /// Base and quote of the pair listed under `name`
+    pub fn pair(&self, name: &str) -> Option<(&Currency, &Currency)> {
+        self.pairs.iter()
+            .find(|(n, _, _)| n == name)
+            .map(|(_, base, quote)| (base, quote))
+    }
+
+    /// Name the pair of `base` and `quote` is listed under
+    pub fn name(&self, base: &Currency, quote: &Currency) -> Option<&str> {
+        self.pairs.iter()
+            .find(|(_, b, q)| b == base && q == quote)
+            .map(|(name, _, _)| name.as_str())
+    }
+}
+
+/// Handler for Hyperliquid perpetuals (e.g., BTC, kPEPE) and spot pairs (e.g., PURR/USDC, @107).
+///
+/// The built-in handler has no spot metadata and only knows the named spot pairs; a handler
+/// built with `with_spot_meta` resolves index aliases.
+#[derive(Default)]
+pub struct HyperliquidHandler {
+    spot_meta: Option<HyperliquidSpotMeta>,
+    /// Underlying coins of scaled tickers, or None for `SCALED_COINS`
+    scaled_coins: Option<Vec<String>>,
+}
+
+// Create a static instance to avoid allocations
+pub static HYPERLIQUID_HANDLER: HyperliquidHandler = HyperliquidHandler::new();
+
+impl HyperliquidHandler {
+    /// Handler without spot metadata, like `Exchange::Hyperliquid.handler()`
+    pub const fn new() -> Self {
+        Self { spot_meta: None, scaled_coins: None }
+    }
+
+    /// Resolves spot index aliases through `spot_meta`
+    pub fn with_spot_meta(mut self, spot_meta: HyperliquidSpotMeta) -> Self {
+        self.spot_meta = Some(spot_meta);
+        self
+    }
+
+    /// Lists the underlying coins of scaled tickers (e.g., PEPE for kPEPE), as published by
+    /// the `meta` info endpoint, in place of the built-in list
+    pub fn with_scaled_coins<I, S>(mut self, scaled_coins: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.scaled_coins = Some(scaled_coins.into_iter().map(Into::into).collect());
+        self
+    }
+
+    fn is_scaled(&self, base: &str) -> bool {
+        match &self.scaled_coins {
+            Some(scaled_coins) => scaled_coins.iter().any(|coin| coin == base),
+            None => SCALED_COINS.contains(&base),
+        }
+    }
+
+    /// Returns true if the pair of `base` and `quote` is listed under its own name (e.g., PURR/USDC)
+    fn is_named_spot_pair(&self, base: &str, quote: &str) -> bool {
+        NAMED_SPOT_PAIRS.contains(&(base, quote))
+            || self.spot_meta.as_ref().is_some_and(|meta| meta.pair(&format!("{}{}{}", base, SPOT_PAIR_SEPARATOR, quote)).is_some())
+    }
+
+    /// Normalizes a perpetual coin, mapping scaled tickers (e.g., kPEPE) to their underlying (e.g., PEPE)
+    fn normalize_coin(&self, coin: &str) -> InstrumentResult<Currency> {
+        match coin.strip_prefix(SCALED_TICKER_PREFIX) {
+            Some(underlying) if self.is_scaled(underlying) => parse_base(Exchange::Hyperliquid, underlying),
+            Some(_) => Err(InstrumentError::invalid_base(Exchange::Hyperliquid, coin)),
+            None => parse_base(Exchange::Hyperliquid, coin),
+        }
+    }
+
+    /// Writes a base back as a perpetual coin, restoring the `k` prefix of scaled tickers
+    fn denormalize_coin(&self, base: &Currency) -> String {
+        if self.is_scaled(base.as_ref()) {
+            format!("{}{}", SCALED_TICKER_PREFIX, base.as_ref())
+        } else {
+            base.to_string()
+        }
+    }
+}
+
+impl ExchangeHandler for HyperliquidHandler {
+    fn try_normalize(&self, market_type: MarketType, instrument_name: &str) -> InstrumentResult<Instrument> {
+        let instrument_type = if instrument_name.starts_with(SPOT_INDEX_PREFIX) {
+            // Spot index alias: e.g., @107
+            let (base, quote) = self.spot_meta.as_ref()
+                .and_then(|meta| meta.pair(instrument_name))
+                .ok_or_else(|| InstrumentError::unrecognized(Exchange::Hyperliquid, instrument_name))?;
+            InstrumentType::Spot { base: base.clone(), quote: quote.clone() }
+        } else if let Some((base, quote)) = instrument_name.split_once(SPOT_PAIR_SEPARATOR) {
+            // Named spot pair: e.g., PURR/USDC
+            if !self.is_named_spot_pair(base, quote) {
+                return Err(InstrumentError::unrecognized(Exchange::Hyperliquid, instrument_name));
+            }
+            InstrumentType::Spot {
+                base: parse_base(Exchange::Hyperliquid, base)?,
+                quote: parse_base(Exchange::Hyperliquid, quote)
+                    .map_err(|_| InstrumentError::unrecognized(Exchange::Hyperliquid, instrument_name))?,
+            }
+        } else {
+            // Perpetual: e.g., BTC or kPEPE
+            InstrumentType::Perpetual {
+                base: self.normalize_coin(instrument_name)?,
+                quote: Currency::new(Cow::Borrowed(DEFAULT_QUOTE_CURRENCY)),
+            }
+        };
+
+        Ok(Instrument::new(Exchange::Hyperliquid, market_type, instrument_type))
+    }
+
+    fn try_denormalize(&self, instrument: &Instrument) -> InstrumentResult<String> {
+        // Check if this is the right exchange handler
+        if instrument.exchange != Exchange::Hyperliquid {
+            return Err(InstrumentError::ExchangeMismatch { expected: Exchange::Hyperliquid, found: instrument.exchange });
+        }
+
+        match &instrument.instrument_type {
+            InstrumentType::Perpetual { base, quote } if quote.as_ref() == DEFAULT_QUOTE_CURRENCY => {
+                Ok(self.denormalize_coin(base))
+            },
+
+            InstrumentType::Spot { base, quote } => {
+                match self.spot_meta.as_ref().and_then(|meta| meta.name(base, quote)) {
+                    Some(name) => Ok(name.to_string()),
+                    None if self.is_named_spot_pair(base.as_ref(), quote.as_ref()) => {
+                        Ok(format!("{}{}{}", base.as_ref(), SPOT_PAIR_SEPARATOR, quote.as_ref()))
+                    },
+                    // Any other pair is only listed under an index alias
+                    None => Err(InstrumentError::unrecognized(Exchange::Hyperliquid, &instrument.instrument_type.to_string())),
+                }
+            },
+
+            _ => Err(InstrumentError::unsupported_instrument_type(Exchange::Hyperliquid, &instrument.instrument_type)),
+        }
+    }
+
+    fn supports_instrument_type(&self, instrument_type: &InstrumentType) -> bool {
+        matches!(instrument_type, InstrumentType::Perpetual { .. } | InstrumentType::Spot { .. })
+    }
+
+    fn contract_spec(&self, instrument: &Instrument) -> InstrumentResult<ContractSpec> {
+        let multiplier = match &instrument.instrument_type {
+            // Scaled tickers trade 1000 units of the underlying per contract
+            InstrumentType::Perpetual { base, .. } if self.is_scaled(base.as_ref()) => SCALED_TICKER_MULTIPLIER,
+            _ => 1,
+        };
+        Ok(ContractSpec::linear(Currency::new(Cow::Borrowed(SETTLEMENT_CURRENCY)), Decimal::from(multiplier)))
+    }
+}
+
+#[cfg(test)]
+mod hyperliquid_normalize_tests {
+    use std::borrow::Cow;
+
+    use super::{HyperliquidHandler, HyperliquidSpotMeta, HYPERLIQUID_HANDLER};
+    use crate::{ContractSpec, Currency, Decimal, Exchange, ExchangeHandler, Instrument, InstrumentError, InstrumentType, MarketType};
+
+    fn spot_meta() -> HyperliquidSpotMeta {
+        let mut meta = HyperliquidSpotMeta::new();
+        meta.insert("PURR/USDC", Currency::new(Cow::Borrowed("PURR")), Currency::new(Cow::Borrowed("USDC")))
+            .insert("@107", Currency::new(Cow::Borrowed("HYPE")), Currency::new(Cow::Borrowed("USDC")));
+        meta
+    }
+
+    #[test]
+    fn test_normalize_perpetual() {
+        let expected_instrument = Instrument::new(
+            Exchange::Hyperliquid,
+            MarketType::OrderBook,
+            InstrumentType::Perpetual {
+                base: Currency::new(Cow::Borrowed("BTC")),
+                quote: Currency::new(Cow::Borrowed("USDC")),
+            });
+        assert_eq!(HYPERLIQUID_HANDLER.normalize(MarketType::OrderBook, "BTC"), Some(expected_instrument));
+    }
+
+    #[test]
+    fn test_normalize_scaled_ticker() {
+        let instrument = HYPERLIQUID_HANDLER.normalize(MarketType::OrderBook, "kPEPE").unwrap();
+        assert_eq!(instrument.instrument_type, InstrumentType::Perpetual {
+            base: Currency::new(Cow::Borrowed("PEPE")),
+            quote: Currency::new(Cow::Borrowed("USDC")),
+        });
+        assert_eq!(instrument.contract_spec().unwrap(), ContractSpec::linear(Currency::new(Cow::Borrowed("USDC")), Decimal::from(1000)));
+        assert_eq!(HYPERLIQUID_HANDLER.denormalize(&instrument), Some(String::from("kPEPE")));
+        // Only listed coins are scaled
+        assert_eq!(HYPERLIQUID_HANDLER.normalize(MarketType::OrderBook, "kBTC"), None);
+        let btc = HYPERLIQUID_HANDLER.normalize(MarketType::OrderBook, "BTC").unwrap();
+        assert_eq!(btc.contract_spec().unwrap().multiplier, Decimal::from(1));
+        // Uppercase K is part of the coin name
+        assert_eq!(HYPERLIQUID_HANDLER.normalize(MarketType::OrderBook, "KAS").unwrap().instrument_type.base().to_string(), "KAS");
+    }
+
+    #[test]
+    fn test_normalize_configured_scaled_coins() {
+        let exchange = HyperliquidHandler::new().with_scaled_coins(["PEPE", "BTC"]);
+        let instrument = exchange.normalize(MarketType::OrderBook, "kBTC").unwrap();
+        assert_eq!(instrument.instrument_type.base().to_string(), "BTC");
+        assert_eq!(exchange.contract_spec(&instrument).unwrap().multiplier, Decimal::from(1000));
+        assert_eq!(exchange.denormalize(&instrument), Some(String::from("kBTC")));
+        // The configured list replaces the built-in one
+        assert_eq!(exchange.normalize(MarketType::OrderBook, "kSHIB"), None);
+    }
+
+    #[test]
+    fn test_normalize_spot() {
+        let expected_type = InstrumentType::Spot {
+            base: Currency::new(Cow::Borrowed("PURR")),
+            quote: Currency::new(Cow::Borrowed("USDC")),
+        };
+        assert_eq!(HYPERLIQUID_HANDLER.normalize(MarketType::OrderBook, "PURR/USDC").unwrap().instrument_type, expected_type);
+
+        let exchange = HyperliquidHandler::default().with_spot_meta(spot_meta());
+        let instrument = exchange.normalize(MarketType::OrderBook, "@107").unwrap();
+        assert_eq!(instrument.instrument_type, InstrumentType::Spot {
+            base: Currency::new(Cow::Borrowed("HYPE")),
+            quote: Currency::new(Cow::Borrowed("USDC")),
+        });
+        assert_eq!(exchange.denormalize(&instrument), Some(String::from("@107")));
+    }
+
+    #[test]
+    fn test_normalize_unknown_spot_index() {
+        assert!(matches!(
+            HYPERLIQUID_HANDLER.try_normalize(MarketType::OrderBook, "@107"),
+            Err(InstrumentError::UnrecognizedInstrument { exchange: Exchange::Hyperliquid, .. })
+        ));
+        assert_eq!(HyperliquidHandler::default().with_spot_meta(spot_meta()).normalize(MarketType::OrderBook, "@1"), None);
+        assert_eq!(HYPERLIQUID_HANDLER.normalize(MarketType::OrderBook, "BTC-PERP"), None);
+        // Other pairs are only listed under an index alias
+        assert!(matches!(
+            HYPERLIQUID_HANDLER.try_normalize(MarketType::OrderBook, "HYPE/USDC"),
+            Err(InstrumentError::UnrecognizedInstrument { exchange: Exchange::Hyperliquid, .. })
+        ));
+    }
+}
+
+#[cfg(test)]
+mod hyperliquid_denormalize_tests {
+    use std::borrow::Cow;
+
+    use super::HYPERLIQUID_HANDLER;
+    use crate::{Currency, Exchange, ExchangeHandler, Instrument, InstrumentError, InstrumentType, MarketType};
+
+    #[test]
+    fn test_denorm_spot_without_meta() {
+        let named = Instrument::new(
+            Exchange::Hyperliquid,
+            MarketType::OrderBook,
+            InstrumentType::Spot {
+                base: Currency::new(Cow::Borrowed("PURR")),
+                quote: Currency::new(Cow::Borrowed("USDC")),
+            });
+        assert_eq!(HYPERLIQUID_HANDLER.denormalize(&named), Some(String::from("PURR/USDC")));
+
+        // HYPE/USDC is only listed under an index alias, which takes spot metadata to resolve
+        let instrument = Instrument::new(
+            Exchange::Hyperliquid,
+            MarketType::OrderBook,
+            InstrumentType::Spot {
+                base: Currency::new(Cow::Borrowed("HYPE")),
+                quote: Currency::new(Cow::Borrowed("USDC")),
+            });
+        assert!(matches!(
+            HYPERLIQUID_HANDLER.try_denormalize(&instrument),
+            Err(InstrumentError::UnrecognizedInstrument { exchange: Exchange::Hyperliquid, .. })
+        ));
+    }
+
+    #[test]
+    fn test_denorm_perpetual_quote() {
+        let instrument = Instrument::new(
+            Exchange::Hyperliquid,
+            MarketType::OrderBook,
+            InstrumentType::Perpetual {
+                base: Currency::new(Cow::Borrowed("BTC")),
+                quote: Currency::new(Cow::Borrowed("USDT")),
+            });
+        assert_eq!(HYPERLIQUID_HANDLER.denormalize(&instrument), None);
+    }
+}
@@ -0,0 +1,312 @@
+use chrono::{Datelike, NaiveDate, NaiveTime, Weekday};
+use std::borrow::Cow;
+
+use crate::{denormalize_expiry, last_weekday, parse_base, ContractSpec, ContractStyle, Currency, Decimal, Exchange, ExchangeHandler, Expiry, Instrument, InstrumentError, InstrumentResult, InstrumentType, MarketType};
+
+use crate::exchange::{denormalize_xbt, normalize_xbt, strip_suffix_ignore_case};
+
+const DEFAULT_EXPIRY_FORMAT: &str = "%y%m%d";
+/// Separator between the product prefix, pair and expiry (e.g., FF_XBTUSD_250328)
+const SEPARATOR: char = '_';
+/// Product prefixes of inverse contracts, margined and settled in the base currency
+const INVERSE_PERPETUAL_PREFIX: &str = "PI";
+const INVERSE_FUTURE_PREFIX: &str = "FI";
+/// Product prefixes of linear multi-collateral contracts, settled in USD
+const LINEAR_PERPETUAL_PREFIX: &str = "PF";
+const LINEAR_FUTURE_PREFIX: &str = "FF";
+const LINEAR_SETTLEMENT_CURRENCY: &str = "USD";
+/// Every pair is quoted in USD (e.g., XBTUSD)
+const QUOTE_CURRENCY: &str = "USD";
+/// Fixed maturity contracts expire at 16:00 London time: 16:00 UTC in winter (GMT)...
+const SETTLEMENT_TIME: NaiveTime = NaiveTime::from_hms_opt(16, 0, 0).unwrap();
+/// ...and 15:00 UTC during British Summer Time
+const SUMMER_SETTLEMENT_TIME: NaiveTime = NaiveTime::from_hms_opt(15, 0, 0).unwrap();
+
+/// Handler for Kraken Futures (e.g., PF_XBTUSD, PI_XBTUSD, FF_XBTUSD_250328, FI_XBTUSD_250328).
+///
+/// Inverse (PI_, FI_) and linear multi-collateral (PF_, FF_) contracts on the same pair only
+/// differ by their contract style, which normalization records on the instrument. Linear is
+/// the usual style, so inverse products read as e.g. `o.p.BTC-USD.kraken_futures.inverse`
+/// in the standard format.
+pub struct KrakenFuturesHandler;
+
+// Create a static instance to avoid allocations
+pub static KRAKEN_FUTURES_HANDLER: KrakenFuturesHandler = KrakenFuturesHandler;
+
+impl KrakenFuturesHandler {
+    /// Product prefixes of the perpetuals and futures of a contract style
+    fn prefixes(&self, instrument: &Instrument) -> InstrumentResult<(&'static str, &'static str)> {
+        match instrument.contract_style() {
+            ContractStyle::Inverse => Ok((INVERSE_PERPETUAL_PREFIX, INVERSE_FUTURE_PREFIX)),
+            ContractStyle::Linear => Ok((LINEAR_PERPETUAL_PREFIX, LINEAR_FUTURE_PREFIX)),
+            style => Err(InstrumentError::UnsupportedContractStyle {
+                exchange: Exchange::KrakenFutures,
+                style,
+                value: instrument.instrument_type.to_string(),
+            }),
+        }
+    }
+
+    /// Reads the contract style from a product prefix
+    fn parse_contract_style(&self, prefix: &str, inverse_prefix: &str) -> ContractStyle {
+        if prefix.eq_ignore_ascii_case(inverse_prefix) { ContractStyle::Inverse } else { ContractStyle::Linear }
+    }
+
+    /// Splits a concatenated pair (e.g., XBTUSD), mapping XBT to BTC
+    fn parse_pair(&self, instrument_name: &str, pair: &str) -> InstrumentResult<(Currency, Currency)> {
+        let base = strip_suffix_ignore_case(pair, QUOTE_CURRENCY)
+            .ok_or_else(|| InstrumentError::unrecognized(Exchange::KrakenFutures, instrument_name))?;
+        Ok((parse_base(Exchange::KrakenFutures, normalize_xbt(base))?, Currency::new(Cow::Borrowed(QUOTE_CURRENCY))))
+    }
+
+    /// Parses a `YYMMDD` expiry
+    fn parse_expiry(&self, expiry: &str) -> InstrumentResult<Expiry> {
+        NaiveDate::parse_from_str(expiry, DEFAULT_EXPIRY_FORMAT)
+            .map(|date| Expiry::new(date, self.settlement_time_on(date)))
+            .map_err(|_| InstrumentError::invalid_expiry(Exchange::KrakenFutures, expiry))
+    }
+
+    /// Writes a pair back in its concatenated form, restoring XBT
+    fn denormalize_pair(&self, instrument: &Instrument, base: &Currency, quote: &Currency) -> InstrumentResult<String> {
+        if quote.as_ref() != QUOTE_CURRENCY {
+            return Err(InstrumentError::unsupported_instrument_type(Exchange::KrakenFutures, &instrument.instrument_type));
+        }
+        Ok(format!("{}{}", denormalize_xbt(base), quote.as_ref()))
+    }
+}
+
+impl ExchangeHandler for KrakenFuturesHandler {
+    fn try_normalize(&self, market_type: MarketType, instrument_name: &str) -> InstrumentResult<Instrument> {
+        // Split the product symbol into parts
+        let parts: Vec<&str> = instrument_name.split(SEPARATOR).collect();
+
+        let (instrument_type, contract_style) = match parts.as_slice() {
+            // Perpetual: e.g., PF_XBTUSD or PI_XBTUSD
+            [prefix, pair] if prefix.eq_ignore_ascii_case(LINEAR_PERPETUAL_PREFIX)
+                || prefix.eq_ignore_ascii_case(INVERSE_PERPETUAL_PREFIX) => {
+                let (base, quote) = self.parse_pair(instrument_name, pair)?;
+                (InstrumentType::Perpetual { base, quote }, self.parse_contract_style(prefix, INVERSE_PERPETUAL_PREFIX))
+            },
+
+            // Fixed maturity future: e.g., FF_XBTUSD_250328 or FI_XBTUSD_250328
+            [prefix, pair, expiry_str] if prefix.eq_ignore_ascii_case(LINEAR_FUTURE_PREFIX)
+                || prefix.eq_ignore_ascii_case(INVERSE_FUTURE_PREFIX) => {
+                let (base, quote) = self.parse_pair(instrument_name, pair)?;
+                let expiry = self.parse_expiry(expiry_str)?;
+                (InstrumentType::Future { base, quote, expiry }, self.parse_contract_style(prefix, INVERSE_FUTURE_PREFIX))
+            },
+
+            // No matching format
+            _ => return Err(InstrumentError::unrecognized(Exchange::KrakenFutures, instrument_name)),
+        };
+
+        Instrument::new(Exchange::KrakenFutures, market_type, instrument_type).with_contract_style(contract_style)
+    }
+
+    fn try_denormalize(&self, instrument: &Instrument) -> InstrumentResult<String> {
+        // Check if this is the right exchange handler
+        if instrument.exchange != Exchange::KrakenFutures {
+            return Err(InstrumentError::ExchangeMismatch { expected: Exchange::KrakenFutures, found: instrument.exchange });
+        }
+
+        match &instrument.instrument_type {
+            InstrumentType::Perpetual { base, quote } => {
+                let (perpetual_prefix, _) = self.prefixes(instrument)?;
+                Ok(format!("{}{}{}", perpetual_prefix, SEPARATOR, self.denormalize_pair(instrument, base, quote)?))
+            },
+
+            InstrumentType::Future { base, quote, expiry } => {
+                let (_, future_prefix) = self.prefixes(instrument)?;
+                Ok(format!("{}{}{}{}{}",
+                    future_prefix,
+                    SEPARATOR,
+                    self.denormalize_pair(instrument, base, quote)?,
+                    SEPARATOR,
+                    denormalize_expiry(expiry, DEFAULT_EXPIRY_FORMAT)))
+            },
+
+            _ => Err(InstrumentError::unsupported_instrument_type(Exchange::KrakenFutures, &instrument.instrument_type)),
+        }
+    }
+
+    fn supports_instrument_type(&self, instrument_type: &InstrumentType) -> bool {
+        matches!(instrument_type, InstrumentType::Perpetual { .. } | InstrumentType::Future { .. })
+    }
+
+    fn settlement_time(&self) -> NaiveTime {
+        SETTLEMENT_TIME
+    }
+
+    fn settlement_time_on(&self, date: NaiveDate) -> NaiveTime {
+        // British Summer Time runs from the last Sunday of March to the last Sunday of
+        // October, switching at 01:00 UTC, well before the afternoon settlement
+        let summer_start = last_weekday(date.year(), 3, Weekday::Sun);
+        let summer_end = last_weekday(date.year(), 10, Weekday::Sun);
+        match (summer_start, summer_end) {
+            (Some(start), Some(end)) if date >= start && date < end => SUMMER_SETTLEMENT_TIME,
+            _ => SETTLEMENT_TIME,
+        }
+    }
+
+    fn supports_contract_style(&self, instrument_type: &InstrumentType, contract_style: ContractStyle) -> bool {
+        // Every pair is listed both linear and inverse
+        matches!(contract_style, ContractStyle::Linear | ContractStyle::Inverse)
+            && self.supports_instrument_type(instrument_type)
+    }
+
+    fn contract_spec(&self, instrument: &Instrument) -> InstrumentResult<ContractSpec> {
+        match instrument.contract_style() {
+            // Inverse contracts are sized in 1 USD, settled in the base currency
+            ContractStyle::Inverse => Ok(ContractSpec::inverse(Currency::new(Cow::Owned(instrument.instrument_type.base().to_string())), Decimal::from(1))),
+            ContractStyle::Linear => Ok(ContractSpec::linear(Currency::new(Cow::Borrowed(LINEAR_SETTLEMENT_CURRENCY)), Decimal::from(1))),
+            style => Err(InstrumentError::UnsupportedContractStyle {
+                exchange: Exchange::KrakenFutures,
+                style,
+                value: instrument.instrument_type.to_string(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod kraken_futures_normalize_tests {
+    use std::borrow::Cow;
+
+    use chrono::NaiveDate;
+
+    use super::{KRAKEN_FUTURES_HANDLER, SETTLEMENT_TIME, SUMMER_SETTLEMENT_TIME};
+    use crate::{parse_standard_format, ContractSpec, ContractStyle, Currency, Decimal, Exchange, ExchangeHandler, Expiry, Instrument, InstrumentError, InstrumentType, MarketType};
+
+    #[test]
+    fn test_normalize_perpetuals() {
+        let expected_type = InstrumentType::Perpetual {
+            base: Currency::new(Cow::Borrowed("BTC")),
+            quote: Currency::new(Cow::Borrowed("USD")),
+        };
+        let linear = KRAKEN_FUTURES_HANDLER.normalize(MarketType::OrderBook, "PF_XBTUSD").unwrap();
+        let inverse = Exchange::KrakenFutures.handler().normalize(MarketType::OrderBook, "PI_XBTUSD").unwrap();
+        assert_eq!(linear, Instrument::new(Exchange::KrakenFutures, MarketType::OrderBook, expected_type.clone()));
+        assert_eq!(inverse, Instrument::new(Exchange::KrakenFutures, MarketType::OrderBook, expected_type)
+            .with_contract_style(ContractStyle::Inverse).unwrap());
+        assert_ne!(linear, inverse);
+
+        assert_eq!(linear.contract_spec().unwrap(), ContractSpec::linear(Currency::new(Cow::Borrowed("USD")), Decimal::from(1)));
+        assert_eq!(inverse.contract_spec().unwrap(), ContractSpec::inverse(Currency::new(Cow::Borrowed("BTC")), Decimal::from(1)));
+    }
+
+    #[test]
+    fn test_normalize_futures() {
+        let expected_type = InstrumentType::Future {
+            base: Currency::new(Cow::Borrowed("BTC")),
+            quote: Currency::new(Cow::Borrowed("USD")),
+            expiry: Expiry::new(NaiveDate::from_ymd_opt(2025, 3, 28).unwrap(), SETTLEMENT_TIME),
+        };
+        let linear = KRAKEN_FUTURES_HANDLER.normalize(MarketType::OrderBook, "FF_XBTUSD_250328").unwrap();
+        let inverse = KRAKEN_FUTURES_HANDLER.normalize(MarketType::OrderBook, "fi_xbtusd_250328").unwrap();
+        assert_eq!(linear.instrument_type, expected_type);
+        assert_eq!(inverse.instrument_type, expected_type);
+        assert_eq!(linear.contract_style(), ContractStyle::Linear);
+        assert_eq!(inverse.contract_style(), ContractStyle::Inverse);
+        assert_eq!(inverse.exchange, Exchange::KrakenFutures);
+
+        assert_eq!(linear.contract_spec().unwrap(), ContractSpec::linear(Currency::new(Cow::Borrowed("USD")), Decimal::from(1)));
+        assert_eq!(inverse.contract_spec().unwrap(), ContractSpec::inverse(Currency::new(Cow::Borrowed("BTC")), Decimal::from(1)));
+    }
+
+    #[test]
+    fn test_normalize_summer_expiry() {
+        // 16:00 London is 15:00 UTC during British Summer Time, from 30 March to 26 October 2025
+        let expiry = |instrument_name| KRAKEN_FUTURES_HANDLER.normalize(MarketType::OrderBook, instrument_name)
+            .and_then(|instrument| instrument.instrument_type.expiry().copied())
+            .unwrap();
+        assert_eq!(expiry("FF_XBTUSD_250328").settlement_time(), SETTLEMENT_TIME);
+        assert_eq!(expiry("FF_XBTUSD_250627").settlement_time(), SUMMER_SETTLEMENT_TIME);
+        assert_eq!(expiry("FI_XBTUSD_250926").settlement_time(), SUMMER_SETTLEMENT_TIME);
+        assert_eq!(expiry("FF_XBTUSD_251226").settlement_time(), SETTLEMENT_TIME);
+        assert_eq!(expiry("FF_XBTUSD_250627").settlement().to_rfc3339(), "2025-06-27T15:00:00+00:00");
+
+        let instrument = KRAKEN_FUTURES_HANDLER.normalize(MarketType::OrderBook, "FF_XBTUSD_250627").unwrap();
+        assert_eq!(parse_standard_format(&instrument.to_string()).unwrap(), instrument);
+    }
+
+    #[test]
+    fn test_standard_format_contract_style() {
+        let inverse = KRAKEN_FUTURES_HANDLER.normalize(MarketType::OrderBook, "PI_XBTUSD").unwrap();
+        assert_eq!(inverse.to_string(), "o.p.BTC-USD.kraken_futures.inverse");
+        assert_eq!(parse_standard_format("o.p.BTC-USD.kraken_futures.inverse").unwrap(), inverse);
+        assert_eq!(parse_standard_format("o.p.BTC-USD.kraken_futures").unwrap().contract_style(), ContractStyle::Linear);
+        assert!(matches!(
+            parse_standard_format("o.p.BTC-USD.kraken_futures.quanto"),
+            Err(InstrumentError::UnsupportedContractStyle { exchange: Exchange::KrakenFutures, style: ContractStyle::Quanto, .. })
+        ));
+        assert!(Exchange::try_from("kraken_futures_inverse").is_err());
+    }
+
+    #[test]
+    fn test_normalize_unknown() {
+        assert_eq!(KRAKEN_FUTURES_HANDLER.normalize(MarketType::OrderBook, "XBTUSD"), None);
+        assert_eq!(KRAKEN_FUTURES_HANDLER.normalize(MarketType::OrderBook, "PF_XBTUSD_250328"), None);
+        assert_eq!(KRAKEN_FUTURES_HANDLER.normalize(MarketType::OrderBook, "FI_XBTUSD"), None);
+        assert!(matches!(
+            KRAKEN_FUTURES_HANDLER.try_normalize(MarketType::OrderBook, "FF_XBTUSD_28MAR25"),
+            Err(InstrumentError::InvalidExpiry { exchange: Exchange::KrakenFutures, .. })
+        ));
+    }
+}
+
+#[cfg(test)]
+mod kraken_futures_denormalize_tests {
+    use std::borrow::Cow;
+
+    use chrono::NaiveDate;
+
+    use super::{KRAKEN_FUTURES_HANDLER, SETTLEMENT_TIME};
+    use crate::{ContractStyle, Currency, Exchange, ExchangeHandler, Expiry, Instrument, InstrumentError, InstrumentType, MarketType};
+
+    #[test]
+    fn test_denorm_restores_xbt() {
+        let instrument = Instrument::new(
+            Exchange::KrakenFutures,
+            MarketType::OrderBook,
+            InstrumentType::Future {
+                base: Currency::new(Cow::Borrowed("BTC")),
+                quote: Currency::new(Cow::Borrowed("USD")),
+                expiry: Expiry::new(NaiveDate::from_ymd_opt(2025, 3, 28).unwrap(), SETTLEMENT_TIME),
+            });
+        assert_eq!(KRAKEN_FUTURES_HANDLER.denormalize(&instrument), Some(String::from("FF_XBTUSD_250328")));
+        let inverse = instrument.with_contract_style(ContractStyle::Inverse).unwrap();
+        assert_eq!(KRAKEN_FUTURES_HANDLER.denormalize(&inverse), Some(String::from("FI_XBTUSD_250328")));
+        // Kraken Futures lists no quanto contracts
+        assert!(matches!(
+            inverse.with_contract_style(ContractStyle::Quanto),
+            Err(InstrumentError::UnsupportedContractStyle { exchange: Exchange::KrakenFutures, style: ContractStyle::Quanto, .. })
+        ));
+    }
+
+    #[test]
+    fn test_denorm_perpetual() {
+        let instrument = Instrument::new(
+            Exchange::KrakenFutures,
+            MarketType::Funding,
+            InstrumentType::Perpetual {
+                base: Currency::new(Cow::Borrowed("ETH")),
+                quote: Currency::new(Cow::Borrowed("USD")),
+            });
+        assert_eq!(KRAKEN_FUTURES_HANDLER.denormalize(&instrument), Some(String::from("PF_ETHUSD")));
+    }
+
+    #[test]
+    fn test_denorm_unlisted_quote() {
+        let instrument = Instrument::new(
+            Exchange::KrakenFutures,
+            MarketType::OrderBook,
+            InstrumentType::Perpetual {
+                base: Currency::new(Cow::Borrowed("BTC")),
+                quote: Currency::new(Cow::Borrowed("USDT")),
+            });
+        assert!(matches!(
+            KRAKEN_FUTURES_HANDLER.try_denormalize(&instrument),
+            Err(InstrumentError::UnsupportedInstrumentType { exchange: Exchange::KrakenFutures, .. })
+        ));
+    }
+}
@@ -0,0 +1,351 @@
+use chrono::NaiveTime;
+use std::borrow::Cow;
+
+use crate::exchange::{asset_contract_size, denormalize_xbt, normalize_xbt, parse_listed_quote, strip_suffix_ignore_case, AssetContractSize};
+use crate::{denormalize_month_code_expiry, normalize_month_code_expiry, parse_base, split_month_code, ContractSpec, ContractStyle, Currency, Decimal, Exchange, ExchangeHandler, Expiry, Instrument, InstrumentError, InstrumentResult, InstrumentType, MarketType};
+
+/// Quote currencies of the spot markets (e.g., BTC-USDT, ETH-BTC, KCS-ETH)
+const SPOT_QUOTE_CURRENCIES: [&str; 8] = ["USDT", "USDC", "BTC", "ETH", "KCS", "TRX", "EUR", "BRL"];
+/// Quote currencies of futures symbols, checked in order (e.g., XBTUSDTM before XBTUSDM)
+const FUTURES_QUOTE_CURRENCIES: [&str; 3] = ["USDT", "USDC", "USD"];
+/// Quote of inverse contracts, which are margined and settled in the base currency
+const INVERSE_QUOTE_CURRENCY: &str = "USD";
+/// Suffix of every futures symbol (e.g., XBTUSDTM, XBTUSDM, XBTMH25)
+const FUTURES_SUFFIX: &str = "M";
+/// Separator of spot pairs (e.g., BTC-USDT)
+const SPOT_PAIR_SEPARATOR: char = '-';
+/// Quarterly futures expire at 12:00 UTC on the last Friday of their month
+const SETTLEMENT_TIME: NaiveTime = NaiveTime::from_hms_opt(12, 0, 0).unwrap();
+
+/// Multipliers of USDT and USDC margined perpetuals (e.g., XBTUSDTM is 0.001 BTC). Other
+/// assets have their own sizes (e.g., DOGEUSDTM is 100 DOGE), so their contract spec is unknown.
+const LINEAR_CONTRACT_MULTIPLIERS: [AssetContractSize; 3] = [
+    AssetContractSize { asset: "BTC", size: (1, 3) },
+    AssetContractSize { asset: "ETH", size: (1, 2) },
+    AssetContractSize { asset: "SOL", size: (1, 1) },
+];
+
+/// KuCoin sub-markets, served by separate APIs (api.kucoin.com and api-futures.kucoin.com)
+#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash)]
+pub enum KucoinMarket {
+    Spot,
+    /// Linear and inverse perpetuals and inverse quarterly futures
+    Futures,
+}
+
+impl TryFrom<&str> for KucoinMarket {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.trim() {
+            s if s.eq_ignore_ascii_case("spot") => Ok(KucoinMarket::Spot),
+            s if s.eq_ignore_ascii_case("futures") => Ok(KucoinMarket::Futures),
+            _ => Err(format!("Invalid KuCoin market: {}", value)),
+        }
+    }
+}
+
+/// Handler for KuCoin spot pairs (e.g., BTC-USDT), linear perpetuals (e.g., XBTUSDTM),
+/// inverse perpetuals (e.g., XBTUSDM) and inverse quarterly futures (e.g., XBTMH25)
+pub struct KucoinHandler {
+    /// Sub-market the symbols come from, or None to accept both (spot pairs and futures symbols never collide)
+    market: Option<KucoinMarket>,
+}
+
+impl KucoinHandler {
+    pub const fn new(market: Option<KucoinMarket>) -> Self {
+        Self { market }
+    }
+
+    /// Returns the handler restricted to `market`
+    pub fn for_market(market: KucoinMarket) -> &'static KucoinHandler {
+        match market {
+            KucoinMarket::Spot => &KUCOIN_SPOT_HANDLER,
+            KucoinMarket::Futures => &KUCOIN_FUTURES_HANDLER,
+        }
+    }
+
+    /// Sub-market the handler is restricted to, if any
+    pub fn market(&self) -> Option<KucoinMarket> {
+        self.market
+    }
+
+    fn accepts(&self, market: KucoinMarket) -> bool {
+        self.market.is_none_or(|m| m == market)
+    }
+
+    /// Sub-market an instrument type is listed on, or None if KuCoin does not list it
+    fn market_of(instrument_type: &InstrumentType) -> Option<KucoinMarket> {
+        match instrument_type {
+            InstrumentType::Spot { quote, .. } if SPOT_QUOTE_CURRENCIES.contains(&quote.as_ref()) => Some(KucoinMarket::Spot),
+            InstrumentType::Perpetual { quote, .. } if FUTURES_QUOTE_CURRENCIES.contains(&quote.as_ref()) => Some(KucoinMarket::Futures),
+            InstrumentType::Future { quote, .. } if quote.as_ref() == INVERSE_QUOTE_CURRENCY => Some(KucoinMarket::Futures),
+            _ => None,
+        }
+    }
+
+    /// Multiplier of `asset`'s linear perpetuals, or None if it is not listed
+    fn linear_multiplier(&self, asset: &str) -> Option<Decimal> {
+        asset_contract_size(&LINEAR_CONTRACT_MULTIPLIERS, asset)
+    }
+
+    /// Splits a month-coded quarterly (e.g., XBTMH25) into its base and expiry
+    fn split_month_code<'a>(&self, symbol: &'a str) -> Option<(&'a str, Expiry)> {
+        let (contract, code) = split_month_code(symbol)?;
+        let expiry = normalize_month_code_expiry(code, self.settlement_time())?;
+        Some((strip_suffix_ignore_case(contract, FUTURES_SUFFIX)?, expiry))
+    }
+
+    /// Normalizes a futures symbol: perpetuals and quarterlies
+    fn normalize_futures(&self, instrument_name: &str) -> InstrumentResult<InstrumentType> {
+        // Inverse quarterly: e.g., XBTMH25
+        if let Some((base, expiry)) = self.split_month_code(instrument_name) {
+            return Ok(InstrumentType::Future {
+                base: parse_base(Exchange::Kucoin, normalize_xbt(base))?,
+                quote: Currency::new(Cow::Borrowed(INVERSE_QUOTE_CURRENCY)),
+                expiry,
+            });
+        }
+
+        // Linear or inverse perpetual: e.g., XBTUSDTM or XBTUSDM
+        let (base, quote) = strip_suffix_ignore_case(instrument_name, FUTURES_SUFFIX)
+            .and_then(|pair| FUTURES_QUOTE_CURRENCIES.iter()
+                .find_map(|quote| Some((strip_suffix_ignore_case(pair, quote)?, *quote))))
+            .ok_or_else(|| InstrumentError::unrecognized(Exchange::Kucoin, instrument_name))?;
+        Ok(InstrumentType::Perpetual {
+            base: parse_base(Exchange::Kucoin, normalize_xbt(base))?,
+            quote: Currency::new(Cow::Borrowed(quote)),
+        })
+    }
+}
+
+// Create static instances to avoid allocations
+pub static KUCOIN_HANDLER: KucoinHandler = KucoinHandler::new(None);
+pub static KUCOIN_SPOT_HANDLER: KucoinHandler = KucoinHandler::new(Some(KucoinMarket::Spot));
+pub static KUCOIN_FUTURES_HANDLER: KucoinHandler = KucoinHandler::new(Some(KucoinMarket::Futures));
+
+impl ExchangeHandler for KucoinHandler {
+    fn try_normalize(&self, market_type: MarketType, instrument_name: &str) -> InstrumentResult<Instrument> {
+        let instrument_type = match instrument_name.split_once(SPOT_PAIR_SEPARATOR) {
+            // Spot pair: e.g., BTC-USDT
+            Some((base, quote)) if self.accepts(KucoinMarket::Spot) => InstrumentType::Spot {
+                base: parse_base(Exchange::Kucoin, base)?,
+                quote: parse_listed_quote(Exchange::Kucoin, instrument_name, quote, &SPOT_QUOTE_CURRENCIES)?,
+            },
+
+            None if self.accepts(KucoinMarket::Futures) => self.normalize_futures(instrument_name)?,
+
+            // No matching format in this sub-market
+            _ => return Err(InstrumentError::unrecognized(Exchange::Kucoin, instrument_name)),
+        };
+
+        Ok(Instrument::new(Exchange::Kucoin, market_type, instrument_type))
+    }
+
+    fn try_denormalize(&self, instrument: &Instrument) -> InstrumentResult<String> {
+        // Check if this is the right exchange handler
+        if instrument.exchange != Exchange::Kucoin {
+            return Err(InstrumentError::ExchangeMismatch { expected: Exchange::Kucoin, found: instrument.exchange });
+        }
+
+        if !self.supports_instrument_type(&instrument.instrument_type) {
+            return Err(InstrumentError::unsupported_instrument_type(Exchange::Kucoin, &instrument.instrument_type));
+        }
+
+        match &instrument.instrument_type {
+            InstrumentType::Spot { base, quote } => {
+                Ok(format!("{}{}{}", base.as_ref(), SPOT_PAIR_SEPARATOR, quote.as_ref()))
+            },
+
+            InstrumentType::Perpetual { base, quote } => {
+                Ok(format!("{}{}{}", denormalize_xbt(base), quote.as_ref(), FUTURES_SUFFIX))
+            },
+
+            InstrumentType::Future { base, expiry, .. } => {
+                let code = denormalize_month_code_expiry(expiry)
+                    .ok_or_else(|| InstrumentError::invalid_expiry(Exchange::Kucoin, &expiry.to_string()))?;
+                Ok(format!("{}{}{}", denormalize_xbt(base), FUTURES_SUFFIX, code))
+            },
+
+            _ => Err(InstrumentError::unsupported_instrument_type(Exchange::Kucoin, &instrument.instrument_type)),
+        }
+    }
+
+    fn supports_instrument_type(&self, instrument_type: &InstrumentType) -> bool {
+        Self::market_of(instrument_type).is_some_and(|market| self.accepts(market))
+    }
+
+    fn settlement_time(&self) -> NaiveTime {
+        SETTLEMENT_TIME
+    }
+
+    fn contract_style(&self, instrument_type: &InstrumentType) -> ContractStyle {
+        match instrument_type {
+            InstrumentType::Perpetual { quote, .. } | InstrumentType::Future { quote, .. }
+                if quote.as_ref() == INVERSE_QUOTE_CURRENCY => ContractStyle::Inverse,
+            _ => ContractStyle::Linear,
+        }
+    }
+
+    fn contract_spec(&self, instrument: &Instrument) -> InstrumentResult<ContractSpec> {
+        let instrument_type = &instrument.instrument_type;
+        let base = Currency::new(Cow::Owned(instrument_type.base().to_string()));
+        let quote = Currency::new(Cow::Owned(instrument_type.quote().to_string()));
+
+        Ok(match (instrument.contract_style(), instrument_type) {
+            // Inverse contracts are sized in 1 USD, settled in the base
+            (ContractStyle::Inverse, _) => ContractSpec::inverse(base, Decimal::from(1)),
+            // Linear perpetuals are sized in a per-asset amount of the base (e.g., 100 DOGE),
+            // settled in the quote
+            (_, InstrumentType::Perpetual { .. }) => {
+                let multiplier = self.linear_multiplier(base.as_ref())
+                    .ok_or_else(|| InstrumentError::unknown_contract_size(Exchange::Kucoin, instrument_type))?;
+                ContractSpec::linear(quote, multiplier)
+            },
+            _ => ContractSpec::linear(quote, Decimal::from(1)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod kucoin_normalize_tests {
+    use std::borrow::Cow;
+
+    use chrono::NaiveDate;
+
+    use super::{KucoinHandler, KucoinMarket, KUCOIN_FUTURES_HANDLER, KUCOIN_HANDLER, KUCOIN_SPOT_HANDLER, SETTLEMENT_TIME};
+    use crate::{ContractSpec, ContractStyle, Currency, Decimal, Exchange, ExchangeHandler, Expiry, Instrument, InstrumentError, InstrumentType, MarketType};
+
+    #[test]
+    fn test_normalize_perpetuals() {
+        let expected_instrument = Instrument::new(
+            Exchange::Kucoin,
+            MarketType::OrderBook,
+            InstrumentType::Perpetual {
+                base: Currency::new(Cow::Borrowed("BTC")),
+                quote: Currency::new(Cow::Borrowed("USDT")),
+            });
+        assert_eq!(KUCOIN_FUTURES_HANDLER.normalize(MarketType::OrderBook, "XBTUSDTM"), Some(expected_instrument.clone()));
+        assert_eq!(expected_instrument.contract_spec().unwrap().style, ContractStyle::Linear);
+        assert_eq!(expected_instrument.contract_spec().unwrap(), ContractSpec::linear(Currency::new(Cow::Borrowed("USDT")), Decimal::new(1, 3).unwrap()));
+        let eth = KUCOIN_FUTURES_HANDLER.normalize(MarketType::OrderBook, "ETHUSDTM").unwrap();
+        assert_eq!(eth.contract_spec().unwrap().multiplier, Decimal::new(1, 2).unwrap());
+        let doge = KUCOIN_FUTURES_HANDLER.normalize(MarketType::OrderBook, "DOGEUSDTM").unwrap();
+        assert!(matches!(doge.contract_spec(), Err(InstrumentError::UnknownContractSize { exchange: Exchange::Kucoin, .. })));
+
+        let inverse = KUCOIN_FUTURES_HANDLER.normalize(MarketType::OrderBook, "XBTUSDM").unwrap();
+        assert_eq!(inverse.instrument_type, InstrumentType::Perpetual {
+            base: Currency::new(Cow::Borrowed("BTC")),
+            quote: Currency::new(Cow::Borrowed("USD")),
+        });
+        assert_eq!(inverse.contract_spec().unwrap(), ContractSpec::inverse(Currency::new(Cow::Borrowed("BTC")), Decimal::from(1)));
+
+        assert_eq!(KUCOIN_HANDLER.normalize(MarketType::OrderBook, "ETHUSDCM").unwrap().to_string(), "o.p.ETH-USDC.kucoin");
+    }
+
+    #[test]
+    fn test_normalize_quarterly() {
+        let expected_instrument = Instrument::new(
+            Exchange::Kucoin,
+            MarketType::OrderBook,
+            InstrumentType::Future {
+                base: Currency::new(Cow::Borrowed("BTC")),
+                quote: Currency::new(Cow::Borrowed("USD")),
+                expiry: Expiry::new(NaiveDate::from_ymd_opt(2025, 3, 28).unwrap(), SETTLEMENT_TIME),
+            });
+        assert_eq!(KUCOIN_FUTURES_HANDLER.normalize(MarketType::OrderBook, "XBTMH25"), Some(expected_instrument.clone()));
+        assert_eq!(expected_instrument.contract_spec().unwrap().style, ContractStyle::Inverse);
+    }
+
+    #[test]
+    fn test_normalize_spot() {
+        let expected_instrument = Instrument::new(
+            Exchange::Kucoin,
+            MarketType::OrderBook,
+            InstrumentType::Spot {
+                base: Currency::new(Cow::Borrowed("BTC")),
+                quote: Currency::new(Cow::Borrowed("USDT")),
+            });
+        assert_eq!(KUCOIN_SPOT_HANDLER.normalize(MarketType::OrderBook, "BTC-USDT"), Some(expected_instrument.clone()));
+        assert_eq!(KUCOIN_HANDLER.normalize(MarketType::OrderBook, "BTC-USDT"), Some(expected_instrument));
+    }
+
+    #[test]
+    fn test_normalize_sub_market() {
+        assert_eq!(KucoinHandler::for_market(KucoinMarket::Spot).market(), Some(KucoinMarket::Spot));
+        assert_eq!(KucoinMarket::try_from("Futures"), Ok(KucoinMarket::Futures));
+
+        assert!(matches!(
+            KUCOIN_SPOT_HANDLER.try_normalize(MarketType::OrderBook, "XBTUSDTM"),
+            Err(InstrumentError::UnrecognizedInstrument { exchange: Exchange::Kucoin, .. })
+        ));
+        assert_eq!(KUCOIN_FUTURES_HANDLER.normalize(MarketType::OrderBook, "BTC-USDT"), None);
+    }
+
+    #[test]
+    fn test_normalize_unknown() {
+        assert_eq!(KUCOIN_FUTURES_HANDLER.normalize(MarketType::OrderBook, "XBTUSDT"), None);
+        assert_eq!(KUCOIN_FUTURES_HANDLER.normalize(MarketType::OrderBook, "XBTEURM"), None);
+        assert_eq!(KUCOIN_FUTURES_HANDLER.normalize(MarketType::OrderBook, "M"), None);
+        // Not a spot quote currency
+        assert_eq!(KUCOIN_HANDLER.normalize(MarketType::OrderBook, "BTC-USD"), None);
+        assert_eq!(KUCOIN_HANDLER.normalize(MarketType::OrderBook, "BTC-28MAR25"), None);
+    }
+}
+
+#[cfg(test)]
+mod kucoin_denormalize_tests {
+    use std::borrow::Cow;
+
+    use chrono::NaiveDate;
+
+    use super::{KUCOIN_FUTURES_HANDLER, KUCOIN_HANDLER, KUCOIN_SPOT_HANDLER, SETTLEMENT_TIME};
+    use crate::{Currency, Exchange, ExchangeHandler, Expiry, Instrument, InstrumentType, MarketType};
+
+    #[test]
+    fn test_denorm_roundtrip() {
+        for instrument_name in ["XBTUSDTM", "XBTUSDM", "ETHUSDTM", "XBTMH25", "BTC-USDT"] {
+            let instrument = KUCOIN_HANDLER.normalize(MarketType::OrderBook, instrument_name).unwrap();
+            assert_eq!(KUCOIN_HANDLER.denormalize(&instrument), Some(String::from(instrument_name)));
+        }
+    }
+
+    #[test]
+    fn test_denorm_outside_sub_market() {
+        let instrument = Instrument::new(
+            Exchange::Kucoin,
+            MarketType::OrderBook,
+            InstrumentType::Spot {
+                base: Currency::new(Cow::Borrowed("BTC")),
+                quote: Currency::new(Cow::Borrowed("USDT")),
+            });
+        assert_eq!(KUCOIN_FUTURES_HANDLER.denormalize(&instrument), None);
+        assert_eq!(KUCOIN_SPOT_HANDLER.denormalize(&instrument), Some(String::from("BTC-USDT")));
+    }
+
+    #[test]
+    fn test_denorm_unlisted_spot_quote() {
+        let instrument = Instrument::new(
+            Exchange::Kucoin,
+            MarketType::OrderBook,
+            InstrumentType::Spot {
+                base: Currency::new(Cow::Borrowed("BTC")),
+                quote: Currency::new(Cow::Borrowed("USD")),
+            });
+        assert_eq!(KUCOIN_HANDLER.denormalize(&instrument), None);
+    }
+
+    #[test]
+    fn test_denorm_off_cycle_expiry() {
+        // Only last-Friday expiries have a month code
+        let instrument = Instrument::new(
+            Exchange::Kucoin,
+            MarketType::OrderBook,
+            InstrumentType::Future {
+                base: Currency::new(Cow::Borrowed("BTC")),
+                quote: Currency::new(Cow::Borrowed("USD")),
+                expiry: Expiry::new(NaiveDate::from_ymd_opt(2025, 3, 21).unwrap(), SETTLEMENT_TIME),
+            });
+        assert_eq!(KUCOIN_FUTURES_HANDLER.denormalize(&instrument), None);
+    }
+}
@@ -1,5 +1,103 @@
+use std::borrow::Cow;
+
+use crate::{Currency, Decimal, Exchange, InstrumentError, InstrumentResult};
+
 pub mod deribit;
 pub mod derive;
 pub mod dydx;
 pub mod paradex;
-pub mod aevo;
\ No newline at end of file
+pub mod aevo;
+pub mod binance;
+pub mod okx;
+pub mod bybit;
+pub mod hyperliquid;
+pub mod kraken_futures;
+pub mod bitmex;
+pub mod coinbase;
+pub mod thalex;
+pub mod delta;
+pub mod kucoin;
+
+/// Legacy code of bitcoin used by some exchanges (e.g., XBTUSD)
+const XBT_CODE: &str = "XBT";
+const BTC_CODE: &str = "BTC";
+
+/// Quote currencies recognized when splitting concatenated pairs (e.g., BTCUSDT),
+/// longest first so that FDUSD is not mistaken for USD
+const KNOWN_QUOTES: &[&str] = &[
+    "FDUSD", "USDT", "USDC", "TUSD", "BUSD", "USD", "DAI", "BTC", "ETH", "BNB", "EUR", "GBP", "TRY", "BRL", "JPY",
+];
+
+/// Splits a concatenated pair (e.g., BTCUSDT) into base and quote using the known quote currencies
+pub(crate) fn split_pair(symbol: &str) -> Option<(&str, &str)> {
+    KNOWN_QUOTES.iter().find_map(|quote| {
+        let split = symbol.len().checked_sub(quote.len()).filter(|split| *split > 0)?;
+        let (base, suffix) = symbol.split_at_checked(split)?;
+        suffix.eq_ignore_ascii_case(quote).then_some((base, suffix))
+    })
+}
+
+/// Contract size of an asset's contracts, in tables of the exchanges sizing contracts per asset
+pub(crate) struct AssetContractSize {
+    pub(crate) asset: &'static str,
+    /// Contract size in units of the asset, as a mantissa and a decimal scale
+    pub(crate) size: (u64, u32),
+}
+
+/// Contract size of `asset` in `sizes`, or None if it is not listed
+pub(crate) fn asset_contract_size(sizes: &[AssetContractSize], asset: &str) -> Option<Decimal> {
+    sizes.iter()
+        .find(|size| size.asset == asset)
+        .and_then(|size| Decimal::new(size.size.0, size.size.1))
+}
+
+/// Parses a quote currency listed in `quotes`, so that expiries and suffixes
+/// (e.g., 28MAR25 or PERP) are never mistaken for a quote
+pub(crate) fn parse_listed_quote(exchange: Exchange, instrument_name: &str, quote: &str, quotes: &[&'static str]) -> InstrumentResult<Currency> {
+    quotes.iter()
+        .find(|listed| listed.eq_ignore_ascii_case(quote))
+        .map(|listed| Currency::new(Cow::Borrowed(*listed)))
+        .ok_or_else(|| InstrumentError::unrecognized(exchange, instrument_name))
+}
+
+/// Parses a quote currency among the known quote currencies
+pub(crate) fn parse_known_quote(exchange: Exchange, instrument_name: &str, quote: &str) -> InstrumentResult<Currency> {
+    parse_listed_quote(exchange, instrument_name, quote, KNOWN_QUOTES)
+}
+
+/// Returns true if `quote` is one of the known quote currencies
+pub(crate) fn is_known_quote(quote: &str) -> bool {
+    KNOWN_QUOTES.iter().any(|known| known.eq_ignore_ascii_case(quote))
+}
+
+/// Strips an ASCII suffix regardless of case, keeping a non-empty remainder
+pub(crate) fn strip_suffix_ignore_case<'a>(value: &'a str, suffix: &str) -> Option<&'a str> {
+    let (rest, tail) = value.split_at_checked(value.len().checked_sub(suffix.len())?)?;
+    (!rest.is_empty() && tail.eq_ignore_ascii_case(suffix)).then_some(rest)
+}
+
+/// Maps the legacy XBT code to BTC
+pub(crate) fn normalize_xbt(symbol: &str) -> &str {
+    if symbol.eq_ignore_ascii_case(XBT_CODE) { BTC_CODE } else { symbol }
+}
+
+/// Restores the legacy XBT code of BTC
+pub(crate) fn denormalize_xbt(currency: &Currency) -> &str {
+    if currency.as_ref() == BTC_CODE { XBT_CODE } else { currency.as_ref() }
+}
+
+#[cfg(test)]
+mod split_pair_tests {
+    use super::split_pair;
+
+    #[test]
+    fn test_split_pair() {
+        assert_eq!(split_pair("BTCUSDT"), Some(("BTC", "USDT")));
+        assert_eq!(split_pair("btcusd"), Some(("btc", "usd")));
+        assert_eq!(split_pair("ETHBTC"), Some(("ETH", "BTC")));
+        assert_eq!(split_pair("BTCFDUSD"), Some(("BTC", "FDUSD")));
+        assert_eq!(split_pair("1000PEPEUSDT"), Some(("1000PEPE", "USDT")));
+        assert_eq!(split_pair("USDT"), None);
+        assert_eq!(split_pair("BTCXYZ"), None);
+    }
+}
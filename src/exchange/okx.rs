@@ -0,0 +1,338 @@
+use chrono::NaiveTime;
+use std::borrow::Cow;
+
+use crate::exchange::{asset_contract_size, is_known_quote, parse_known_quote, AssetContractSize};
+use crate::{denormalize_expiry, parse_base, ContractSpec, ContractStyle, Currency, Decimal, Exchange, ExchangeHandler, Expiry, Instrument, InstrumentError, InstrumentResult, InstrumentType, MarketType, OptionKind, Strike};
+
+/// Quote of inverse (coin-margined) contracts, e.g. BTC-USD-SWAP
+const INVERSE_QUOTE_CURRENCY: &str = "USD";
+const DEFAULT_EXPIRY_FORMAT: &str = "%y%m%d";
+const STRIKE_DECIMAL_SEPARATOR: char = '.';
+/// Suffix of perpetual swaps (e.g., BTC-USDT-SWAP)
+const SWAP_SUFFIX: &str = "SWAP";
+/// Face value in USD of BTC inverse contracts (other currencies use 10 USD)
+const BTC_INVERSE_CONTRACT_SIZE: u64 = 100;
+const INVERSE_CONTRACT_SIZE: u64 = 10;
+/// Expiring contracts settle at 08:00 UTC
+const SETTLEMENT_TIME: NaiveTime = NaiveTime::from_hms_opt(8, 0, 0).unwrap();
+
+/// Contract values (ctVal, or ctMult for options) of USDT margined swaps and futures. Assets missing from these tables
+/// have their own values, so their contract spec is unknown.
+const USDT_CONTRACT_VALUES: [AssetContractSize; 6] = [
+    AssetContractSize { asset: "BTC", size: (1, 2) },
+    AssetContractSize { asset: "ETH", size: (1, 1) },
+    AssetContractSize { asset: "SOL", size: (1, 0) },
+    AssetContractSize { asset: "LTC", size: (1, 0) },
+    AssetContractSize { asset: "XRP", size: (100, 0) },
+    AssetContractSize { asset: "DOGE", size: (1000, 0) },
+];
+
+/// Contract values of USDC margined swaps and futures
+const USDC_CONTRACT_VALUES: [AssetContractSize; 2] = [
+    AssetContractSize { asset: "BTC", size: (1, 4) },
+    AssetContractSize { asset: "ETH", size: (1, 3) },
+];
+
+/// Contract multipliers of options
+const OPTION_CONTRACT_VALUES: [AssetContractSize; 2] = [
+    AssetContractSize { asset: "BTC", size: (1, 2) },
+    AssetContractSize { asset: "ETH", size: (1, 1) },
+];
+
+pub struct OkxHandler;
+
+// Create a static instance to avoid allocations
+pub static OKX_HANDLER: OkxHandler = OkxHandler;
+
+impl OkxHandler {
+    /// Parses a `YYMMDD` expiry
+    fn parse_expiry(&self, expiry: &str) -> InstrumentResult<Expiry> {
+        Expiry::parse(expiry, DEFAULT_EXPIRY_FORMAT, self.settlement_time())
+            .ok_or_else(|| InstrumentError::invalid_expiry(Exchange::Okx, expiry))
+    }
+
+    /// Contract value of `asset` in `values`, or an error if it is not listed
+    fn contract_value(&self, values: &[AssetContractSize], instrument_type: &InstrumentType) -> InstrumentResult<Decimal> {
+        asset_contract_size(values, instrument_type.base())
+            .ok_or_else(|| InstrumentError::unknown_contract_size(Exchange::Okx, instrument_type))
+    }
+
+    /// Validates the quote currency of an instrument ID, so that futures (e.g., BTC-28MAR25)
+    /// and other venues' perpetuals (e.g., ETH-PERP) are not taken for spot pairs
+    fn parse_quote(&self, instrument_name: &str, quote: &str) -> InstrumentResult<Currency> {
+        parse_known_quote(Exchange::Okx, instrument_name, quote)
+    }
+}
+
+impl ExchangeHandler for OkxHandler {
+    fn try_normalize(&self, market_type: MarketType, instrument_name: &str) -> InstrumentResult<Instrument> {
+        // Split the instrument ID into parts
+        let parts: Vec<&str> = instrument_name.split('-').collect();
+
+        let instrument_type = match parts.as_slice() {
+            // Spot: e.g., BTC-USDT
+            [base, quote] => InstrumentType::Spot {
+                base: parse_base(Exchange::Okx, base)?,
+                quote: self.parse_quote(instrument_name, quote)?,
+            },
+
+            // Perpetual swap: e.g., BTC-USDT-SWAP (linear) or BTC-USD-SWAP (inverse)
+            [base, quote, swap] if swap.eq_ignore_ascii_case(SWAP_SUFFIX) => InstrumentType::Perpetual {
+                base: parse_base(Exchange::Okx, base)?,
+                quote: self.parse_quote(instrument_name, quote)?,
+            },
+
+            // Future: e.g., BTC-USD-250328
+            [base, quote, expiry_str] => InstrumentType::Future {
+                base: parse_base(Exchange::Okx, base)?,
+                quote: self.parse_quote(instrument_name, quote)?,
+                expiry: self.parse_expiry(expiry_str)?,
+            },
+
+            // Option: e.g., BTC-USD-250328-100000-C
+            [base, quote, expiry_str, strike_str, kind_str] => {
+                let strike = Strike::parse_with_separator(strike_str, STRIKE_DECIMAL_SEPARATOR)
+                    .ok_or_else(|| InstrumentError::invalid_strike(Exchange::Okx, strike_str))?;
+
+                let kind = OptionKind::try_from(*kind_str)
+                    .map_err(|_| InstrumentError::invalid_option_kind(Exchange::Okx, kind_str))?;
+
+                InstrumentType::Option {
+                    base: parse_base(Exchange::Okx, base)?,
+                    quote: self.parse_quote(instrument_name, quote)?,
+                    expiry: self.parse_expiry(expiry_str)?,
+                    strike,
+                    kind,
+                }
+            },
+
+            // No matching format
+            _ => return Err(InstrumentError::unrecognized(Exchange::Okx, instrument_name)),
+        };
+
+        Ok(Instrument::new(Exchange::Okx, market_type, instrument_type))
+    }
+
+    fn try_denormalize(&self, instrument: &Instrument) -> InstrumentResult<String> {
+        // Check if this is the right exchange handler
+        if instrument.exchange != Exchange::Okx {
+            return Err(InstrumentError::ExchangeMismatch { expected: Exchange::Okx, found: instrument.exchange });
+        }
+
+        match &instrument.instrument_type {
+            InstrumentType::Spot { base, quote } if is_known_quote(quote.as_ref()) => {
+                Ok(format!("{}-{}", base.as_ref(), quote.as_ref()))
+            },
+
+            InstrumentType::Perpetual { base, quote } if is_known_quote(quote.as_ref()) => {
+                Ok(format!("{}-{}-{}", base.as_ref(), quote.as_ref(), SWAP_SUFFIX))
+            },
+
+            InstrumentType::Future { base, quote, expiry } if is_known_quote(quote.as_ref()) => {
+                Ok(format!("{}-{}-{}", base.as_ref(), quote.as_ref(), denormalize_expiry(expiry, DEFAULT_EXPIRY_FORMAT)))
+            },
+
+            InstrumentType::Option { base, quote, expiry, strike, kind } if is_known_quote(quote.as_ref()) => {
+                Ok(format!("{}-{}-{}-{}-{}",
+                    base.as_ref(),
+                    quote.as_ref(),
+                    denormalize_expiry(expiry, DEFAULT_EXPIRY_FORMAT),
+                    strike.format_with_separator(STRIKE_DECIMAL_SEPARATOR),
+                    kind))
+            },
+
+            _ => Err(InstrumentError::unsupported_instrument_type(Exchange::Okx, &instrument.instrument_type)),
+        }
+    }
+
+    fn supports_instrument_type(&self, instrument_type: &InstrumentType) -> bool {
+        matches!(instrument_type,
+            InstrumentType::Spot { .. } | InstrumentType::Perpetual { .. }
+            | InstrumentType::Future { .. } | InstrumentType::Option { .. })
+    }
+
+    fn settlement_time(&self) -> NaiveTime {
+        SETTLEMENT_TIME
+    }
+
+    fn contract_style(&self, instrument_type: &InstrumentType) -> ContractStyle {
+        // Coin-margined futures, swaps and options are inverse
+        match instrument_type {
+            InstrumentType::Future { quote, .. } | InstrumentType::Perpetual { quote, .. } | InstrumentType::Option { quote, .. }
+                if quote.as_ref().eq_ignore_ascii_case(INVERSE_QUOTE_CURRENCY) => ContractStyle::Inverse,
+            _ => ContractStyle::Linear,
+        }
+    }
+
+    fn contract_spec(&self, instrument: &Instrument) -> InstrumentResult<ContractSpec> {
+        let instrument_type = &instrument.instrument_type;
+        let base = Currency::new(Cow::Owned(instrument_type.base().to_string()));
+        let quote = Currency::new(Cow::Owned(instrument_type.quote().to_string()));
+        let inverse = instrument.contract_style() == ContractStyle::Inverse;
+
+        Ok(match instrument_type {
+            // Coin-margined futures and swaps are inverse, sized in USD
+            InstrumentType::Future { .. } | InstrumentType::Perpetual { .. } if inverse => {
+                let face_value = if base.as_ref() == "BTC" { BTC_INVERSE_CONTRACT_SIZE } else { INVERSE_CONTRACT_SIZE };
+                ContractSpec::inverse(base, Decimal::from(face_value))
+            },
+
+            // USD quoted options settle in the base currency, sized in a fraction of it
+            InstrumentType::Option { .. } if inverse => {
+                let multiplier = self.contract_value(&OPTION_CONTRACT_VALUES, instrument_type)?;
+                ContractSpec::inverse(base, multiplier)
+            },
+
+            // Linear swaps and futures are sized in a fraction (or multiple) of the base currency
+            InstrumentType::Future { .. } | InstrumentType::Perpetual { .. } => {
+                let values: &[AssetContractSize] = match quote.as_ref() {
+                    "USDT" => &USDT_CONTRACT_VALUES,
+                    "USDC" => &USDC_CONTRACT_VALUES,
+                    _ => &[],
+                };
+                let multiplier = self.contract_value(values, instrument_type)?;
+                ContractSpec::linear(quote, multiplier)
+            },
+
+            _ => ContractSpec::linear(quote, Decimal::from(1)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod okx_normalize_tests {
+    use std::borrow::Cow;
+
+    use chrono::NaiveDate;
+
+    use super::SETTLEMENT_TIME;
+    use crate::{exchange::okx::OkxHandler, ContractSpec, ContractStyle, Currency, Decimal, Exchange, ExchangeHandler, Expiry, Instrument, InstrumentError, InstrumentType, MarketType, OptionKind, Strike};
+
+    #[test]
+    fn test_normalize_spot() {
+        let exchange = OkxHandler;
+        let expected_instrument = Instrument::new(
+            Exchange::Okx,
+            MarketType::OrderBook,
+            InstrumentType::Spot {
+                base: Currency::new(Cow::Borrowed("BTC")),
+                quote: Currency::new(Cow::Borrowed("USDT")),
+            });
+        assert_eq!(exchange.normalize(MarketType::OrderBook, "BTC-USDT"), Some(expected_instrument));
+    }
+
+    #[test]
+    fn test_normalize_linear_and_inverse_swaps() {
+        let exchange = OkxHandler;
+        let linear = exchange.normalize(MarketType::OrderBook, "BTC-USDT-SWAP").unwrap();
+        let inverse = exchange.normalize(MarketType::OrderBook, "BTC-USD-SWAP").unwrap();
+        assert_eq!(linear.instrument_type, InstrumentType::Perpetual {
+            base: Currency::new(Cow::Borrowed("BTC")),
+            quote: Currency::new(Cow::Borrowed("USDT")),
+        });
+        assert_ne!(linear, inverse);
+        assert_eq!(linear.contract_spec().unwrap().style, ContractStyle::Linear);
+        assert_eq!(linear.contract_spec().unwrap(), ContractSpec::linear(Currency::new(Cow::Borrowed("USDT")), Decimal::new(1, 2).unwrap()));
+        let eth = exchange.normalize(MarketType::OrderBook, "ETH-USDT-SWAP").unwrap();
+        assert_eq!(eth.contract_spec().unwrap().multiplier, Decimal::new(1, 1).unwrap());
+        assert_eq!(inverse.contract_spec().unwrap(), ContractSpec::inverse(Currency::new(Cow::Borrowed("BTC")), Decimal::from(100)));
+        let pepe = exchange.normalize(MarketType::OrderBook, "PEPE-USDT-SWAP").unwrap();
+        assert!(matches!(pepe.contract_spec(), Err(InstrumentError::UnknownContractSize { exchange: Exchange::Okx, .. })));
+    }
+
+    #[test]
+    fn test_normalize_future() {
+        let exchange = OkxHandler;
+        let expected_instrument = Instrument::new(
+            Exchange::Okx,
+            MarketType::OrderBook,
+            InstrumentType::Future {
+                base: Currency::new(Cow::Borrowed("BTC")),
+                quote: Currency::new(Cow::Borrowed("USD")),
+                expiry: Expiry::new(NaiveDate::from_ymd_opt(2025, 3, 28).unwrap(), SETTLEMENT_TIME),
+            });
+        assert_eq!(exchange.normalize(MarketType::OrderBook, "BTC-USD-250328"), Some(expected_instrument));
+    }
+
+    #[test]
+    fn test_normalize_option() {
+        let exchange = OkxHandler;
+        let expected_instrument = Instrument::new(
+            Exchange::Okx,
+            MarketType::OrderBook,
+            InstrumentType::Option {
+                base: Currency::new(Cow::Borrowed("BTC")),
+                quote: Currency::new(Cow::Borrowed("USD")),
+                expiry: Expiry::new(NaiveDate::from_ymd_opt(2025, 3, 28).unwrap(), SETTLEMENT_TIME),
+                strike: Strike::from(100000),
+                kind: OptionKind::Call,
+            });
+        assert_eq!(exchange.normalize(MarketType::OrderBook, "BTC-USD-250328-100000-C"), Some(expected_instrument.clone()));
+        assert_eq!(expected_instrument.contract_spec().unwrap(), ContractSpec::inverse(Currency::new(Cow::Borrowed("BTC")), Decimal::new(1, 2).unwrap()));
+    }
+
+    #[test]
+    fn test_normalize_unknown() {
+        let exchange = OkxHandler;
+        assert_eq!(exchange.normalize(MarketType::OrderBook, "BTC-PERPETUAL-X-Y"), None);
+        // Other venues' futures and perpetuals are not spot pairs
+        assert_eq!(exchange.normalize(MarketType::OrderBook, "BTC-28MAR25"), None);
+        assert_eq!(exchange.normalize(MarketType::OrderBook, "ETH-PERP"), None);
+        assert!(matches!(
+            exchange.try_normalize(MarketType::OrderBook, "BTC-USD-28MAR25"),
+            Err(InstrumentError::InvalidExpiry { exchange: Exchange::Okx, .. })
+        ));
+    }
+}
+
+#[cfg(test)]
+mod okx_denormalize_tests {
+    use std::borrow::Cow;
+
+    use chrono::NaiveDate;
+
+    use super::SETTLEMENT_TIME;
+    use crate::{exchange::okx::OkxHandler, parse_standard_format, Currency, Exchange, ExchangeHandler, Expiry, Instrument, InstrumentError, InstrumentType, MarketType, OptionKind, Strike};
+
+    #[test]
+    fn test_denorm_option() {
+        let instrument = Instrument::new(
+            Exchange::Okx,
+            MarketType::OrderBook,
+            InstrumentType::Option {
+                base: Currency::new(Cow::Borrowed("ETH")),
+                quote: Currency::new(Cow::Borrowed("USD")),
+                expiry: Expiry::new(NaiveDate::from_ymd_opt(2025, 3, 28).unwrap(), SETTLEMENT_TIME),
+                strike: Strike::from(3000),
+                kind: OptionKind::Put,
+            });
+        let exchange = OkxHandler;
+        assert_eq!(exchange.denormalize(&instrument), Some(String::from("ETH-USD-250328-3000-P")));
+    }
+
+    #[test]
+    fn test_denorm_swap() {
+        let instrument = Instrument::new(
+            Exchange::Okx,
+            MarketType::Funding,
+            InstrumentType::Perpetual {
+                base: Currency::new(Cow::Borrowed("SOL")),
+                quote: Currency::new(Cow::Borrowed("USDC")),
+            });
+        let exchange = OkxHandler;
+        assert_eq!(exchange.denormalize(&instrument), Some(String::from("SOL-USDC-SWAP")));
+    }
+
+    #[test]
+    fn test_denorm_unknown_quote() {
+        let exchange = OkxHandler;
+        for (kind, name) in [("s", "BTC-FOO"), ("p", "BTC-FOO"), ("f", "BTC-FOO-20250328"), ("o", "BTC-FOO-20250328-100000-C")] {
+            let instrument = Instrument::new(Exchange::Okx, MarketType::OrderBook, InstrumentType::from_parts(kind, name).unwrap());
+            assert!(matches!(
+                exchange.try_denormalize(&instrument),
+                Err(InstrumentError::UnsupportedInstrumentType { exchange: Exchange::Okx, .. })
+            ));
+        }
+        assert!(parse_standard_format("o.p.BTC-FOO.okx").is_err());
+    }
+}
@@ -1,59 +1,123 @@
+use chrono::NaiveTime;
 use std::borrow::Cow;
 
-use tracing::error;
+use crate::exchange::{is_known_quote, parse_known_quote};
+use crate::{denormalize_expiry, normalize_expiry, parse_base, parse_expiry_date, ContractSpec, Currency, Decimal, Exchange, ExchangeHandler, Expiry, IndexKind, Instrument, InstrumentError, InstrumentResult, InstrumentType, MarketType, OptionKind, Strike};
 
-use crate::{Currency, Exchange, ExchangeHandler, Instrument, InstrumentType, MarketType};
-
-const LOG_CTX: &str = "normify::exchange#paradex";
+/// Every market is margined and settled in USDC
+const SETTLEMENT_CURRENCY: &str = "USDC";
+const DEFAULT_EXPIRY_FORMAT: &str = "%d%b%y";
+const STRIKE_DECIMAL_SEPARATOR: char = '.';
+/// Dated options settle at 08:00 UTC
+const SETTLEMENT_TIME: NaiveTime = NaiveTime::from_hms_opt(8, 0, 0).unwrap();
+/// Index prices are only published as tickers
+const INDEX_MARKET_TYPE: MarketType = MarketType::Ticker;
 pub struct ParadexHandler;
         
 // Create a static instance to avoid allocations
 pub static PARADEX_HANDLER: ParadexHandler = ParadexHandler;
 
+impl ParadexHandler {
+    /// Parses a `DDMMMYY` expiry
+    fn parse_expiry(&self, expiry: &str) -> InstrumentResult<Expiry> {
+        parse_expiry_date(expiry, DEFAULT_EXPIRY_FORMAT)
+            .and_then(|_| normalize_expiry(expiry, self.settlement_time()))
+            .ok_or_else(|| InstrumentError::invalid_expiry(Exchange::Paradex, expiry))
+    }
+
+    fn parse_strike(&self, strike: &str) -> InstrumentResult<Strike> {
+        Strike::parse_with_separator(strike, STRIKE_DECIMAL_SEPARATOR)
+            .ok_or_else(|| InstrumentError::invalid_strike(Exchange::Paradex, strike))
+    }
+}
+
 impl ExchangeHandler for ParadexHandler {
 
-    fn normalize(&self, market_type: MarketType, instrument_name: &str) -> Option<Instrument> {
+    fn try_normalize(&self, market_type: MarketType, instrument_name: &str) -> InstrumentResult<Instrument> {
 
         if !self.supports_market_type(&market_type) {
-            error!(name: LOG_CTX, "denormalize::Market Type for is unsupported: {:?}", market_type);
-            return None;
+            return Err(InstrumentError::UnsupportedMarketType { exchange: Exchange::Paradex, market_type });
         }
         let parts: Vec<&str> = instrument_name.split('-').collect();
     
         match parts.as_slice() {
             [base, quote, "perp" | "PERP"] => 
-                Some(Instrument::new(
+                Ok(Instrument::new(
                     Exchange::Paradex, 
                     market_type, 
                     InstrumentType::Perpetual {
-                        base: Currency(Cow::Owned(base.to_string())), 
-                        quote: Currency(Cow::Owned(quote.to_string())),
+                        base: parse_base(Exchange::Paradex, base)?, 
+                        quote: parse_known_quote(Exchange::Paradex, instrument_name, quote)?,
+                    }
+                )),
+            // Price index of the underlying: e.g., BTC-USD
+            [base, quote] if market_type == INDEX_MARKET_TYPE && !quote.eq_ignore_ascii_case("perp") => 
+                Ok(Instrument::new(
+                    Exchange::Paradex, 
+                    market_type, 
+                    InstrumentType::Index {
+                        base: parse_base(Exchange::Paradex, base)?, 
+                        quote: parse_known_quote(Exchange::Paradex, instrument_name, quote)?,
+                        kind: IndexKind::Price,
                     }
                 )),
-            _ => {
-                error!(name: LOG_CTX, "normalize::Unexpected instrument format: {:?}", instrument_name);
-                None
-            }
+            // Perpetual option: e.g., BTC-USD-100000-C
+            [base, quote, strike, kind] if OptionKind::try_from(*kind).is_ok() => 
+                Ok(Instrument::new(
+                    Exchange::Paradex, 
+                    market_type, 
+                    InstrumentType::PerpetualOption {
+                        base: parse_base(Exchange::Paradex, base)?, 
+                        quote: parse_known_quote(Exchange::Paradex, instrument_name, quote)?,
+                        strike: self.parse_strike(strike)?,
+                        kind: OptionKind::try_from(*kind)
+                            .map_err(|_| InstrumentError::invalid_option_kind(Exchange::Paradex, kind))?,
+                    }
+                )),
+            // Dated option: e.g., BTC-USD-28MAR25-100000-C
+            [base, quote, expiry, strike, kind] => 
+                Ok(Instrument::new(
+                    Exchange::Paradex, 
+                    market_type, 
+                    InstrumentType::Option {
+                        base: parse_base(Exchange::Paradex, base)?, 
+                        quote: parse_known_quote(Exchange::Paradex, instrument_name, quote)?,
+                        expiry: self.parse_expiry(expiry)?,
+                        strike: self.parse_strike(strike)?,
+                        kind: OptionKind::try_from(*kind)
+                            .map_err(|_| InstrumentError::invalid_option_kind(Exchange::Paradex, kind))?,
+                    }
+                )),
+            _ => Err(InstrumentError::unrecognized(Exchange::Paradex, instrument_name)),
         }
     }
 
-    fn denormalize(&self, instrument: &Instrument) -> Option<String> {
+    fn try_denormalize(&self, instrument: &Instrument) -> InstrumentResult<String> {
         if instrument.exchange != Exchange::Paradex {
-            error!(name: LOG_CTX, "denormalize::Attempted to use Paradex handler for {:?}", instrument.exchange);
-            return None;
+            return Err(InstrumentError::ExchangeMismatch { expected: Exchange::Paradex, found: instrument.exchange });
         }
 
         if !self.supports_instrument_type(&instrument.instrument_type) {
-            error!(name: LOG_CTX, "denormalize::Instrument Type for {:?} is unsupported", instrument.instrument_type);
-            return None;
+            return Err(InstrumentError::unsupported_instrument_type(Exchange::Paradex, &instrument.instrument_type));
         }
         if !self.supports_market_type(&instrument.market_type) {
-            error!(name: LOG_CTX, "denormalize::Market Type for {:?} is unsupported",instrument.market_type);
-            return None;
+            return Err(InstrumentError::UnsupportedMarketType { exchange: Exchange::Paradex, market_type: instrument.market_type.clone() });
         }
         match &instrument.instrument_type {
-            InstrumentType::Perpetual{base, quote} => Some(format!("{}-{}-PERP", base.as_ref(), quote.as_ref())),
-            _ => None
+            InstrumentType::Perpetual{base, quote} if is_known_quote(quote.as_ref()) => Ok(format!("{}-{}-PERP", base.as_ref(), quote.as_ref())),
+            InstrumentType::Index{base, quote, kind: IndexKind::Price} if instrument.market_type == INDEX_MARKET_TYPE && is_known_quote(quote.as_ref()) => Ok(format!("{}-{}", base.as_ref(), quote.as_ref())),
+            InstrumentType::PerpetualOption{base, quote, strike, kind} if is_known_quote(quote.as_ref()) => Ok(format!("{}-{}-{}-{}", 
+                base.as_ref(), 
+                quote.as_ref(), 
+                strike.format_with_separator(STRIKE_DECIMAL_SEPARATOR), 
+                kind)),
+            InstrumentType::Option{base, quote, expiry, strike, kind} if is_known_quote(quote.as_ref()) => Ok(format!("{}-{}-{}-{}-{}", 
+                base.as_ref(), 
+                quote.as_ref(), 
+                denormalize_expiry(expiry, DEFAULT_EXPIRY_FORMAT), 
+                strike.format_with_separator(STRIKE_DECIMAL_SEPARATOR), 
+                kind)),
+            _ => Err(InstrumentError::unsupported_instrument_type(Exchange::Paradex, &instrument.instrument_type)),
         }
     }
 
@@ -63,6 +127,16 @@ impl ExchangeHandler for ParadexHandler {
 
     fn supports_instrument_type(&self, instrument_type: &InstrumentType) -> bool {
         matches!(instrument_type, InstrumentType::Perpetual { base: _, quote: _ })
+        || matches!(instrument_type, InstrumentType::PerpetualOption { .. } | InstrumentType::Option { .. })
+        || matches!(instrument_type, InstrumentType::Index { kind: IndexKind::Price, .. })
+    }
+
+    fn settlement_time(&self) -> NaiveTime {
+        SETTLEMENT_TIME
+    }
+
+    fn contract_spec(&self, _instrument: &Instrument) -> InstrumentResult<ContractSpec> {
+        Ok(ContractSpec::linear(Currency::new(Cow::Borrowed(SETTLEMENT_CURRENCY)), Decimal::from(1)))
     }
 }
 
@@ -70,7 +144,10 @@ impl ExchangeHandler for ParadexHandler {
 mod paradex_normalize_tests{
     use std::borrow::Cow;
 
-    use crate::{exchange::paradex::ParadexHandler, Currency, Exchange, ExchangeHandler, Instrument, InstrumentType, MarketType};
+    use chrono::NaiveDate;
+
+    use super::SETTLEMENT_TIME;
+    use crate::{exchange::paradex::ParadexHandler, Currency, Exchange, ExchangeHandler, Expiry, IndexKind, Instrument, InstrumentType, MarketType, OptionKind, Strike};
 
     #[test]
     fn test_normalize_perpetual() {
@@ -87,10 +164,67 @@ mod paradex_normalize_tests{
         assert_eq!(exchange.normalize(MarketType::OrderBook, &instrument_name), Some(expected_instrument));
     }
     #[test]
+    fn test_normalize_index() {
+        let instrument_name = "ETH-USD";
+        let exchange = ParadexHandler;
+        let expected_instrument = Instrument::new(
+            Exchange::Paradex, 
+            MarketType::Ticker, 
+            InstrumentType::Index {
+                base: Currency::new(Cow::Borrowed("ETH")), 
+                quote: Currency::new(Cow::Borrowed("USD")),
+                kind: IndexKind::Price,
+            });
+        assert_eq!(exchange.normalize(MarketType::Ticker, instrument_name), Some(expected_instrument.clone()));
+        assert_eq!(exchange.denormalize(&expected_instrument), Some(String::from(instrument_name)));
+    }
+    #[test]
+    fn test_normalize_perpetual_option() {
+        let instrument_name = "BTC-USD-100000-C";
+        let exchange = ParadexHandler;
+        let expected_instrument = Instrument::new(
+            Exchange::Paradex, 
+            MarketType::OrderBook, 
+            InstrumentType::PerpetualOption {
+                base: Currency::new(Cow::Borrowed("BTC")), 
+                quote: Currency::new(Cow::Borrowed("USD")),
+                strike: Strike::from(100000),
+                kind: OptionKind::Call,
+            });
+        assert_eq!(exchange.normalize(MarketType::OrderBook, instrument_name), Some(expected_instrument.clone()));
+        assert_eq!(expected_instrument.instrument_type.expiry(), None);
+        assert_eq!(expected_instrument.to_string(), "o.po.BTC-USD-100000-C.paradex");
+    }
+    #[test]
+    fn test_normalize_dated_option() {
+        let instrument_name = "ETH-USD-28MAR25-2500-P";
+        let exchange = ParadexHandler;
+        let expected_instrument = Instrument::new(
+            Exchange::Paradex, 
+            MarketType::OrderBook, 
+            InstrumentType::Option {
+                base: Currency::new(Cow::Borrowed("ETH")), 
+                quote: Currency::new(Cow::Borrowed("USD")),
+                expiry: Expiry::new(NaiveDate::from_ymd_opt(2025, 3, 28).unwrap(), SETTLEMENT_TIME),
+                strike: Strike::from(2500),
+                kind: OptionKind::Put,
+            });
+        assert_eq!(exchange.normalize(MarketType::OrderBook, instrument_name), Some(expected_instrument));
+    }
+    #[test]
     fn test_normalize_unknown() {
         let instrument_name = "BTC-PERP".to_string();
         let exchange = ParadexHandler;
         assert_eq!(exchange.normalize(MarketType::OrderBook, &instrument_name), None);
+        // Deribit-style option names have no quote
+        assert_eq!(exchange.normalize(MarketType::OrderBook, "BTC-28MAR25-100000-C"), None);
+        // Expiries and suffixes are not quote currencies
+        assert_eq!(exchange.normalize(MarketType::Ticker, "BTC-28MAR25"), None);
+        assert_eq!(exchange.normalize(MarketType::Ticker, "BTC-PERPETUAL"), None);
+        assert_eq!(exchange.normalize(MarketType::OrderBook, "BTC-28MAR25-PERP"), None);
+        // Two-part names are price indices, which are only published as tickers
+        assert_eq!(exchange.normalize(MarketType::Funding, "ETH-USD"), None);
+        assert_eq!(exchange.normalize(MarketType::OrderBook, "ETH-USD"), None);
     }
 }
 
@@ -98,7 +232,10 @@ mod paradex_normalize_tests{
 mod paradex_denormalize_tests{
     use std::borrow::Cow;
 
-    use crate::{exchange::paradex::ParadexHandler, Currency, Exchange, ExchangeHandler, Instrument, InstrumentType, MarketType};
+    use chrono::NaiveDate;
+
+    use super::SETTLEMENT_TIME;
+    use crate::{exchange::paradex::ParadexHandler, parse_standard_format, Currency, Exchange, ExchangeHandler, Expiry, IndexKind, Instrument, InstrumentError, InstrumentType, MarketType, OptionKind, Strike};
 
     #[test]
     fn test_denorm_perp() {
@@ -112,4 +249,76 @@ mod paradex_denormalize_tests{
         let exchange = ParadexHandler;
         assert_eq!(exchange.denormalize(&instrument), Some(String::from("BTC-USD-PERP")));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_denorm_unknown_quote() {
+        let instrument = Instrument::new(
+            Exchange::Paradex, 
+            MarketType::OrderBook, 
+            InstrumentType::Perpetual{
+                base: Currency::new(Cow::Borrowed("BTC")), 
+                quote: Currency::new(Cow::Borrowed("28MAR25"))
+            });
+        let exchange = ParadexHandler;
+        assert_eq!(exchange.denormalize(&instrument), None);
+
+        let index = Instrument::new(
+            Exchange::Paradex, 
+            MarketType::Funding, 
+            InstrumentType::Index{
+                base: Currency::new(Cow::Borrowed("BTC")), 
+                quote: Currency::new(Cow::Borrowed("USD")),
+                kind: IndexKind::Price,
+            });
+        assert_eq!(exchange.denormalize(&index), None);
+    }
+
+    #[test]
+    fn test_denorm_perpetual_option() {
+        let instrument = Instrument::new(
+            Exchange::Paradex, 
+            MarketType::Funding, 
+            InstrumentType::PerpetualOption{
+                base: Currency::new(Cow::Borrowed("ETH")), 
+                quote: Currency::new(Cow::Borrowed("USD")),
+                strike: Strike::from(3000),
+                kind: OptionKind::Put,
+            });
+        let exchange = ParadexHandler;
+        assert_eq!(exchange.denormalize(&instrument), Some(String::from("ETH-USD-3000-P")));
+    }
+
+    #[test]
+    fn test_denorm_options_unknown_quote() {
+        let exchange = ParadexHandler;
+        let perpetual_option = Instrument::new(
+            Exchange::Paradex, 
+            MarketType::OrderBook, 
+            InstrumentType::PerpetualOption{
+                base: Currency::new(Cow::Borrowed("ETH")), 
+                quote: Currency::new(Cow::Borrowed("FOO")),
+                strike: Strike::from(3000),
+                kind: OptionKind::Put,
+            });
+        assert!(matches!(
+            exchange.try_denormalize(&perpetual_option),
+            Err(InstrumentError::UnsupportedInstrumentType { exchange: Exchange::Paradex, .. })
+        ));
+
+        let option = Instrument::new(
+            Exchange::Paradex, 
+            MarketType::OrderBook, 
+            InstrumentType::Option{
+                base: Currency::new(Cow::Borrowed("BTC")), 
+                quote: Currency::new(Cow::Borrowed("FOO")),
+                expiry: Expiry::new(NaiveDate::from_ymd_opt(2025, 3, 28).unwrap(), SETTLEMENT_TIME),
+                strike: Strike::from(100000),
+                kind: OptionKind::Call,
+            });
+        assert!(matches!(
+            exchange.try_denormalize(&option),
+            Err(InstrumentError::UnsupportedInstrumentType { exchange: Exchange::Paradex, .. })
+        ));
+        assert!(parse_standard_format("o.o.BTC-FOO-20250328-100000-C.paradex").is_err());
+    }
+}
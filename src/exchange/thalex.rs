@@ -0,0 +1,230 @@
+use chrono::NaiveTime;
+use std::borrow::Cow;
+
+use crate::exchange::deribit::DERIBIT_HANDLER;
+use crate::{denormalize_expiry, normalize_expiry, parse_base, parse_expiry_date, ComboKind, ContractSpec, Currency, Decimal, Exchange, ExchangeHandler, Expiry, Instrument, InstrumentError, InstrumentResult, InstrumentType, MarketType};
+
+/// Every product is quoted, margined and settled in USD
+const QUOTE_CURRENCY: &str = "USD";
+const DEFAULT_EXPIRY_FORMAT: &str = "%d%b%y";
+const PERPETUAL_SUFFIX: &str = "PERPETUAL";
+/// Fractional strikes use 'd' as the decimal separator, like on Deribit (e.g., 0d625)
+const STRIKE_DECIMAL_SEPARATOR: char = 'd';
+/// Expiring contracts settle at 08:00 UTC
+const SETTLEMENT_TIME: NaiveTime = NaiveTime::from_hms_opt(8, 0, 0).unwrap();
+
+/// Handler for Thalex, which names perpetuals, futures and options like Deribit (e.g., BTC-PERPETUAL,
+/// BTC-28MAR25, BTC-28MAR25-100000-C) and adds calendar rolls between two futures (e.g., BTC-28MAR25-27JUN25)
+pub struct ThalexHandler;
+
+// Create a static instance to avoid allocations
+pub static THALEX_HANDLER: ThalexHandler = ThalexHandler;
+
+impl ThalexHandler {
+    /// Parses a `DDMMMYY` expiry
+    fn parse_expiry(&self, expiry: &str) -> Option<Expiry> {
+        parse_expiry_date(expiry, DEFAULT_EXPIRY_FORMAT)
+            .and_then(|_| normalize_expiry(expiry, self.settlement_time()))
+    }
+
+    /// Normalizes a calendar roll (e.g., BTC-28MAR25-27JUN25) as a future spread
+    fn normalize_roll(&self, instrument_name: &str, base: &str, front: Expiry, back: Expiry) -> InstrumentResult<InstrumentType> {
+        let base = parse_base(Exchange::Thalex, base)?;
+        let quote = Currency::new(Cow::Borrowed(QUOTE_CURRENCY));
+        let legs = ComboKind::FutureSpread.build_legs(&base, &quote, &[Some(front), Some(back)], &[])
+            .ok_or_else(|| InstrumentError::unrecognized(Exchange::Thalex, instrument_name))?;
+        Ok(InstrumentType::Combo { kind: ComboKind::FutureSpread, legs })
+    }
+}
+
+impl ExchangeHandler for ThalexHandler {
+    fn try_normalize(&self, market_type: MarketType, instrument_name: &str) -> InstrumentResult<Instrument> {
+        // Calendar roll: e.g., BTC-28MAR25-27JUN25
+        if let [base, front, back] = instrument_name.split('-').collect::<Vec<_>>().as_slice() {
+            if let (Some(front), Some(back)) = (self.parse_expiry(front), self.parse_expiry(back)) {
+                let instrument_type = self.normalize_roll(instrument_name, base, front, back)?;
+                return Ok(Instrument::new(Exchange::Thalex, market_type, instrument_type));
+            }
+        }
+
+        // Perpetual, future or option, named like on Deribit
+        let instrument = DERIBIT_HANDLER.normalize_as(Exchange::Thalex, market_type, instrument_name)?;
+        match &instrument.instrument_type {
+            InstrumentType::Perpetual { quote, .. } | InstrumentType::Future { quote, .. } | InstrumentType::Option { quote, .. }
+                if quote.as_ref() == QUOTE_CURRENCY => Ok(instrument),
+            _ => Err(InstrumentError::unrecognized(Exchange::Thalex, instrument_name)),
+        }
+    }
+
+    fn try_denormalize(&self, instrument: &Instrument) -> InstrumentResult<String> {
+        // Check if this is the right exchange handler
+        if instrument.exchange != Exchange::Thalex {
+            return Err(InstrumentError::ExchangeMismatch { expected: Exchange::Thalex, found: instrument.exchange });
+        }
+
+        // Every product is listed in USD only
+        match &instrument.instrument_type {
+            InstrumentType::Perpetual { base, quote } if quote.as_ref() == QUOTE_CURRENCY => {
+                Ok(format!("{}-{}", base.as_ref(), PERPETUAL_SUFFIX))
+            },
+
+            InstrumentType::Future { base, quote, expiry } if quote.as_ref() == QUOTE_CURRENCY => {
+                Ok(format!("{}-{}", base.as_ref(), denormalize_expiry(expiry, DEFAULT_EXPIRY_FORMAT)))
+            },
+
+            InstrumentType::Option { base, quote, expiry, strike, kind } if quote.as_ref() == QUOTE_CURRENCY => {
+                Ok(format!("{}-{}-{}-{}",
+                    base.as_ref(),
+                    denormalize_expiry(expiry, DEFAULT_EXPIRY_FORMAT),
+                    strike.format_with_separator(STRIKE_DECIMAL_SEPARATOR),
+                    kind))
+            },
+
+            InstrumentType::Combo { kind: ComboKind::FutureSpread, legs } if instrument.instrument_type.quote() == QUOTE_CURRENCY => {
+                match ComboKind::FutureSpread.decompose_legs(legs).map(|(expiries, _)| expiries).as_deref() {
+                    Some([Some(front), Some(back)]) => Ok(format!("{}-{}-{}",
+                        instrument.instrument_type.base(),
+                        denormalize_expiry(front, DEFAULT_EXPIRY_FORMAT),
+                        denormalize_expiry(back, DEFAULT_EXPIRY_FORMAT))),
+                    // Rolls are only listed between two futures
+                    _ => Err(InstrumentError::unsupported_instrument_type(Exchange::Thalex, &instrument.instrument_type)),
+                }
+            },
+
+            _ => Err(InstrumentError::unsupported_instrument_type(Exchange::Thalex, &instrument.instrument_type)),
+        }
+    }
+
+    fn supports_instrument_type(&self, instrument_type: &InstrumentType) -> bool {
+        matches!(instrument_type,
+            InstrumentType::Perpetual { .. } | InstrumentType::Future { .. } | InstrumentType::Option { .. }
+            | InstrumentType::Combo { kind: ComboKind::FutureSpread, .. })
+    }
+
+    fn settlement_time(&self) -> NaiveTime {
+        SETTLEMENT_TIME
+    }
+
+    fn contract_spec(&self, _instrument: &Instrument) -> InstrumentResult<ContractSpec> {
+        // Contracts are linear, one unit of the base each
+        Ok(ContractSpec::linear(Currency::new(Cow::Borrowed(QUOTE_CURRENCY)), Decimal::from(1)))
+    }
+}
+
+#[cfg(test)]
+mod thalex_normalize_tests {
+    use std::borrow::Cow;
+
+    use chrono::NaiveDate;
+
+    use super::{SETTLEMENT_TIME, THALEX_HANDLER};
+    use crate::{ComboKind, ComboLeg, ContractStyle, Currency, Exchange, ExchangeHandler, Expiry, Instrument, InstrumentError, InstrumentType, MarketType, OptionKind, Strike};
+
+    fn future(day: u32, month: u32) -> InstrumentType {
+        InstrumentType::Future {
+            base: Currency::new(Cow::Borrowed("BTC")),
+            quote: Currency::new(Cow::Borrowed("USD")),
+            expiry: Expiry::new(NaiveDate::from_ymd_opt(2025, month, day).unwrap(), SETTLEMENT_TIME),
+        }
+    }
+
+    #[test]
+    fn test_normalize_deribit_style() {
+        let perpetual = THALEX_HANDLER.normalize(MarketType::OrderBook, "BTC-PERPETUAL").unwrap();
+        assert_eq!(perpetual, Instrument::new(
+            Exchange::Thalex,
+            MarketType::OrderBook,
+            InstrumentType::Perpetual {
+                base: Currency::new(Cow::Borrowed("BTC")),
+                quote: Currency::new(Cow::Borrowed("USD")),
+            }));
+        assert_eq!(perpetual.contract_spec().unwrap().style, ContractStyle::Linear);
+
+        assert_eq!(THALEX_HANDLER.normalize(MarketType::OrderBook, "BTC-28MAR25").unwrap().instrument_type, future(28, 3));
+        assert_eq!(THALEX_HANDLER.normalize(MarketType::OrderBook, "BTC-28MAR25-100000-C").unwrap().instrument_type, InstrumentType::Option {
+            base: Currency::new(Cow::Borrowed("BTC")),
+            quote: Currency::new(Cow::Borrowed("USD")),
+            expiry: Expiry::new(NaiveDate::from_ymd_opt(2025, 3, 28).unwrap(), SETTLEMENT_TIME),
+            strike: Strike::from(100000),
+            kind: OptionKind::Call,
+        });
+    }
+
+    #[test]
+    fn test_normalize_roll() {
+        let instrument = THALEX_HANDLER.normalize(MarketType::OrderBook, "BTC-28MAR25-27JUN25").unwrap();
+        assert_eq!(instrument.instrument_type, InstrumentType::Combo {
+            kind: ComboKind::FutureSpread,
+            legs: vec![
+                ComboLeg { instrument_type: future(28, 3), ratio: 1 },
+                ComboLeg { instrument_type: future(27, 6), ratio: -1 },
+            ],
+        });
+        assert_eq!(THALEX_HANDLER.denormalize(&instrument), Some(String::from("BTC-28MAR25-27JUN25")));
+    }
+
+    #[test]
+    fn test_normalize_deribit_only() {
+        assert!(matches!(
+            THALEX_HANDLER.try_normalize(MarketType::OrderBook, "BTC-DVOL"),
+            Err(InstrumentError::UnrecognizedInstrument { exchange: Exchange::Thalex, .. })
+        ));
+        assert_eq!(THALEX_HANDLER.normalize(MarketType::OrderBook, "SOL_USDC-PERPETUAL"), None);
+        assert_eq!(THALEX_HANDLER.normalize(MarketType::OrderBook, "BTC-FS-28MAR25_PERP"), None);
+    }
+}
+
+#[cfg(test)]
+mod thalex_denormalize_tests {
+    use std::borrow::Cow;
+
+    use chrono::NaiveDate;
+
+    use super::{SETTLEMENT_TIME, THALEX_HANDLER};
+    use crate::{ComboKind, Currency, Exchange, ExchangeHandler, Expiry, Instrument, InstrumentError, InstrumentType, MarketType, OptionKind, Strike};
+
+    #[test]
+    fn test_denorm_fractional_strike() {
+        let instrument = Instrument::new(
+            Exchange::Thalex,
+            MarketType::OrderBook,
+            InstrumentType::Option {
+                base: Currency::new(Cow::Borrowed("BTC")),
+                quote: Currency::new(Cow::Borrowed("USD")),
+                expiry: Expiry::new(NaiveDate::from_ymd_opt(2025, 3, 28).unwrap(), SETTLEMENT_TIME),
+                strike: Strike::new(25, 1).unwrap(),
+                kind: OptionKind::Call,
+            });
+        assert_eq!(THALEX_HANDLER.denormalize(&instrument), Some(String::from("BTC-28MAR25-2d5-C")));
+        assert_eq!(THALEX_HANDLER.normalize(MarketType::OrderBook, "BTC-28MAR25-2d5-C"), Some(instrument));
+    }
+
+    #[test]
+    fn test_denorm_unlisted_perpetual_quote() {
+        let instrument = Instrument::new(
+            Exchange::Thalex,
+            MarketType::OrderBook,
+            InstrumentType::Perpetual {
+                base: Currency::new(Cow::Borrowed("BTC")),
+                quote: Currency::new(Cow::Borrowed("USDC")),
+            });
+        assert!(matches!(
+            THALEX_HANDLER.try_denormalize(&instrument),
+            Err(InstrumentError::UnsupportedInstrumentType { exchange: Exchange::Thalex, .. })
+        ));
+    }
+
+    #[test]
+    fn test_denorm_perpetual_roll() {
+        let legs = ComboKind::FutureSpread.build_legs(
+            &Currency::new(Cow::Borrowed("BTC")),
+            &Currency::new(Cow::Borrowed("USD")),
+            &[Some(Expiry::new(NaiveDate::from_ymd_opt(2025, 3, 28).unwrap(), SETTLEMENT_TIME)), None],
+            &[]).unwrap();
+        let instrument = Instrument::new(
+            Exchange::Thalex,
+            MarketType::OrderBook,
+            InstrumentType::Combo { kind: ComboKind::FutureSpread, legs });
+        assert_eq!(THALEX_HANDLER.denormalize(&instrument), None);
+    }
+}
@@ -1,15 +1,35 @@
-use chrono::{DateTime, NaiveDate, NaiveTime, TimeZone, Utc};
+use chrono::{DateTime, Datelike, Days, NaiveDate, NaiveTime, TimeZone, Utc, Weekday};
 use tracing::error;
 use std::borrow::Cow;
+use std::cell::Cell;
 use std::fmt::{self, Display, Formatter};
+use std::hash::{Hash, Hasher};
 use thiserror::Error;
 
 /// Module containing exchange-related definitions
 pub mod exchange;
+/// Runtime registry of exchange handlers
+pub mod registry;
+/// Exchange detection from raw instrument names
+pub mod detect;
+/// Serde support for the instrument model, enabled by the `serde` feature
+#[cfg(feature = "serde")]
+pub mod serde;
 
 /// Standard date format for expiry parsing
 const STANDARD_DATE_FORMAT: &str = "%Y%m%d";
+/// Decimal separator used for strikes in the standard format.
+/// A '.' would collide with the field separator, so fractional strikes are written as e.g. `2d5`
+const STANDARD_DECIMAL_SEPARATOR: char = 'd';
+/// Largest number of fractional digits a `Decimal` can hold
+const MAX_DECIMAL_SCALE: u32 = 18;
+/// Settlement time of day (UTC) used when the exchange is not known
+pub const DEFAULT_SETTLEMENT_TIME: NaiveTime = NaiveTime::from_hms_opt(8, 0, 0).unwrap();
 const LOG_CTX: &str = "normify#lib";
+/// Futures month codes, January (F) to December (Z)
+const FUTURES_MONTH_CODES: [char; 12] = ['F', 'G', 'H', 'J', 'K', 'M', 'N', 'Q', 'U', 'V', 'X', 'Z'];
+/// Length of a month code expiry suffix (e.g., H25)
+const MONTH_CODE_LEN: usize = 3;
 
 /// Error types for instrument operations
 #[derive(Error, Debug)]
@@ -25,47 +45,141 @@ pub enum InstrumentError {
     
     #[error("Parsing error: {0}")]
     ParseError(String),
+    
+    #[error("{exchange}: invalid base currency '{value}'")]
+    InvalidBase { exchange: Exchange, value: String },
+    
+    #[error("{exchange}: invalid expiry '{value}'")]
+    InvalidExpiry { exchange: Exchange, value: String },
+    
+    #[error("{exchange}: invalid strike '{value}'")]
+    InvalidStrike { exchange: Exchange, value: String },
+    
+    #[error("{exchange}: invalid option kind '{value}'")]
+    InvalidOptionKind { exchange: Exchange, value: String },
+    
+    #[error("{exchange}: unsupported market type '{market_type:?}'")]
+    UnsupportedMarketType { exchange: Exchange, market_type: MarketType },
+    
+    #[error("{exchange}: unsupported instrument type '{value}'")]
+    UnsupportedInstrumentType { exchange: Exchange, value: String },
+    
+    #[error("{exchange}: unsupported {style} contract '{value}'")]
+    UnsupportedContractStyle { exchange: Exchange, style: ContractStyle, value: String },
+    
+    #[error("{exchange}: unknown contract size of '{value}'")]
+    UnknownContractSize { exchange: Exchange, value: String },
+    
+    #[error("{exchange}: unrecognized instrument '{value}'")]
+    UnrecognizedInstrument { exchange: Exchange, value: String },
+    
+    #[error("{exchange}: ambiguous instrument '{value}', a sub-market is required")]
+    AmbiguousInstrument { exchange: Exchange, value: String },
+    
+    #[error("{expected} handler cannot process {found} instruments")]
+    ExchangeMismatch { expected: Exchange, found: Exchange },
+    
+    #[error("Invalid exchange name: '{0}'")]
+    InvalidExchangeName(String),
+    
+    #[error("Exchange already registered: '{0}'")]
+    ExchangeAlreadyRegistered(String),
+    
+    #[error("Handler implements neither {0} nor try_{0}")]
+    Unimplemented(&'static str),
+}
+
+impl InstrumentError {
+    pub(crate) fn invalid_base(exchange: Exchange, value: &str) -> Self {
+        InstrumentError::InvalidBase { exchange, value: value.to_string() }
+    }
+
+    pub(crate) fn invalid_expiry(exchange: Exchange, value: &str) -> Self {
+        InstrumentError::InvalidExpiry { exchange, value: value.to_string() }
+    }
+
+    pub(crate) fn invalid_strike(exchange: Exchange, value: &str) -> Self {
+        InstrumentError::InvalidStrike { exchange, value: value.to_string() }
+    }
+
+    pub(crate) fn invalid_option_kind(exchange: Exchange, value: &str) -> Self {
+        InstrumentError::InvalidOptionKind { exchange, value: value.to_string() }
+    }
+
+    pub(crate) fn unsupported_instrument_type(exchange: Exchange, instrument_type: &InstrumentType) -> Self {
+        InstrumentError::UnsupportedInstrumentType { exchange, value: instrument_type.to_string() }
+    }
+
+    pub(crate) fn unknown_contract_size(exchange: Exchange, instrument_type: &InstrumentType) -> Self {
+        InstrumentError::UnknownContractSize { exchange, value: instrument_type.to_string() }
+    }
+
+    pub(crate) fn unrecognized(exchange: Exchange, value: &str) -> Self {
+        InstrumentError::UnrecognizedInstrument { exchange, value: value.to_string() }
+    }
+
+    /// Exchange the error was raised for, if it came from an exchange handler
+    pub fn exchange(&self) -> Option<Exchange> {
+        match self {
+            InstrumentError::InvalidBase { exchange, .. }
+            | InstrumentError::InvalidExpiry { exchange, .. }
+            | InstrumentError::InvalidStrike { exchange, .. }
+            | InstrumentError::InvalidOptionKind { exchange, .. }
+            | InstrumentError::UnsupportedMarketType { exchange, .. }
+            | InstrumentError::UnsupportedInstrumentType { exchange, .. }
+            | InstrumentError::UnsupportedContractStyle { exchange, .. }
+            | InstrumentError::UnknownContractSize { exchange, .. }
+            | InstrumentError::UnrecognizedInstrument { exchange, .. }
+            | InstrumentError::AmbiguousInstrument { exchange, .. } => Some(*exchange),
+            InstrumentError::ExchangeMismatch { expected, .. } => Some(*expected),
+            _ => None,
+        }
+    }
+}
+
+/// Validates a base currency symbol taken from an exchange instrument name
+pub(crate) fn parse_base(exchange: Exchange, value: &str) -> InstrumentResult<Currency> {
+    if value.is_empty() || !value.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Err(InstrumentError::invalid_base(exchange, value));
+    }
+    Ok(Currency::new(Cow::Owned(value.to_string())))
 }
 
 /// Result type for instrument operations
 pub type InstrumentResult<T> = Result<T, InstrumentError>;
 
 /// Parse a standard format string into an Instrument
-/// Standard instrument format: <market-type>.<instrument-kind>.<instrument-name>.<exchange>
-/// Example: o.p.BTC-USD.deribit
+/// Standard instrument format: <market-type>.<instrument-kind>.<instrument-name>.<exchange>[.<contract-style>]
+/// The contract style is only written when it differs from the exchange's usual style for the instrument
+/// Example: o.p.BTC-USD.deribit, o.p.BTC-USD.kraken_futures.inverse
 pub fn parse_standard_format(instrument_str: &str) -> InstrumentResult<Instrument> {
     let parts: Vec<&str> = instrument_str.split('.').collect();
     
-    match parts.as_slice() {
+    let (parts, contract_style) = match parts.as_slice() {
+        [_, _, _, _, contract_style] => {
+            let contract_style = ContractStyle::try_from(*contract_style)
+                .map_err(InstrumentError::ParseError)?;
+            (&parts[..4], Some(contract_style))
+        },
+        _ => (parts.as_slice(), None),
+    };
+    
+    match parts {
         [market_type, instrument_kind, instrument_name, exchange] => {
             // Parse exchange and market type once
             let exchange = Exchange::try_from(*exchange)
-                .map_err(|e| InstrumentError::ParseError(e))?;
+                .map_err(InstrumentError::ParseError)?;
             
             let market_type = MarketType::try_from(*market_type)
                 .map_err(|e| InstrumentError::ParseError(e.to_string()))?;
             
             // Parse instrument type
-            let instrument_type = InstrumentType::from_str(instrument_kind, instrument_name)
+            let instrument_type = InstrumentType::from_parts(instrument_kind, instrument_name)
                 .ok_or_else(|| InstrumentError::InvalidFormat(
                     format!("Invalid instrument format: {instrument_kind}.{instrument_name}")
                 ))?;
             
-            let instrument = Instrument {
-                exchange,
-                market_type,
-                instrument_type,
-            };
-            
-            // Validate by attempting to denormalize
-            let handler = exchange.handler();
-            if handler.denormalize(&instrument).is_some() {
-                Ok(instrument)
-            } else {
-                Err(InstrumentError::UnsupportedByExchange(
-                    format!("Instrument not supported by {}", exchange)
-                ))
-            }
+            Instrument::validated(exchange, market_type, instrument_type, contract_style)
         },
         _ => Err(InstrumentError::InvalidFormat(
             format!("Invalid instrument format: {}", instrument_str)
@@ -91,18 +205,24 @@ pub enum Exchange {
     Dydx,
     Derive,
     Paradex,
-    Aevo
+    Aevo,
+    Binance,
+    Okx,
+    Bybit,
+    Hyperliquid,
+    KrakenFutures,
+    Bitmex,
+    Coinbase,
+    Thalex,
+    Delta,
+    Kucoin,
+    /// Exchange registered at runtime through `registry::register_exchange`
+    Custom(registry::CustomExchange),
 }
 
 impl Display for Exchange {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        f.write_str(match self {
-            Exchange::Deribit => "deribit",
-            Exchange::Dydx => "dydx",
-            Exchange::Derive => "derive",
-            Exchange::Paradex => "paradex",
-            Exchange::Aevo => "aevo",
-        })
+        f.write_str(self.name())
     }
 }
 
@@ -117,14 +237,70 @@ impl TryFrom<&str> for Exchange {
             s if s.eq_ignore_ascii_case("derive") => Ok(Exchange::Derive),
             s if s.eq_ignore_ascii_case("paradex") => Ok(Exchange::Paradex),
             s if s.eq_ignore_ascii_case("aevo") => Ok(Exchange::Aevo),
-            _ => Err(format!("Invalid exchange name: {}", value)),
+            s if s.eq_ignore_ascii_case("binance") => Ok(Exchange::Binance),
+            s if s.eq_ignore_ascii_case("okx") => Ok(Exchange::Okx),
+            s if s.eq_ignore_ascii_case("bybit") => Ok(Exchange::Bybit),
+            s if s.eq_ignore_ascii_case("hyperliquid") => Ok(Exchange::Hyperliquid),
+            s if s.eq_ignore_ascii_case("kraken_futures") => Ok(Exchange::KrakenFutures),
+            s if s.eq_ignore_ascii_case("bitmex") => Ok(Exchange::Bitmex),
+            s if s.eq_ignore_ascii_case("coinbase") => Ok(Exchange::Coinbase),
+            s if s.eq_ignore_ascii_case("thalex") => Ok(Exchange::Thalex),
+            s if s.eq_ignore_ascii_case("delta") => Ok(Exchange::Delta),
+            s if s.eq_ignore_ascii_case("kucoin") => Ok(Exchange::Kucoin),
+            s => registry::lookup(s).ok_or_else(|| format!("Invalid exchange name: {}", value)),
         }
     }
 }
 
 impl Exchange {
-    /// Returns the appropriate exchange handler
+    /// Exchanges shipped with the crate, registered before any custom handler
+    pub const BUILTIN: [Exchange; 15] = [
+        Exchange::Deribit,
+        Exchange::Dydx,
+        Exchange::Derive,
+        Exchange::Paradex,
+        Exchange::Aevo,
+        Exchange::Binance,
+        Exchange::Okx,
+        Exchange::Bybit,
+        Exchange::Hyperliquid,
+        Exchange::KrakenFutures,
+        Exchange::Bitmex,
+        Exchange::Coinbase,
+        Exchange::Thalex,
+        Exchange::Delta,
+        Exchange::Kucoin,
+    ];
+
+    /// Name of the exchange as written in the standard format
+    pub fn name(&self) -> &'static str {
+        match self {
+            Exchange::Deribit => "deribit",
+            Exchange::Dydx => "dydx",
+            Exchange::Derive => "derive",
+            Exchange::Paradex => "paradex",
+            Exchange::Aevo => "aevo",
+            Exchange::Binance => "binance",
+            Exchange::Okx => "okx",
+            Exchange::Bybit => "bybit",
+            Exchange::Hyperliquid => "hyperliquid",
+            Exchange::KrakenFutures => "kraken_futures",
+            Exchange::Bitmex => "bitmex",
+            Exchange::Coinbase => "coinbase",
+            Exchange::Thalex => "thalex",
+            Exchange::Delta => "delta",
+            Exchange::Kucoin => "kucoin",
+            Exchange::Custom(custom) => custom.name(),
+        }
+    }
+
+    /// Returns the handler for this exchange, which is the one registered through
+    /// `registry::register_handler` if a built-in exchange was taken over
     pub fn handler(&self) -> &'static dyn ExchangeHandler {
+        if let Some(handler) = registry::builtin_handler(*self) {
+            return handler;
+        }
+
         // Static handlers avoid Box allocation
         match self {
             Exchange::Deribit => &exchange::deribit::DERIBIT_HANDLER,
@@ -132,6 +308,18 @@ impl Exchange {
             Exchange::Derive => &exchange::derive::DERIVE_HANDLER,
             Exchange::Paradex => &exchange::paradex::PARADEX_HANDLER,
             Exchange::Aevo => &exchange::aevo::AEVO_HANDLER,
+            Exchange::Binance => &exchange::binance::BINANCE_HANDLER,
+            Exchange::Okx => &exchange::okx::OKX_HANDLER,
+            Exchange::Bybit => &exchange::bybit::BYBIT_HANDLER,
+            Exchange::Hyperliquid => &exchange::hyperliquid::HYPERLIQUID_HANDLER,
+            Exchange::KrakenFutures => &exchange::kraken_futures::KRAKEN_FUTURES_HANDLER,
+            Exchange::Bitmex => &exchange::bitmex::BITMEX_HANDLER,
+            Exchange::Coinbase => &exchange::coinbase::COINBASE_HANDLER,
+            Exchange::Thalex => &exchange::thalex::THALEX_HANDLER,
+            Exchange::Delta => &exchange::delta::DELTA_HANDLER,
+            Exchange::Kucoin => &exchange::kucoin::KUCOIN_HANDLER,
+            // Only custom exchanges go through the runtime registry
+            Exchange::Custom(custom) => custom.handler(),
         }
     }
 }
@@ -176,6 +364,14 @@ impl TryFrom<&str> for MarketType {
     }
 }
 
+impl std::str::FromStr for MarketType {
+    type Err = InstrumentError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        MarketType::try_from(s).map_err(|e| InstrumentError::ParseError(e.to_string()))
+    }
+}
+
 /// Represents different instrument types with their specificities
 #[derive(Debug, PartialEq, Clone, Eq, Hash)]
 pub enum InstrumentType {
@@ -183,15 +379,23 @@ pub enum InstrumentType {
     Future {
         base: Currency,
         quote: Currency,
-        expiry: Cow<'static, str>,
+        expiry: Expiry,
     },
     
     /// Options contract: BASE-QUOTE-EXPIRY-STRIKE-OPTIONKIND (e.g., BTC-USD-20250528-19000-C)
     Option {
         base: Currency,
         quote: Currency,
-        expiry: Cow<'static, str>,
-        strike: u64,
+        expiry: Expiry,
+        strike: Strike,
+        kind: OptionKind,
+    },
+    
+    /// Perpetual option, with a strike and kind but no expiry: BASE-QUOTE-STRIKE-OPTIONKIND (e.g., BTC-USD-100000-C)
+    PerpetualOption {
+        base: Currency,
+        quote: Currency,
+        strike: Strike,
         kind: OptionKind,
     },
     
@@ -206,39 +410,67 @@ pub enum InstrumentType {
         base: Currency,
         quote: Currency,
     },
+    
+    /// Price, mark or volatility index: BASE-QUOTE-INDEXKIND (e.g., BTC-USD-VOL)
+    Index {
+        base: Currency,
+        quote: Currency,
+        kind: IndexKind,
+    },
+    
+    /// MOVE contract, paying the absolute move of the underlying away from the strike at expiry
+    /// (a straddle): BASE-QUOTE-EXPIRY-STRIKE (e.g., BTC-USD-20250328-100000)
+    Move {
+        base: Currency,
+        quote: Currency,
+        expiry: Expiry,
+        strike: Strike,
+    },
+    
+    /// Multi-leg combo: CODE_LEG_LEG... where each leg is RATIO KIND:NAME
+    /// (e.g., CS_+1o:BTC-USD-20250328-90000-C_-1o:BTC-USD-20250328-100000-C)
+    Combo {
+        kind: ComboKind,
+        legs: Vec<ComboLeg>,
+    },
 }
 
 impl Display for InstrumentType {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        match self {
-            InstrumentType::Future { base, quote, expiry } => 
-                write!(f, "f.{}-{}-{}", base.as_ref(), quote.as_ref(), expiry),
-                
-            InstrumentType::Option { base, quote, expiry, strike, kind } => {
-                match parse_expiry_date(expiry, STANDARD_DATE_FORMAT) {
-                    Some(date) => write!(f, "o.{}-{}-{}-{}-{}", 
-                                        base.as_ref(), quote.as_ref(), 
-                                        format_expiry_date(date, STANDARD_DATE_FORMAT), 
-                                        strike, kind),
-                    None => Err(fmt::Error),
-                }
-            },
-            
-            InstrumentType::Spot { base, quote } => 
-                write!(f, "s.{}-{}", base.as_ref(), quote.as_ref()),
-                
-            InstrumentType::Perpetual { base, quote } => 
-                write!(f, "p.{}-{}", base.as_ref(), quote.as_ref()),
-        }
+        write!(f, "{}.", self.kind_code())?;
+        self.fmt_name(f)
+    }
+}
+
+impl std::str::FromStr for InstrumentType {
+    type Err = InstrumentError;
+
+    /// Parses the `<instrument-kind>.<instrument-name>` part of the standard format (e.g., p.BTC-USD)
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split_once('.')
+            .and_then(|(kind, name)| InstrumentType::from_parts(kind, name))
+            .ok_or_else(|| InstrumentError::InvalidFormat(format!("Invalid instrument format: {}", s)))
     }
 }
 
 impl InstrumentType {
     /// Create an InstrumentType from string components
+    ///
+    /// Named apart from `FromStr::from_str`, which an inherent `from_str` would shadow.
+    #[deprecated(note = "use `InstrumentType::from_parts` or `str::parse`")]
+    pub fn from_kind_and_name(kind: &str, instrument_name: &str) -> Option<Self> {
+        Self::from_parts(kind, instrument_name)
+    }
+
+    /// Create an InstrumentType from string components
+    /// 
+    /// Expiries are given the `DEFAULT_SETTLEMENT_TIME`; use `with_settlement_time`
+    /// to apply an exchange's own settlement time. Expiries compare by date, so the
+    /// result equals the instrument type an exchange's handler normalizes either way.
     /// 
     /// * `kind` - The kind of instrument (e.g., "future", "option", "spot")
     /// * `instrument_name` - The full name of the instrument (e.g., "BTC-USD-202306")
-    pub fn from_str(kind: &str, instrument_name: &str) -> Option<Self> {
+    pub fn from_parts(kind: &str, instrument_name: &str) -> Option<Self> {
         // Split the instrument name once
         let parts: Vec<&str> = instrument_name.split('-').collect();
         
@@ -248,12 +480,15 @@ impl InstrumentType {
                 // Parse option details
                 if let [base, quote, expiry, strike, option_kind] = parts.as_slice() {
                     let option_kind = OptionKind::try_from(*option_kind).ok()?;
-                    let strike = strike.parse::<u64>().ok()?;
+                    let strike = Strike::parse_with_separator(strike, STANDARD_DECIMAL_SEPARATOR)
+                        .or_else(|| strike.parse::<Strike>().ok())?;
+                    
+                    let expiry = Expiry::parse(expiry, STANDARD_DATE_FORMAT, DEFAULT_SETTLEMENT_TIME)?;
                     
                     Some(InstrumentType::Option { 
-                        base: Currency::new(Cow::Owned(base.to_string())),
-                        quote: Currency::new(Cow::Owned(quote.to_string())),
-                        expiry: Cow::Owned(expiry.to_string()),
+                        base: base.parse().ok()?,
+                        quote: quote.parse().ok()?,
+                        expiry,
                         strike,
                         kind: option_kind,
                     })
@@ -262,12 +497,26 @@ impl InstrumentType {
                 }
             },
             
+            k if k.eq_ignore_ascii_case("po") || k.eq_ignore_ascii_case("perpetual_option") => {
+                if let [base, quote, strike, option_kind] = parts.as_slice() {
+                    Some(InstrumentType::PerpetualOption {
+                        base: base.parse().ok()?,
+                        quote: quote.parse().ok()?,
+                        strike: Strike::parse_with_separator(strike, STANDARD_DECIMAL_SEPARATOR)
+                            .or_else(|| strike.parse::<Strike>().ok())?,
+                        kind: OptionKind::try_from(*option_kind).ok()?,
+                    })
+                } else {
+                    None
+                }
+            },
+            
             k if k.eq_ignore_ascii_case("f") || k.eq_ignore_ascii_case("future") => {
                 if let [base, quote, expiry] = parts.as_slice() {
                     Some(InstrumentType::Future {
-                        base: Currency::new(Cow::Owned(base.to_string())),
-                        quote: Currency::new(Cow::Owned(quote.to_string())),
-                        expiry: Cow::Owned(expiry.to_string()),
+                        base: base.parse().ok()?,
+                        quote: quote.parse().ok()?,
+                        expiry: Expiry::parse(expiry, STANDARD_DATE_FORMAT, DEFAULT_SETTLEMENT_TIME)?,
                     })
                 } else {
                     None
@@ -277,8 +526,8 @@ impl InstrumentType {
             k if k.eq_ignore_ascii_case("p") || k.eq_ignore_ascii_case("perpetual") => {
                 if let [base, quote] = parts.as_slice() {
                     Some(InstrumentType::Perpetual {
-                        base: Currency::new(Cow::Owned(base.to_string())),
-                        quote: Currency::new(Cow::Owned(quote.to_string())),
+                        base: base.parse().ok()?,
+                        quote: quote.parse().ok()?,
                     })
                 } else {
                     None
@@ -288,25 +537,119 @@ impl InstrumentType {
             k if k.eq_ignore_ascii_case("s") || k.eq_ignore_ascii_case("spot") => {
                 if let [base, quote] = parts.as_slice() {
                     Some(InstrumentType::Spot {
-                        base: Currency::new(Cow::Owned(base.to_string())),
-                        quote: Currency::new(Cow::Owned(quote.to_string())),
+                        base: base.parse().ok()?,
+                        quote: quote.parse().ok()?,
                     })
                 } else {
                     None
                 }
             },
             
+            k if k.eq_ignore_ascii_case("i") || k.eq_ignore_ascii_case("index") => {
+                if let [base, quote, index_kind] = parts.as_slice() {
+                    Some(InstrumentType::Index {
+                        base: base.parse().ok()?,
+                        quote: quote.parse().ok()?,
+                        kind: IndexKind::try_from(*index_kind).ok()?,
+                    })
+                } else {
+                    None
+                }
+            },
+            
+            k if k.eq_ignore_ascii_case("m") || k.eq_ignore_ascii_case("move") => {
+                if let [base, quote, expiry, strike] = parts.as_slice() {
+                    Some(InstrumentType::Move {
+                        base: base.parse().ok()?,
+                        quote: quote.parse().ok()?,
+                        expiry: Expiry::parse(expiry, STANDARD_DATE_FORMAT, DEFAULT_SETTLEMENT_TIME)?,
+                        strike: Strike::parse_with_separator(strike, STANDARD_DECIMAL_SEPARATOR)
+                            .or_else(|| strike.parse::<Strike>().ok())?,
+                    })
+                } else {
+                    None
+                }
+            },
+            
+            k if k.eq_ignore_ascii_case("c") || k.eq_ignore_ascii_case("combo") => {
+                let mut sections = instrument_name.split('_');
+                let kind = ComboKind::try_from(sections.next()?).ok()?;
+                let legs = sections.map(ComboLeg::from_standard).collect::<Option<Vec<_>>>()?;
+                
+                if legs.is_empty() {
+                    return None;
+                }
+                Some(InstrumentType::Combo { kind, legs })
+            },
+            
             _ => None,
         }
     }
 
+    /// Kind letter used in the standard format
+    fn kind_code(&self) -> &'static str {
+        match self {
+            InstrumentType::Future { .. } => "f",
+            InstrumentType::Option { .. } => "o",
+            InstrumentType::PerpetualOption { .. } => "po",
+            InstrumentType::Spot { .. } => "s",
+            InstrumentType::Perpetual { .. } => "p",
+            InstrumentType::Index { .. } => "i",
+            InstrumentType::Move { .. } => "m",
+            InstrumentType::Combo { .. } => "c",
+        }
+    }
+
+    /// Writes the instrument name part of the standard format
+    fn fmt_name(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            InstrumentType::Future { base, quote, expiry } => 
+                write!(f, "{}-{}-{}", base.as_ref(), quote.as_ref(), expiry),
+                
+            InstrumentType::Option { base, quote, expiry, strike, kind } => 
+                write!(f, "{}-{}-{}-{}-{}", 
+                       base.as_ref(), quote.as_ref(), expiry,
+                       strike.format_with_separator(STANDARD_DECIMAL_SEPARATOR), kind),
+            
+            InstrumentType::PerpetualOption { base, quote, strike, kind } => 
+                write!(f, "{}-{}-{}-{}", 
+                       base.as_ref(), quote.as_ref(),
+                       strike.format_with_separator(STANDARD_DECIMAL_SEPARATOR), kind),
+            
+            InstrumentType::Spot { base, quote } |
+            InstrumentType::Perpetual { base, quote } => 
+                write!(f, "{}-{}", base.as_ref(), quote.as_ref()),
+            
+            InstrumentType::Index { base, quote, kind } => 
+                write!(f, "{}-{}-{}", base.as_ref(), quote.as_ref(), kind),
+            
+            InstrumentType::Move { base, quote, expiry, strike } => 
+                write!(f, "{}-{}-{}-{}", 
+                       base.as_ref(), quote.as_ref(), expiry,
+                       strike.format_with_separator(STANDARD_DECIMAL_SEPARATOR)),
+            
+            InstrumentType::Combo { kind, legs } => {
+                write!(f, "{}", kind)?;
+                for leg in legs {
+                    write!(f, "_{:+}{}:", leg.ratio, leg.instrument_type.kind_code())?;
+                    leg.instrument_type.fmt_name(f)?;
+                }
+                Ok(())
+            },
+        }
+    }
+
     /// Get base currency
     pub fn base(&self) -> &str {
         match self {
             InstrumentType::Future { base, .. } => base.as_ref(),
             InstrumentType::Option { base, .. } => base.as_ref(),
+            InstrumentType::PerpetualOption { base, .. } => base.as_ref(),
             InstrumentType::Spot { base, .. } => base.as_ref(),
             InstrumentType::Perpetual { base, .. } => base.as_ref(),
+            InstrumentType::Index { base, .. } => base.as_ref(),
+            InstrumentType::Move { base, .. } => base.as_ref(),
+            InstrumentType::Combo { legs, .. } => legs.first().map_or("", |leg| leg.instrument_type.base()),
         }
     }
 
@@ -315,8 +658,254 @@ impl InstrumentType {
         match self {
             InstrumentType::Future { quote, .. } => quote.as_ref(),
             InstrumentType::Option { quote, .. } => quote.as_ref(),
+            InstrumentType::PerpetualOption { quote, .. } => quote.as_ref(),
             InstrumentType::Spot { quote, .. } => quote.as_ref(),
             InstrumentType::Perpetual { quote, .. } => quote.as_ref(),
+            InstrumentType::Index { quote, .. } => quote.as_ref(),
+            InstrumentType::Move { quote, .. } => quote.as_ref(),
+            InstrumentType::Combo { legs, .. } => legs.first().map_or("", |leg| leg.instrument_type.quote()),
+        }
+    }
+
+    /// Get expiry, if the instrument has one (the earliest leg expiry for combos)
+    pub fn expiry(&self) -> Option<&Expiry> {
+        match self {
+            InstrumentType::Future { expiry, .. } | InstrumentType::Option { expiry, .. }
+            | InstrumentType::Move { expiry, .. } => Some(expiry),
+            InstrumentType::Combo { legs, .. } => legs.iter().filter_map(|leg| leg.instrument_type.expiry()).min(),
+            _ => None,
+        }
+    }
+
+    /// Returns the instrument with its expiry (if any) settling at the given time of day
+    pub fn with_settlement_time(self, settlement_time: NaiveTime) -> Self {
+        self.with_settlement_time_on(|_| settlement_time)
+    }
+
+    /// Returns the instrument with each expiry (if any) settling at the time of day
+    /// `settlement_time_on` gives for its date
+    pub(crate) fn with_settlement_time_on(mut self, settlement_time_on: impl Fn(NaiveDate) -> NaiveTime) -> Self {
+        self.set_settlement_time_on(&settlement_time_on);
+        self
+    }
+
+    fn set_settlement_time_on(&mut self, settlement_time_on: &dyn Fn(NaiveDate) -> NaiveTime) {
+        match self {
+            InstrumentType::Future { expiry, .. } | InstrumentType::Option { expiry, .. }
+            | InstrumentType::Move { expiry, .. } => {
+                *expiry = expiry.with_settlement_time(settlement_time_on(expiry.date()));
+            },
+            InstrumentType::Combo { legs, .. } => {
+                for leg in legs.iter_mut() {
+                    leg.instrument_type.set_settlement_time_on(settlement_time_on);
+                }
+            },
+            _ => {}
+        }
+    }
+}
+
+/// Kind of value an index instrument publishes
+#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash)]
+pub enum IndexKind {
+    /// Spot price index of the underlying
+    Price,
+    /// Mark price the exchange uses for margining
+    Mark,
+    /// Implied volatility index (e.g., Deribit DVOL)
+    Volatility,
+}
+
+impl Display for IndexKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            IndexKind::Price => "PRICE",
+            IndexKind::Mark => "MARK",
+            IndexKind::Volatility => "VOL",
+        })
+    }
+}
+
+impl TryFrom<&str> for IndexKind {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            s if s.eq_ignore_ascii_case("price") => Ok(IndexKind::Price),
+            s if s.eq_ignore_ascii_case("mark") => Ok(IndexKind::Mark),
+            s if s.eq_ignore_ascii_case("vol") || s.eq_ignore_ascii_case("volatility") => Ok(IndexKind::Volatility),
+            _ => Err(format!("Invalid index kind: {}", value)),
+        }
+    }
+}
+
+/// Strategy of a multi-leg combo instrument
+///
+/// Legs are listed in the order their expiries and strikes appear in the name.
+/// Spreads, calendars and diagonals buy the first leg and sell the second.
+#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash)]
+pub enum ComboKind {
+    FutureSpread,
+    CallSpread,
+    PutSpread,
+    CallCalendar,
+    PutCalendar,
+    CallDiagonal,
+    PutDiagonal,
+    Straddle,
+    Strangle,
+    RiskReversal,
+    CallButterfly,
+    PutButterfly,
+    IronCondor,
+    IronButterfly,
+}
+
+impl ComboKind {
+    /// Builds the legs of this strategy
+    ///
+    /// * `expiries` - Leg expiries in name order; `None` stands for a perpetual leg
+    /// * `strikes` - Distinct strikes in name order (empty for future spreads)
+    pub fn build_legs(&self, base: &Currency, quote: &Currency, expiries: &[Option<Expiry>], strikes: &[Strike]) -> Option<Vec<ComboLeg>> {
+        use OptionKind::{Call, Put};
+
+        let future = |expiry: &Option<Expiry>, ratio: i32| {
+            let instrument_type = match expiry {
+                Some(expiry) => InstrumentType::Future { base: base.clone(), quote: quote.clone(), expiry: *expiry },
+                None => InstrumentType::Perpetual { base: base.clone(), quote: quote.clone() },
+            };
+            ComboLeg { instrument_type, ratio }
+        };
+        let option = |expiry: &Expiry, strike: &Strike, kind: OptionKind, ratio: i32| ComboLeg {
+            instrument_type: InstrumentType::Option {
+                base: base.clone(),
+                quote: quote.clone(),
+                expiry: *expiry,
+                strike: *strike,
+                kind,
+            },
+            ratio,
+        };
+
+        let legs = match (self, expiries, strikes) {
+            (ComboKind::FutureSpread, [e1, e2], []) if e1 != e2 => vec![future(e1, 1), future(e2, -1)],
+            (ComboKind::CallSpread, [Some(e)], [k1, k2]) => vec![option(e, k1, Call, 1), option(e, k2, Call, -1)],
+            (ComboKind::PutSpread, [Some(e)], [k1, k2]) => vec![option(e, k1, Put, 1), option(e, k2, Put, -1)],
+            (ComboKind::CallCalendar, [Some(e1), Some(e2)], [k]) => vec![option(e1, k, Call, 1), option(e2, k, Call, -1)],
+            (ComboKind::PutCalendar, [Some(e1), Some(e2)], [k]) => vec![option(e1, k, Put, 1), option(e2, k, Put, -1)],
+            (ComboKind::CallDiagonal, [Some(e1), Some(e2)], [k1, k2]) => vec![option(e1, k1, Call, 1), option(e2, k2, Call, -1)],
+            (ComboKind::PutDiagonal, [Some(e1), Some(e2)], [k1, k2]) => vec![option(e1, k1, Put, 1), option(e2, k2, Put, -1)],
+            (ComboKind::Straddle, [Some(e)], [k]) => vec![option(e, k, Call, 1), option(e, k, Put, 1)],
+            (ComboKind::Strangle, [Some(e)], [k1, k2]) => vec![option(e, k1, Put, 1), option(e, k2, Call, 1)],
+            (ComboKind::RiskReversal, [Some(e)], [k1, k2]) => vec![option(e, k1, Put, -1), option(e, k2, Call, 1)],
+            (ComboKind::CallButterfly, [Some(e)], [k1, k2, k3]) => 
+                vec![option(e, k1, Call, 1), option(e, k2, Call, -2), option(e, k3, Call, 1)],
+            (ComboKind::PutButterfly, [Some(e)], [k1, k2, k3]) => 
+                vec![option(e, k1, Put, 1), option(e, k2, Put, -2), option(e, k3, Put, 1)],
+            (ComboKind::IronCondor, [Some(e)], [k1, k2, k3, k4]) => 
+                vec![option(e, k1, Put, 1), option(e, k2, Put, -1), option(e, k3, Call, -1), option(e, k4, Call, 1)],
+            (ComboKind::IronButterfly, [Some(e)], [k1, k2, k3]) => 
+                vec![option(e, k1, Put, 1), option(e, k2, Put, -1), option(e, k2, Call, -1), option(e, k3, Call, 1)],
+            _ => return None,
+        };
+        Some(legs)
+    }
+
+    /// Splits legs back into the expiries and strikes that `build_legs` would take,
+    /// returning None if the legs do not form this strategy
+    pub fn decompose_legs(&self, legs: &[ComboLeg]) -> Option<(Vec<Option<Expiry>>, Vec<Strike>)> {
+        let first = legs.first()?;
+        let (base, quote) = (Currency::new(first.instrument_type.base().to_string()), Currency::new(first.instrument_type.quote().to_string()));
+
+        let mut expiries: Vec<Option<Expiry>> = Vec::new();
+        let mut strikes: Vec<Strike> = Vec::new();
+        for leg in legs {
+            let (expiry, strike) = match &leg.instrument_type {
+                InstrumentType::Future { expiry, .. } => (Some(*expiry), None),
+                InstrumentType::Perpetual { .. } => (None, None),
+                InstrumentType::Option { expiry, strike, .. } => (Some(*expiry), Some(*strike)),
+                _ => return None,
+            };
+            if *self == ComboKind::FutureSpread || !expiries.contains(&expiry) {
+                expiries.push(expiry);
+            }
+            if let Some(strike) = strike.filter(|s| !strikes.contains(s)) {
+                strikes.push(strike);
+            }
+        }
+
+        // Only accept legs that rebuild to exactly the same combo
+        match self.build_legs(&base, &quote, &expiries, &strikes) {
+            Some(rebuilt) if rebuilt == legs => Some((expiries, strikes)),
+            _ => None,
+        }
+    }
+}
+
+impl Display for ComboKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ComboKind::FutureSpread => "FS",
+            ComboKind::CallSpread => "CS",
+            ComboKind::PutSpread => "PS",
+            ComboKind::CallCalendar => "CCAL",
+            ComboKind::PutCalendar => "PCAL",
+            ComboKind::CallDiagonal => "CDIAG",
+            ComboKind::PutDiagonal => "PDIAG",
+            ComboKind::Straddle => "STRD",
+            ComboKind::Strangle => "STRG",
+            ComboKind::RiskReversal => "RR",
+            ComboKind::CallButterfly => "CBUT",
+            ComboKind::PutButterfly => "PBUT",
+            ComboKind::IronCondor => "IC",
+            ComboKind::IronButterfly => "IB",
+        })
+    }
+}
+
+impl TryFrom<&str> for ComboKind {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            s if s.eq_ignore_ascii_case("fs") => Ok(ComboKind::FutureSpread),
+            s if s.eq_ignore_ascii_case("cs") => Ok(ComboKind::CallSpread),
+            s if s.eq_ignore_ascii_case("ps") => Ok(ComboKind::PutSpread),
+            s if s.eq_ignore_ascii_case("ccal") => Ok(ComboKind::CallCalendar),
+            s if s.eq_ignore_ascii_case("pcal") => Ok(ComboKind::PutCalendar),
+            s if s.eq_ignore_ascii_case("cdiag") => Ok(ComboKind::CallDiagonal),
+            s if s.eq_ignore_ascii_case("pdiag") => Ok(ComboKind::PutDiagonal),
+            s if s.eq_ignore_ascii_case("strd") => Ok(ComboKind::Straddle),
+            s if s.eq_ignore_ascii_case("strg") => Ok(ComboKind::Strangle),
+            s if s.eq_ignore_ascii_case("rr") => Ok(ComboKind::RiskReversal),
+            s if s.eq_ignore_ascii_case("cbut") => Ok(ComboKind::CallButterfly),
+            s if s.eq_ignore_ascii_case("pbut") => Ok(ComboKind::PutButterfly),
+            s if s.eq_ignore_ascii_case("ic") => Ok(ComboKind::IronCondor),
+            s if s.eq_ignore_ascii_case("ib") => Ok(ComboKind::IronButterfly),
+            _ => Err(format!("Invalid combo kind: {}", value)),
+        }
+    }
+}
+
+/// A single leg of a combo instrument
+#[derive(Debug, PartialEq, Clone, Eq, Hash)]
+pub struct ComboLeg {
+    pub instrument_type: InstrumentType,
+    /// Signed quantity of this leg per unit of combo (positive = long)
+    pub ratio: i32,
+}
+
+impl ComboLeg {
+    /// Parses a leg from its standard format encoding, e.g. `+1o:BTC-USD-20250328-90000-C`
+    fn from_standard(value: &str) -> Option<Self> {
+        let (head, name) = value.split_once(':')?;
+        let kind_start = head.find(|c: char| c.is_ascii_alphabetic())?;
+        let (ratio, kind) = head.split_at(kind_start);
+        let ratio = ratio.parse::<i32>().ok().filter(|r| *r != 0)?;
+
+        match InstrumentType::from_parts(kind, name)? {
+            InstrumentType::Combo { .. } => None,
+            instrument_type => Some(ComboLeg { instrument_type, ratio }),
         }
     }
 }
@@ -357,49 +946,257 @@ pub struct Instrument {
     pub exchange: Exchange,
     pub market_type: MarketType,
     pub instrument_type: InstrumentType,
+    /// Linear, inverse or quanto, as read from the exchange instrument name
+    contract_style: ContractStyle,
 }
 
 impl Instrument {
-    /// Creates a new `Instrument`
+    /// Creates a new `Instrument` with the exchange's usual contract style for its type
     pub fn new(exchange: Exchange, market_type: MarketType, instrument_type: InstrumentType) -> Self {
+        let contract_style = exchange.handler().contract_style(&instrument_type);
         Self {
             exchange,
             market_type,
             instrument_type,
+            contract_style,
         }
     }
 
-    pub fn is_expired(&self) -> bool {
+    /// Linear, inverse or quanto, as read from the exchange instrument name
+    pub fn contract_style(&self) -> ContractStyle {
+        self.contract_style
+    }
+
+    /// Returns the instrument with another contract style, for exchanges listing the same
+    /// instrument in several styles (e.g., Kraken Futures PF_XBTUSD and PI_XBTUSD)
+    /// Returns an error if the exchange does not list the instrument in that style
+    pub fn with_contract_style(self, contract_style: ContractStyle) -> InstrumentResult<Self> {
+        if !self.exchange.handler().supports_contract_style(&self.instrument_type, contract_style) {
+            return Err(InstrumentError::UnsupportedContractStyle {
+                exchange: self.exchange,
+                style: contract_style,
+                value: self.instrument_type.to_string(),
+            });
+        }
+        Ok(Self { contract_style, ..self })
+    }
+
+    /// Builds an instrument from already parsed components, applying the exchange's
+    /// settlement time and validating it by attempting to denormalize
+    pub(crate) fn validated(exchange: Exchange, market_type: MarketType, instrument_type: InstrumentType, contract_style: Option<ContractStyle>) -> InstrumentResult<Self> {
+        let handler = exchange.handler();
+        let mut instrument = Instrument::new(exchange, market_type, instrument_type.with_settlement_time_on(|date| handler.settlement_time_on(date)));
+
+        if let Some(contract_style) = contract_style {
+            instrument = instrument.with_contract_style(contract_style)?;
+        }
+
+        handler.try_denormalize(&instrument)?;
+        Ok(instrument)
+    }
+
+    /// Returns true if the contract style is the exchange's usual one for the instrument type,
+    /// in which case the standard format leaves it out
+    fn has_default_contract_style(&self) -> bool {
+        self.contract_style == self.exchange.handler().contract_style(&self.instrument_type)
+    }
+
+    /// Returns the contract specification (settlement currency, style and multiplier)
+    /// as defined by the instrument's exchange
+    pub fn contract_spec(&self) -> InstrumentResult<ContractSpec> {
+        self.exchange.handler().contract_spec(self)
+    }
+
+    /// Returns the legs of a combo as standalone instruments with their ratios,
+    /// or None if the instrument is not a combo
+    pub fn legs(&self) -> Option<Vec<(Instrument, i32)>> {
         match &self.instrument_type {
-            InstrumentType::Option {expiry,..} | InstrumentType::Future {expiry,..} => {
-                match is_date_expired(expiry) {
-                    Ok(expired) => expired,
-                    Err(err) => {
-                        error!(name: LOG_CTX, "{}", err);
-                        false
-                    }
-                }
-            },
-            _ => false
+            InstrumentType::Combo { legs, .. } => Some(
+                legs.iter()
+                    .map(|leg| {
+                        // Legs are listed in the combo's style
+                        let instrument = Instrument {
+                            contract_style: self.contract_style,
+                            ..Instrument::new(self.exchange, self.market_type.clone(), leg.instrument_type.clone())
+                        };
+                        (instrument, leg.ratio)
+                    })
+                    .collect()
+            ),
+            _ => None,
         }
     }
+
+    /// Returns true once the instrument's settlement instant has passed.
+    /// Instruments without an expiry never expire.
+    pub fn is_expired(&self) -> bool {
+        self.instrument_type.expiry().is_some_and(Expiry::is_expired)
+    }
 }
 
+/// Writes the standard format, which `parse_standard_format` (and `str::parse`) reads back
+/// into an equal `Instrument` for every instrument the exchange handler can denormalize
 impl Display for Instrument {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "{}.{}.{}", self.market_type, self.instrument_type, self.exchange)
+        write!(f, "{}.{}.{}", self.market_type, self.instrument_type, self.exchange)?;
+        if !self.has_default_contract_style() {
+            write!(f, ".{}", self.contract_style)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for Instrument {
+    type Err = InstrumentError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_standard_format(s)
+    }
+}
+
+/// How a contract's payoff is denominated relative to its settlement currency
+#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash)]
+pub enum ContractStyle {
+    /// Settled in the quote currency (e.g., BTC-USDC settled in USDC)
+    Linear,
+    /// Settled in the base currency (e.g., BTC-USD settled in BTC)
+    Inverse,
+    /// Settled in a third currency at a fixed conversion rate
+    Quanto,
+}
+
+impl Display for ContractStyle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ContractStyle::Linear => "linear",
+            ContractStyle::Inverse => "inverse",
+            ContractStyle::Quanto => "quanto",
+        })
+    }
+}
+
+impl TryFrom<&str> for ContractStyle {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.trim() {
+            s if s.eq_ignore_ascii_case("linear") => Ok(ContractStyle::Linear),
+            s if s.eq_ignore_ascii_case("inverse") => Ok(ContractStyle::Inverse),
+            s if s.eq_ignore_ascii_case("quanto") => Ok(ContractStyle::Quanto),
+            _ => Err(format!("Invalid contract style: {}", value)),
+        }
+    }
+}
+
+/// Contract specification of an instrument
+#[derive(Debug, PartialEq, Clone, Eq, Hash)]
+pub struct ContractSpec {
+    /// Currency profits and losses are paid in
+    pub settlement: Currency,
+    pub style: ContractStyle,
+    /// Units of the base currency one contract represents, or, for inverse futures
+    /// and perpetuals, the contract's face value in the quote currency
+    pub multiplier: Decimal,
+}
+
+impl ContractSpec {
+    /// Linear contract settled in `settlement`
+    pub fn linear(settlement: Currency, multiplier: Decimal) -> Self {
+        Self { settlement, style: ContractStyle::Linear, multiplier }
+    }
+
+    /// Inverse contract settled in `settlement`
+    pub fn inverse(settlement: Currency, multiplier: Decimal) -> Self {
+        Self { settlement, style: ContractStyle::Inverse, multiplier }
+    }
+
+    /// Quanto contract settled in `settlement`
+    pub fn quanto(settlement: Currency, multiplier: Decimal) -> Self {
+        Self { settlement, style: ContractStyle::Quanto, multiplier }
+    }
+}
+
+thread_local! {
+    /// Set while a default `try_normalize` or `try_denormalize` calls its Option-based
+    /// counterpart, to true once that call comes back into the default, which means the
+    /// handler implements neither
+    static OPTION_ADAPTER_RECURSED: Cell<Option<bool>> = const { Cell::new(None) };
+}
+
+/// Calls the Option-based method of a handler from the default Result-based one.
+/// Returns None if the handler implements neither, instead of recursing.
+fn call_option_method<T>(method: impl FnOnce() -> Option<T>) -> Option<Option<T>> {
+    /// Clears the flag even if the handler panics
+    struct Reset;
+
+    impl Drop for Reset {
+        fn drop(&mut self) {
+            OPTION_ADAPTER_RECURSED.with(|flag| flag.set(None));
+        }
+    }
+
+    if OPTION_ADAPTER_RECURSED.with(Cell::get).is_some() {
+        OPTION_ADAPTER_RECURSED.with(|flag| flag.set(Some(true)));
+        return None;
+    }
+
+    OPTION_ADAPTER_RECURSED.with(|flag| flag.set(Some(false)));
+    let _reset = Reset;
+    let result = method();
+    match OPTION_ADAPTER_RECURSED.with(Cell::get) {
+        Some(true) => None,
+        _ => Some(result),
     }
 }
 
 /// Trait for handling exchange-specific operations
+///
+/// Implement `try_normalize` and `try_denormalize`; `normalize` and `denormalize`
+/// default to them, logging the error and returning None.
+///
+/// Handlers written against the Option-based `normalize` and `denormalize` keep working,
+/// as the `try_` methods default to them. Implement at least one method of each pair: a
+/// handler implementing neither returns `InstrumentError::Unimplemented` rather than recursing.
+///
+/// Some built-in exchanges also have handlers configured at runtime with metadata the
+/// instrument name does not carry (e.g., `DydxMarketsHandler`). `Exchange::handler`, and so
+/// `parse_standard_format` and `Instrument::contract_spec`, keep resolving to the static
+/// handler unless the configured one is registered with `registry::register_handler`;
+/// otherwise call the configured handler directly.
 pub trait ExchangeHandler {
     /// Normalize an exchange-specific instrument name to our standard format
-    /// Returns None if the instrument is not valid for this exchange
-    fn normalize(&self, market_type: MarketType, instrument_name: &str) -> Option<Instrument>;
+    /// Returns an error naming the component that failed to parse
+    /// Defaults to `normalize`, which can only report that the name was not recognized
+    fn try_normalize(&self, market_type: MarketType, instrument_name: &str) -> InstrumentResult<Instrument> {
+        call_option_method(|| self.normalize(market_type, instrument_name))
+            .ok_or(InstrumentError::Unimplemented("normalize"))?
+            .ok_or_else(|| InstrumentError::InvalidFormat(format!("Unrecognized instrument: {}", instrument_name)))
+    }
+
+    /// Convert a standard instrument to an exchange-specific format
+    /// Returns an error if the instrument is not valid for this exchange
+    /// Defaults to `denormalize`, which can only report that the instrument is not supported
+    fn try_denormalize(&self, instrument: &Instrument) -> InstrumentResult<String> {
+        call_option_method(|| self.denormalize(instrument))
+            .ok_or(InstrumentError::Unimplemented("denormalize"))?
+            .ok_or_else(|| InstrumentError::UnsupportedByExchange(instrument.to_string()))
+    }
+
+    /// Normalize an exchange-specific instrument name to our standard format
+    /// Returns None (and logs the reason) if the instrument is not valid for this exchange
+    fn normalize(&self, market_type: MarketType, instrument_name: &str) -> Option<Instrument> {
+        self.try_normalize(market_type, instrument_name)
+            .map_err(|err| error!(name: LOG_CTX, "normalize::{}", err))
+            .ok()
+    }
 
     /// Convert a standard instrument to an exchange-specific format
-    /// Returns None if the instrument is not valid for this exchange
-    fn denormalize(&self, instrument: &Instrument) -> Option<String>;
+    /// Returns None (and logs the reason) if the instrument is not valid for this exchange
+    fn denormalize(&self, instrument: &Instrument) -> Option<String> {
+        self.try_denormalize(instrument)
+            .map_err(|err| error!(name: LOG_CTX, "denormalize::{}", err))
+            .ok()
+    }
 
     /// Check if market type is supported by this exchange
     fn supports_market_type(&self, market_type: &MarketType) -> bool {
@@ -412,34 +1209,64 @@ pub trait ExchangeHandler {
         let _ = instrument_type;
         true
     }
+
+    /// Time of day (UTC) at which this exchange settles expiring contracts
+    fn settlement_time(&self) -> NaiveTime {
+        DEFAULT_SETTLEMENT_TIME
+    }
+
+    /// Time of day (UTC) at which this exchange settles contracts expiring on `date`
+    /// Defaults to `settlement_time`; override for exchanges settling at a local time
+    /// that shifts with daylight saving
+    fn settlement_time_on(&self, date: NaiveDate) -> NaiveTime {
+        let _ = date;
+        self.settlement_time()
+    }
+
+    /// Contract style this exchange lists instruments of the given type in, unless their name says otherwise
+    /// Defaults to linear
+    fn contract_style(&self, instrument_type: &InstrumentType) -> ContractStyle {
+        let _ = instrument_type;
+        ContractStyle::Linear
+    }
+
+    /// Check if this exchange lists instruments of the given type in the given contract style
+    fn supports_contract_style(&self, instrument_type: &InstrumentType, contract_style: ContractStyle) -> bool {
+        contract_style == self.contract_style(instrument_type)
+    }
+
+    /// Contract specification of an instrument listed on this exchange
+    /// Defaults to a contract of one base unit, settled in the quote currency if linear and
+    /// in the base currency if inverse. Returns an error if the exchange does not publish
+    /// the specification of the instrument.
+    fn contract_spec(&self, instrument: &Instrument) -> InstrumentResult<ContractSpec> {
+        let instrument_type = &instrument.instrument_type;
+        match instrument.contract_style {
+            ContractStyle::Linear => Ok(ContractSpec::linear(Currency::new(instrument_type.quote().to_string()), Decimal::from(1))),
+            ContractStyle::Inverse => Ok(ContractSpec::inverse(Currency::new(instrument_type.base().to_string()), Decimal::from(1))),
+            // The settlement currency of a quanto contract is not part of its name
+            ContractStyle::Quanto => Err(InstrumentError::UnsupportedContractStyle {
+                exchange: instrument.exchange,
+                style: ContractStyle::Quanto,
+                value: instrument_type.to_string(),
+            }),
+        }
+    }
 }
 
-/// Date handling functions
+// Date handling functions
 
-/// Parses and normalizes an expiry date to standard format
-fn normalize_expiry(date_str: &str) -> Option<String> {
+/// Parses an exchange expiry date into an `Expiry` settling at `settlement_time`
+fn normalize_expiry(date_str: &str, settlement_time: NaiveTime) -> Option<Expiry> {
     // Use a single vector of formats to try, avoiding repetitive code
     let formats = ["%d%b%y", STANDARD_DATE_FORMAT, "%d%b%Y"];
     
-    for format in &formats {
-        if let Some(dt) = parse_expiry_date(date_str, format) {
-            return Some(format_expiry_date(dt, STANDARD_DATE_FORMAT));
-        }
-    }
-    
-    error!(name: LOG_CTX, "normalize_expiry: Failed to parse expiry date: {}", date_str);
-    None
+    formats.iter().find_map(|format| Expiry::parse(date_str, format, settlement_time))
 }
 
-/// Parse and normalize standard expiry format to desired format
-fn denormalize_expiry(date_str: &str, format: &str) -> String {
-    match parse_expiry_date(date_str, STANDARD_DATE_FORMAT) {
-        Some(utc) => format_expiry_date(utc, format).to_uppercase(),
-        None => {
-            error!(name: LOG_CTX, "denormalize_expiry: Failed to parse expiry date: {}", date_str);
-            String::new()
-        }
-    }
+/// Formats an expiry in an exchange's date format
+fn denormalize_expiry(expiry: &Expiry, format: &str) -> String {
+    format_expiry_date(expiry.settlement(), format).to_uppercase()
 }
 
 /// Parses an expiry date string to `DateTime<Utc>`
@@ -457,27 +1284,151 @@ fn format_expiry_date(date: DateTime<Utc>, format: &str) -> String {
     date.format(format).to_string()
 }
 
+/// Splits a month-coded symbol (e.g., XBTH25) into its non-empty contract and its month code
+fn split_month_code(symbol: &str) -> Option<(&str, &str)> {
+    let (contract, code) = symbol.split_at_checked(symbol.len().checked_sub(MONTH_CODE_LEN)?)?;
+    (!contract.is_empty()).then_some((contract, code))
+}
+
+/// Parses a futures month code with a two-digit year (e.g., H25 for March 2025) into
+/// an expiry on the last Friday of that month
+fn normalize_month_code_expiry(code: &str, settlement_time: NaiveTime) -> Option<Expiry> {
+    let mut chars = code.chars();
+    let month_code = chars.next()?.to_ascii_uppercase();
+    let year = chars.as_str();
+    if year.len() != 2 || !year.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let month = FUTURES_MONTH_CODES.iter().position(|c| *c == month_code)? as u32 + 1;
+    let year = 2000 + year.parse::<i32>().ok()?;
+    last_friday(year, month).map(|date| Expiry::new(date, settlement_time))
+}
+
+/// Formats an expiry as a futures month code (e.g., H25), provided it falls on
+/// the last Friday of its month
+fn denormalize_month_code_expiry(expiry: &Expiry) -> Option<String> {
+    let date = expiry.date();
+    if last_friday(date.year(), date.month()) != Some(date) {
+        return None;
+    }
+    Some(format!("{}{:02}", FUTURES_MONTH_CODES[date.month0() as usize], date.year() % 100))
+}
+
+/// Last Friday of a month, the usual expiry of quarterly futures
+fn last_friday(year: i32, month: u32) -> Option<NaiveDate> {
+    last_weekday(year, month, Weekday::Fri)
+}
+
+/// Last given weekday of a month
+fn last_weekday(year: i32, month: u32, weekday: Weekday) -> Option<NaiveDate> {
+    let last_day = NaiveDate::from_ymd_opt(year, month, 1)?
+        .checked_add_months(chrono::Months::new(1))?
+        .pred_opt()?;
+    let days_since = (last_day.weekday().num_days_from_monday() + 7 - weekday.num_days_from_monday()) % 7;
+    last_day.checked_sub_days(Days::new(days_since.into()))
+}
+
+/// Checks whether a standard format date (`%Y%m%d`) has passed its settlement,
+/// assuming the `DEFAULT_SETTLEMENT_TIME`
 pub fn is_date_expired(date_str: &str) -> Result<bool, String> {
-    // Parse the input date string
-    let naive_date = match NaiveDate::parse_from_str(date_str, STANDARD_DATE_FORMAT) {
-        Ok(d) => d,
-        Err(err) => return Err(format!("{}", err))
-    };
-    
-    // Create a time at 23:59:59.999 (end of day with millisecond precision)
-    let end_of_day_time = NaiveTime::from_hms_milli_opt(23, 59, 59, 999).unwrap();
-    
-    // Combine date and time
-    let naive_datetime = naive_date.and_time(end_of_day_time);
-    
-    // Convert to DateTime<Utc>
-    let expiration_date = Utc.from_utc_datetime(&naive_datetime);
-    
-    // Get current UTC time
-    let now = Utc::now();
+    let naive_date = NaiveDate::parse_from_str(date_str, STANDARD_DATE_FORMAT)
+        .map_err(|err| format!("{}", err))?;
     
-    // Compare: if now is after expiration date, then the date is expired
-    Ok(now > expiration_date)
+    Ok(Expiry::new(naive_date, DEFAULT_SETTLEMENT_TIME).is_expired())
+}
+
+/// Expiry of a dated contract: the expiry date plus the exchange's settlement time of day (UTC)
+///
+/// Expiries compare, order and hash by date alone. An exchange settles every contract of a
+/// date at the same time, so an expiry parsed without its exchange (e.g., by
+/// `InstrumentType::from_parts`, at the `DEFAULT_SETTLEMENT_TIME`) equals the one the
+/// exchange's handler normalizes.
+#[derive(Debug, Clone, Copy)]
+pub struct Expiry {
+    date: NaiveDate,
+    settlement_time: NaiveTime,
+}
+
+impl PartialEq for Expiry {
+    fn eq(&self, other: &Self) -> bool {
+        self.date == other.date
+    }
+}
+
+impl Eq for Expiry {}
+
+impl Hash for Expiry {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.date.hash(state);
+    }
+}
+
+impl PartialOrd for Expiry {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Expiry {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.date.cmp(&other.date)
+    }
+}
+
+impl Expiry {
+    /// Creates an expiry settling on `date` at `settlement_time` UTC
+    pub fn new(date: NaiveDate, settlement_time: NaiveTime) -> Self {
+        Self { date, settlement_time }
+    }
+
+    /// Parses a date in the given chrono format into an expiry settling at `settlement_time`
+    pub fn parse(date_str: &str, format: &str, settlement_time: NaiveTime) -> Option<Self> {
+        NaiveDate::parse_from_str(date_str, format)
+            .ok()
+            .map(|date| Self::new(date, settlement_time))
+    }
+
+    /// Expiry date
+    pub fn date(&self) -> NaiveDate {
+        self.date
+    }
+
+    /// Settlement time of day (UTC)
+    pub fn settlement_time(&self) -> NaiveTime {
+        self.settlement_time
+    }
+
+    /// Instant at which the contract settles
+    pub fn settlement(&self) -> DateTime<Utc> {
+        Utc.from_utc_datetime(&self.date.and_time(self.settlement_time))
+    }
+
+    /// Returns the same expiry date settling at a different time of day
+    pub fn with_settlement_time(self, settlement_time: NaiveTime) -> Self {
+        Self { settlement_time, ..self }
+    }
+
+    /// Returns true if the settlement instant is at or before `now`
+    pub fn is_expired_at(&self, now: DateTime<Utc>) -> bool {
+        now >= self.settlement()
+    }
+
+    /// Returns true if the settlement instant has passed
+    pub fn is_expired(&self) -> bool {
+        self.is_expired_at(Utc::now())
+    }
+
+    /// Formats the expiry date using the given chrono format
+    pub fn format(&self, format: &str) -> String {
+        self.date.format(format).to_string()
+    }
+}
+
+impl Display for Expiry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.date.format(STANDARD_DATE_FORMAT))
+    }
 }
 
 #[derive(Debug, PartialEq, Clone, Eq, Hash)]
@@ -512,11 +1463,145 @@ impl AsRef<str> for Currency {
     }
 }
 
+impl Display for Currency {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+impl std::str::FromStr for Currency {
+    type Err = InstrumentError;
+
+    /// Parses a currency symbol, rejecting empty symbols and standard format separators
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let symbol = s.trim();
+        if symbol.is_empty() || symbol.contains(['-', '.', '_', ':']) {
+            return Err(InstrumentError::ParseError(format!("Invalid currency: {}", s)));
+        }
+        Ok(Currency::new(Cow::Owned(symbol.to_string())))
+    }
+}
+
+/// Exact decimal number, stored as `mantissa * 10^-scale`
+///
+/// Values are kept normalized (no trailing fractional zeros), so `2.50` and `2.5`
+/// compare and hash equal.
+#[derive(Debug, PartialEq, Clone, Copy, Eq, Hash)]
+pub struct Decimal {
+    mantissa: u64,
+    scale: u32,
+}
+
+impl Decimal {
+    /// Creates a decimal equal to `mantissa * 10^-scale`
+    ///
+    /// Returns None if `scale` exceeds the supported precision
+    pub fn new(mantissa: u64, scale: u32) -> Option<Self> {
+        if scale > MAX_DECIMAL_SCALE {
+            return None;
+        }
+        let (mut mantissa, mut scale) = (mantissa, scale);
+        while scale > 0 && mantissa % 10 == 0 {
+            mantissa /= 10;
+            scale -= 1;
+        }
+        Some(Decimal { mantissa, scale })
+    }
+
+    /// Unscaled integer value
+    pub fn mantissa(&self) -> u64 {
+        self.mantissa
+    }
+
+    /// Number of fractional digits
+    pub fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    /// Parses a decimal written with the given decimal separator (e.g. `2d5` with 'd', `0.15` with '.')
+    pub fn parse_with_separator(value: &str, separator: char) -> Option<Self> {
+        let (int_part, frac_part) = match value.split_once(separator) {
+            Some((i, f)) => (i, f),
+            None => (value, ""),
+        };
+
+        if int_part.is_empty() && frac_part.is_empty() {
+            return None;
+        }
+        if !int_part.bytes().all(|b| b.is_ascii_digit()) || !frac_part.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        // A trailing separator with no digits (e.g. `2d`) is not a valid decimal
+        if value.ends_with(separator) {
+            return None;
+        }
+
+        let scale = u32::try_from(frac_part.len()).ok()?;
+        let mantissa = int_part
+            .bytes()
+            .chain(frac_part.bytes())
+            .try_fold(0u64, |acc, b| acc.checked_mul(10)?.checked_add(u64::from(b - b'0')))?;
+
+        Decimal::new(mantissa, scale)
+    }
+
+    /// Formats the decimal using the given decimal separator, without trailing zeros
+    pub fn format_with_separator(&self, separator: char) -> String {
+        if self.scale == 0 {
+            return self.mantissa.to_string();
+        }
+        let digits = format!("{:0>width$}", self.mantissa, width = self.scale as usize + 1);
+        let (int_part, frac_part) = digits.split_at(digits.len() - self.scale as usize);
+        format!("{int_part}{separator}{frac_part}")
+    }
+
+    /// Value scaled up to `MAX_DECIMAL_SCALE` fractional digits, used for ordering
+    fn scaled_value(&self) -> u128 {
+        u128::from(self.mantissa) * 10u128.pow(MAX_DECIMAL_SCALE - self.scale)
+    }
+}
+
+/// Option strike price
+pub type Strike = Decimal;
+
+impl From<u64> for Decimal {
+    fn from(value: u64) -> Self {
+        Decimal { mantissa: value, scale: 0 }
+    }
+}
+
+impl PartialOrd for Decimal {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Decimal {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.scaled_value().cmp(&other.scaled_value())
+    }
+}
+
+impl Display for Decimal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.format_with_separator('.'))
+    }
+}
+
+impl std::str::FromStr for Decimal {
+    type Err = InstrumentError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Decimal::parse_with_separator(s.trim(), '.')
+            .ok_or_else(|| InstrumentError::ParseError(format!("Invalid decimal: {}", s)))
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use chrono::{Utc, TimeZone};
+    use chrono::{NaiveDate, Utc, TimeZone};
 
-    use crate::parse_expiry_date;
+    use crate::{denormalize_month_code_expiry, normalize_expiry, normalize_month_code_expiry, parse_expiry_date, parse_standard_format, Expiry, Strike, DEFAULT_SETTLEMENT_TIME};
 
     #[test]
     fn test_parse_expiry_date() {
@@ -525,6 +1610,81 @@ mod test {
         println!("{}", parse_expiry_date(date_str, format).unwrap());
     }
 
+    #[test]
+    fn test_strike_parse() {
+        assert_eq!(Strike::parse_with_separator("2d5", 'd'), Strike::new(25, 1));
+        assert_eq!(Strike::parse_with_separator("0.15", '.'), Strike::new(15, 2));
+        assert_eq!(Strike::parse_with_separator("100000", '.'), Some(Strike::from(100000)));
+        assert_eq!(Strike::parse_with_separator("2.50", '.'), Strike::parse_with_separator("2.5", '.'));
+        assert_eq!(Strike::parse_with_separator("2d", 'd'), None);
+        assert_eq!(Strike::parse_with_separator("", '.'), None);
+        assert_eq!(Strike::parse_with_separator("-1", '.'), None);
+        assert_eq!(Strike::parse_with_separator("1.2.3", '.'), None);
+    }
+
+    #[test]
+    fn test_strike_format() {
+        assert_eq!(Strike::new(25, 1).unwrap().format_with_separator('d'), "2d5");
+        assert_eq!(Strike::new(5, 3).unwrap().to_string(), "0.005");
+        assert_eq!(Strike::from(90000).to_string(), "90000");
+        assert!(Strike::new(15, 2).unwrap() < Strike::from(1));
+    }
+
+    #[test]
+    fn test_expiry_settlement() {
+        let expiry = Expiry::parse("20250328", "%Y%m%d", DEFAULT_SETTLEMENT_TIME).unwrap();
+        assert_eq!(expiry.settlement(), Utc.with_ymd_and_hms(2025, 3, 28, 8, 0, 0).unwrap());
+        assert!(!expiry.is_expired_at(Utc.with_ymd_and_hms(2025, 3, 28, 7, 59, 59).unwrap()));
+        assert!(expiry.is_expired_at(Utc.with_ymd_and_hms(2025, 3, 28, 8, 0, 0).unwrap()));
+        assert_eq!(expiry.to_string(), "20250328");
+    }
+
+    #[test]
+    fn test_normalize_expiry_formats() {
+        let expected = Expiry::new(NaiveDate::from_ymd_opt(2025, 3, 28).unwrap(), DEFAULT_SETTLEMENT_TIME);
+        for date_str in ["28MAR25", "20250328", "28MAR2025"] {
+            assert_eq!(normalize_expiry(date_str, DEFAULT_SETTLEMENT_TIME), Some(expected), "{date_str}");
+        }
+        // Exchange-specific formats are parsed by their handlers
+        assert_eq!(normalize_expiry("250328", DEFAULT_SETTLEMENT_TIME), None);
+    }
+
+    #[test]
+    fn test_month_code_expiry() {
+        let expiry = normalize_month_code_expiry("H25", DEFAULT_SETTLEMENT_TIME).unwrap();
+        assert_eq!(expiry.date(), NaiveDate::from_ymd_opt(2025, 3, 28).unwrap());
+        assert_eq!(denormalize_month_code_expiry(&expiry), Some(String::from("H25")));
+        // December 2025 ends on a Wednesday
+        assert_eq!(normalize_month_code_expiry("z25", DEFAULT_SETTLEMENT_TIME).unwrap().date(), NaiveDate::from_ymd_opt(2025, 12, 26).unwrap());
+        // Last day of the month is a Friday
+        assert_eq!(normalize_month_code_expiry("V25", DEFAULT_SETTLEMENT_TIME).unwrap().date(), NaiveDate::from_ymd_opt(2025, 10, 31).unwrap());
+
+        assert_eq!(normalize_month_code_expiry("A25", DEFAULT_SETTLEMENT_TIME), None);
+        assert_eq!(normalize_month_code_expiry("H2025", DEFAULT_SETTLEMENT_TIME), None);
+        let not_last_friday = Expiry::new(NaiveDate::from_ymd_opt(2025, 3, 21).unwrap(), DEFAULT_SETTLEMENT_TIME);
+        assert_eq!(denormalize_month_code_expiry(&not_last_friday), None);
+    }
+
+    #[test]
+    fn test_standard_format_uses_exchange_settlement() {
+        let instrument = parse_standard_format("o.f.BTC-USD-20250328.deribit").unwrap();
+        let expiry = instrument.instrument_type.expiry().unwrap();
+        assert_eq!(expiry.settlement(), Utc.with_ymd_and_hms(2025, 3, 28, 8, 0, 0).unwrap());
+        assert!(instrument.is_expired());
+    }
+
+    #[test]
+    fn test_combo_expiry() {
+        let instrument = parse_standard_format("o.c.FS_+1f:BTC-USD-20250328_-1f:BTC-USD-20250627.deribit").unwrap();
+        let expiry = instrument.instrument_type.expiry().unwrap();
+        assert_eq!(expiry.settlement(), Utc.with_ymd_and_hms(2025, 3, 28, 8, 0, 0).unwrap());
+        assert!(instrument.is_expired());
+
+        // A perpetual leg never expires, so a future spread against it expires with its dated leg
+        let instrument = parse_standard_format("o.c.FS_+1f:BTC-USD-20250328_-1p:BTC-USD.deribit").unwrap();
+        assert!(instrument.is_expired());
+    }
+
     #[test]
     fn test_format_expiry_date() {
         let date = Utc.with_ymd_and_hms(2025, 3, 28, 0, 0, 0).unwrap();
@@ -533,9 +1693,106 @@ mod test {
     }
 }
 
+#[cfg(test)]
+mod test_instrument_type_from_str {
+    use std::str::FromStr;
+
+    use crate::{Exchange, InstrumentType, MarketType};
+
+    #[test]
+    fn test_from_str_is_not_shadowed() {
+        let instrument_type = InstrumentType::from_str("p.BTC-USD").unwrap();
+        assert_eq!(Some(instrument_type), InstrumentType::from_parts("p", "BTC-USD"));
+        #[allow(deprecated)]
+        let legacy = InstrumentType::from_kind_and_name("p", "BTC-USD");
+        assert_eq!(legacy, InstrumentType::from_parts("p", "BTC-USD"));
+    }
+
+    #[test]
+    fn test_from_str_equals_handler_output() {
+        // Kraken Futures settles at 16:00 UTC, not at the default settlement time
+        let parsed = InstrumentType::from_str("f.BTC-USD-20250328").unwrap();
+        let normalized = Exchange::KrakenFutures.handler().normalize(MarketType::OrderBook, "FF_XBTUSD_250328").unwrap();
+        assert_eq!(parsed, normalized.instrument_type);
+        assert_ne!(parsed.expiry().unwrap().settlement_time(), normalized.instrument_type.expiry().unwrap().settlement_time());
+    }
+}
+
+#[cfg(test)]
+mod test_handler_defaults {
+    use std::borrow::Cow;
+
+    use crate::{Currency, Exchange, ExchangeHandler, Instrument, InstrumentError, InstrumentResult, InstrumentType, MarketType};
+
+    /// Handler implementing only the required Result-based methods
+    struct SlashHandler;
+
+    impl ExchangeHandler for SlashHandler {
+        fn try_normalize(&self, market_type: MarketType, instrument_name: &str) -> InstrumentResult<Instrument> {
+            let (base, quote) = instrument_name.split_once('/')
+                .ok_or_else(|| InstrumentError::unrecognized(Exchange::Deribit, instrument_name))?;
+            Ok(Instrument::new(Exchange::Deribit, market_type, InstrumentType::Spot {
+                base: Currency::new(Cow::Owned(base.to_string())),
+                quote: Currency::new(Cow::Owned(quote.to_string())),
+            }))
+        }
+
+        fn try_denormalize(&self, instrument: &Instrument) -> InstrumentResult<String> {
+            match &instrument.instrument_type {
+                InstrumentType::Spot { base, quote } => Ok(format!("{}/{}", base, quote)),
+                _ => Err(InstrumentError::unsupported_instrument_type(Exchange::Deribit, &instrument.instrument_type)),
+            }
+        }
+    }
+
+    /// Handler written against the Option-based methods only
+    struct LegacyHandler;
+
+    impl ExchangeHandler for LegacyHandler {
+        fn normalize(&self, market_type: MarketType, instrument_name: &str) -> Option<Instrument> {
+            SlashHandler.try_normalize(market_type, instrument_name).ok()
+        }
+
+        fn denormalize(&self, instrument: &Instrument) -> Option<String> {
+            SlashHandler.try_denormalize(instrument).ok()
+        }
+    }
+
+    /// Handler implementing neither method of either pair
+    struct EmptyHandler;
+
+    impl ExchangeHandler for EmptyHandler {}
+
+    #[test]
+    fn test_try_methods_default_to_option_methods() {
+        let instrument = LegacyHandler.try_normalize(MarketType::OrderBook, "BTC/USD").unwrap();
+        assert_eq!(LegacyHandler.try_denormalize(&instrument).unwrap(), "BTC/USD");
+        assert!(matches!(LegacyHandler.try_normalize(MarketType::OrderBook, "BTC-USD"), Err(InstrumentError::InvalidFormat(_))));
+        assert_eq!(LegacyHandler.normalize(MarketType::OrderBook, "BTC-USD"), None);
+    }
+
+    #[test]
+    fn test_unimplemented_methods_do_not_recurse() {
+        assert!(matches!(EmptyHandler.try_normalize(MarketType::OrderBook, "BTC/USD"), Err(InstrumentError::Unimplemented("normalize"))));
+        assert_eq!(EmptyHandler.normalize(MarketType::OrderBook, "BTC/USD"), None);
+        let instrument = SlashHandler.normalize(MarketType::OrderBook, "BTC/USD").unwrap();
+        assert!(matches!(EmptyHandler.try_denormalize(&instrument), Err(InstrumentError::Unimplemented("denormalize"))));
+        assert_eq!(EmptyHandler.denormalize(&instrument), None);
+        // The guard is released once the call returns
+        assert!(LegacyHandler.try_normalize(MarketType::OrderBook, "BTC/USD").is_ok());
+    }
+
+    #[test]
+    fn test_option_methods_default_to_try_methods() {
+        let instrument = SlashHandler.normalize(MarketType::OrderBook, "BTC/USD").unwrap();
+        assert_eq!(SlashHandler.denormalize(&instrument), Some(String::from("BTC/USD")));
+        assert_eq!(SlashHandler.normalize(MarketType::OrderBook, "BTC-USD"), None);
+    }
+}
+
 #[cfg(test)]
 mod test_denormalize {
-    use crate::parse_standard_format;
+    use crate::{parse_standard_format, to_exchange_format, ContractStyle, Exchange, InstrumentError};
 
 
     #[test]
@@ -566,7 +1823,49 @@ mod test_denormalize {
     fn test_denormalize_invalid() {
         let standard_format = "o.o.BTC-USD.deribit";
         let denormalized_instrument = parse_standard_format(standard_format);
-        assert!(denormalized_instrument.is_ok());
+        assert!(denormalized_instrument.is_err());
+    }
+    #[test]
+    fn test_denormalize_combo() {
+        let standard_format = "o.c.CS_+1o:BTC-USD-20250328-90000-C_-1o:BTC-USD-20250328-100000-C.deribit";
+        let instrument = parse_standard_format(standard_format).unwrap();
+        assert_eq!(instrument.to_string(), standard_format);
+        assert_eq!(to_exchange_format(standard_format), Some(String::from("BTC-CS-28MAR25-90000_100000")));
+    }
+    #[test]
+    fn test_denormalize_index() {
+        let standard_format = "t.i.BTC-USD-VOL.deribit";
+        let instrument = parse_standard_format(standard_format).unwrap();
+        assert_eq!(instrument.to_string(), standard_format);
+        assert_eq!(to_exchange_format(standard_format), Some(String::from("BTC-DVOL")));
+    }
+    #[test]
+    fn test_denormalize_unsupported_by_exchange() {
+        let standard_format = "o.o.BTC-USD-20250328-100000-C.dydx";
+        let error = parse_standard_format(standard_format).unwrap_err();
+        assert!(matches!(error, InstrumentError::UnsupportedInstrumentType { exchange: Exchange::Dydx, .. }));
+    }
+    #[test]
+    fn test_denormalize_contract_style() {
+        // The exchange's usual style may be spelled out, but is written back without it
+        let instrument = parse_standard_format("o.p.BTC-USD.deribit.inverse").unwrap();
+        assert_eq!(instrument, parse_standard_format("o.p.BTC-USD.deribit").unwrap());
+        assert_eq!(instrument.to_string(), "o.p.BTC-USD.deribit");
+
+        let error = parse_standard_format("o.p.BTC-USD.deribit.linear").unwrap_err();
+        assert!(matches!(error, InstrumentError::UnsupportedContractStyle { exchange: Exchange::Deribit, style: ContractStyle::Linear, .. }));
+        assert!(parse_standard_format("o.p.BTC-USD.deribit.flat").is_err());
+        // Nor can the style be swapped for one the exchange does not list
+        assert!(matches!(
+            instrument.with_contract_style(ContractStyle::Linear),
+            Err(InstrumentError::UnsupportedContractStyle { exchange: Exchange::Deribit, style: ContractStyle::Linear, .. })
+        ));
+    }
+    #[test]
+    fn test_denormalize_fractional_strike() {
+        let standard_format = "o.o.XRP-USDC-20250530-2d5-C.deribit";
+        let instrument = parse_standard_format(standard_format).unwrap();
+        assert_eq!(instrument.to_string(), standard_format);
     }
 }
 
@@ -586,4 +1885,112 @@ fn test_multiple_dates() {
         let is_expired = is_date_expired(date).unwrap();
         println!("Date {} is {}", date, if is_expired { "expired" } else { "not expired" });
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod test_round_trip {
+    use crate::{Currency, Exchange, Instrument, InstrumentType, MarketType};
+
+    /// Exchange instrument names covering every instrument type each handler supports
+    const SAMPLES: &[(Exchange, MarketType, &str)] = &[
+        (Exchange::Deribit, MarketType::OrderBook, "BTC-PERPETUAL"),
+        (Exchange::Deribit, MarketType::OrderBook, "SOL_USDC-PERPETUAL"),
+        (Exchange::Deribit, MarketType::OrderBook, "BTC-28MAR25"),
+        (Exchange::Deribit, MarketType::OrderBook, "BTC-28MAR25-100000-C"),
+        (Exchange::Deribit, MarketType::OrderBook, "XRP-30MAY25-2d5-P"),
+        (Exchange::Deribit, MarketType::OrderBook, "BTC_USDC-28MAR25-100000-C"),
+        (Exchange::Deribit, MarketType::OrderBook, "BTC_USDC"),
+        (Exchange::Deribit, MarketType::OrderBook, "BTC-CS-28MAR25-90000_100000"),
+        (Exchange::Deribit, MarketType::OrderBook, "BTC-FS-28MAR25_PERP"),
+        (Exchange::Deribit, MarketType::Ticker, "deribit_price_index.btc_usd"),
+        (Exchange::Deribit, MarketType::Ticker, "markprice.options.eth_usd"),
+        (Exchange::Deribit, MarketType::Ticker, "BTC-DVOL"),
+        (Exchange::Aevo, MarketType::OrderBook, "BTC-PERP"),
+        (Exchange::Aevo, MarketType::OrderBook, "BTC-28MAR25-100000-C"),
+        (Exchange::Aevo, MarketType::Ticker, "DOGE-28MAR25-0.15-P"),
+        (Exchange::Aevo, MarketType::Ticker, "index:BTC"),
+        (Exchange::Aevo, MarketType::PublicTrade, "AEVO-USDC"),
+        (Exchange::Aevo, MarketType::Funding, "ETH-PERP"),
+        (Exchange::Aevo, MarketType::OrderBook, "BTC-27JUN25"),
+        (Exchange::Derive, MarketType::OrderBook, "BTC-PERP"),
+        (Exchange::Derive, MarketType::OrderBook, "ETH-20250328-3000-P"),
+        (Exchange::Derive, MarketType::PublicTrade, "ETH-USDC"),
+        (Exchange::Derive, MarketType::Funding, "ETH-PERP"),
+        (Exchange::Dydx, MarketType::OrderBook, "BTC-USD"),
+        (Exchange::Paradex, MarketType::OrderBook, "BTC-USD-PERP"),
+        (Exchange::Paradex, MarketType::Ticker, "ETH-USD"),
+        (Exchange::Binance, MarketType::OrderBook, "BTCUSDT_250328"),
+        (Exchange::Binance, MarketType::OrderBook, "BTCUSD_250328"),
+        (Exchange::Binance, MarketType::OrderBook, "ETHUSD_PERP"),
+        (Exchange::Binance, MarketType::OrderBook, "BTC-250328-100000-C"),
+        (Exchange::Okx, MarketType::OrderBook, "BTC-USDT"),
+        (Exchange::Okx, MarketType::OrderBook, "BTC-USDT-SWAP"),
+        (Exchange::Okx, MarketType::Funding, "BTC-USD-SWAP"),
+        (Exchange::Okx, MarketType::OrderBook, "BTC-USD-250328"),
+        (Exchange::Okx, MarketType::OrderBook, "BTC-USD-250328-100000-C"),
+        (Exchange::Bybit, MarketType::OrderBook, "BTCPERP"),
+        (Exchange::Bybit, MarketType::OrderBook, "BTCUSD"),
+        (Exchange::Bybit, MarketType::OrderBook, "BTCUSDH25"),
+        (Exchange::Bybit, MarketType::OrderBook, "BTC-28MAR25"),
+        (Exchange::Bybit, MarketType::OrderBook, "BTC-28MAR25-100000-C"),
+        (Exchange::Bybit, MarketType::OrderBook, "BTC-28MAR25-100000-C-USDT"),
+        (Exchange::Hyperliquid, MarketType::OrderBook, "BTC"),
+        (Exchange::Hyperliquid, MarketType::PublicTrade, "kPEPE"),
+        (Exchange::Hyperliquid, MarketType::OrderBook, "PURR/USDC"),
+        (Exchange::KrakenFutures, MarketType::OrderBook, "PF_XBTUSD"),
+        (Exchange::KrakenFutures, MarketType::Funding, "PF_ETHUSD"),
+        (Exchange::KrakenFutures, MarketType::OrderBook, "FF_XBTUSD_250328"),
+        (Exchange::KrakenFutures, MarketType::OrderBook, "PI_XBTUSD"),
+        (Exchange::KrakenFutures, MarketType::OrderBook, "FI_XBTUSD_250328"),
+        (Exchange::Bitmex, MarketType::OrderBook, "XBTUSD"),
+        (Exchange::Bitmex, MarketType::Funding, "ETHUSDT"),
+        (Exchange::Bitmex, MarketType::OrderBook, "XBTH25"),
+        (Exchange::Bitmex, MarketType::OrderBook, "ETHH25"),
+        (Exchange::Bitmex, MarketType::OrderBook, "ETHUSDTZ25"),
+        (Exchange::Coinbase, MarketType::OrderBook, "BTC-USD"),
+        (Exchange::Coinbase, MarketType::Funding, "BTC-PERP-INTX"),
+        (Exchange::Coinbase, MarketType::OrderBook, "BIT-28MAR25-CDE"),
+        (Exchange::Coinbase, MarketType::OrderBook, "ET-28MAR25-CDE"),
+        (Exchange::Thalex, MarketType::OrderBook, "BTC-PERPETUAL"),
+        (Exchange::Thalex, MarketType::OrderBook, "BTC-28MAR25"),
+        (Exchange::Thalex, MarketType::OrderBook, "BTC-28MAR25-100000-C"),
+        (Exchange::Thalex, MarketType::OrderBook, "BTC-28MAR25-27JUN25"),
+        (Exchange::Delta, MarketType::OrderBook, "BTCUSD"),
+        (Exchange::Delta, MarketType::OrderBook, "C-BTC-100000-280325"),
+        (Exchange::Delta, MarketType::OrderBook, "P-BTC-100000-280325"),
+        (Exchange::Delta, MarketType::OrderBook, "MV-BTC-100000-280325"),
+        (Exchange::Kucoin, MarketType::OrderBook, "XBTUSDTM"),
+        (Exchange::Kucoin, MarketType::OrderBook, "XBTUSDM"),
+        (Exchange::Kucoin, MarketType::OrderBook, "XBTMH25"),
+        (Exchange::Kucoin, MarketType::PublicTrade, "BTC-USDT"),
+        (Exchange::Paradex, MarketType::OrderBook, "BTC-USD-100000-C"),
+        (Exchange::Paradex, MarketType::Funding, "ETH-USD-2500-P"),
+        (Exchange::Paradex, MarketType::OrderBook, "BTC-USD-28MAR25-100000-C"),
+    ];
+
+    #[test]
+    fn test_standard_format_round_trip() {
+        for (exchange, market_type, instrument_name) in SAMPLES {
+            let handler = exchange.handler();
+            let instrument = handler.try_normalize(market_type.clone(), instrument_name).unwrap();
+            let standard_format = instrument.to_string();
+
+            let parsed: Instrument = standard_format.parse().unwrap();
+            assert_eq!(parsed, instrument, "{standard_format}");
+            assert_eq!(parsed.to_string(), standard_format);
+            assert_eq!(handler.try_denormalize(&parsed).unwrap(), *instrument_name, "{standard_format}");
+        }
+    }
+
+    #[test]
+    fn test_component_from_str() {
+        assert_eq!("trade".parse::<MarketType>().unwrap(), MarketType::PublicTrade);
+        assert_eq!("btc".parse::<Currency>().unwrap().to_string(), "BTC");
+        assert!("BTC-USD".parse::<Currency>().is_err());
+        assert!("".parse::<Currency>().is_err());
+
+        let instrument_type: InstrumentType = "f.BTC-USD-20250328".parse().unwrap();
+        assert_eq!(instrument_type.to_string(), "f.BTC-USD-20250328");
+        assert!("f.BTC-USD".parse::<InstrumentType>().is_err());
+        assert!("p.-USD".parse::<InstrumentType>().is_err());
+    }
+}
@@ -0,0 +1,213 @@
+//! Runtime registry of custom exchange handlers
+//!
+//! Built-in exchanges resolve to their static handlers without touching the registry.
+//! Downstream crates can register their own `ExchangeHandler` under a new name, after which
+//! the name resolves through `Exchange::try_from`, `parse_standard_format` and
+//! `to_exchange_format` like any built-in.
+//!
+//! Built-in names cannot be registered. A handler of a built-in exchange configured at runtime
+//! (e.g., `HyperliquidHandler::with_spot_meta`) can instead take over that exchange through
+//! `register_handler`, after which `Exchange::handler`, `parse_standard_format` and
+//! `Instrument::contract_spec` resolve to it rather than to the static handler.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{LazyLock, PoisonError, RwLock};
+
+use crate::{Exchange, ExchangeHandler, InstrumentError, InstrumentResult};
+
+/// Handlers keyed by lowercase exchange name
+type Registry = HashMap<&'static str, &'static (dyn ExchangeHandler + Sync)>;
+
+static REGISTRY: LazyLock<RwLock<Registry>> = LazyLock::new(|| RwLock::new(HashMap::new()));
+
+/// Handlers taking over built-in exchanges
+static OVERRIDES: LazyLock<RwLock<HashMap<Exchange, &'static (dyn ExchangeHandler + Sync)>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+/// Set once any built-in exchange is taken over, so the static handlers are served without locking until then
+static HAS_OVERRIDES: AtomicBool = AtomicBool::new(false);
+
+/// Exchange registered at runtime, obtained only from `register_exchange` or `lookup`
+///
+/// Holds the lowercase registered name together with its handler, so two values are equal
+/// exactly when they name the same registered exchange.
+#[derive(Clone, Copy)]
+pub struct CustomExchange {
+    name: &'static str,
+    handler: &'static (dyn ExchangeHandler + Sync),
+}
+
+impl CustomExchange {
+    /// Lowercase name the exchange was registered under
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub(crate) fn handler(&self) -> &'static dyn ExchangeHandler {
+        self.handler
+    }
+}
+
+impl PartialEq for CustomExchange {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+
+impl Eq for CustomExchange {}
+
+impl Hash for CustomExchange {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+    }
+}
+
+impl fmt::Debug for CustomExchange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CustomExchange").field(&self.name).finish()
+    }
+}
+
+/// Handler accepted by `register_exchange`: a `&'static` reference (e.g., to a static)
+/// or an owned `Box`
+pub struct RegisteredHandler(HandlerRef);
+
+enum HandlerRef {
+    Static(&'static (dyn ExchangeHandler + Sync)),
+    Owned(Box<dyn ExchangeHandler + Sync>),
+}
+
+impl RegisteredHandler {
+    fn into_static(self) -> &'static (dyn ExchangeHandler + Sync) {
+        match self.0 {
+            HandlerRef::Static(handler) => handler,
+            // Handed out as `&'static` by `Exchange::handler`, so it lives as long as the program
+            HandlerRef::Owned(handler) => Box::leak(handler),
+        }
+    }
+}
+
+impl<H: ExchangeHandler + Sync> From<&'static H> for RegisteredHandler {
+    fn from(handler: &'static H) -> Self {
+        Self(HandlerRef::Static(handler))
+    }
+}
+
+impl<H: ExchangeHandler + Sync + 'static> From<Box<H>> for RegisteredHandler {
+    fn from(handler: Box<H>) -> Self {
+        Self(HandlerRef::Owned(handler))
+    }
+}
+
+/// Registers `handler` under `name` and returns the custom exchange it is reachable as.
+///
+/// Names are case-insensitive and may contain ASCII letters, digits, '_' and '-'.
+/// Built-in names (e.g., "deribit") are rejected, and so are names already registered,
+/// since `Exchange::handler` hands out `&'static` references: a registered handler
+/// (boxed or not) lives for the rest of the program and is never replaced.
+pub fn register_exchange(name: &str, handler: impl Into<RegisteredHandler>) -> InstrumentResult<Exchange> {
+    let name = name.trim();
+    if name.is_empty()
+        || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        || Exchange::BUILTIN.iter().any(|exchange| exchange.name().eq_ignore_ascii_case(name))
+    {
+        return Err(InstrumentError::InvalidExchangeName(name.to_string()));
+    }
+
+    let name = name.to_ascii_lowercase();
+    let mut registry = REGISTRY.write().unwrap_or_else(PoisonError::into_inner);
+    if registry.contains_key(name.as_str()) {
+        return Err(InstrumentError::ExchangeAlreadyRegistered(name));
+    }
+
+    // Names are leaked once per custom exchange so `Exchange` stays `Copy`
+    let name: &'static str = Box::leak(name.into_boxed_str());
+    let handler = handler.into().into_static();
+    registry.insert(name, handler);
+    Ok(Exchange::Custom(CustomExchange { name, handler }))
+}
+
+/// Registers `handler` in place of the static handler of the built-in `exchange`.
+///
+/// Custom exchanges are rejected (register them with `register_exchange`), and so are
+/// built-in exchanges already taken over: like registered exchanges, the handler lives
+/// for the rest of the program and is never replaced.
+pub fn register_handler(exchange: Exchange, handler: impl Into<RegisteredHandler>) -> InstrumentResult<()> {
+    if matches!(exchange, Exchange::Custom(_)) {
+        return Err(InstrumentError::InvalidExchangeName(exchange.name().to_string()));
+    }
+
+    let mut overrides = OVERRIDES.write().unwrap_or_else(PoisonError::into_inner);
+    if overrides.contains_key(&exchange) {
+        return Err(InstrumentError::ExchangeAlreadyRegistered(exchange.name().to_string()));
+    }
+
+    overrides.insert(exchange, handler.into().into_static());
+    HAS_OVERRIDES.store(true, Ordering::Release);
+    Ok(())
+}
+
+/// Looks up a built-in or registered exchange by name (case-insensitive)
+pub fn lookup(name: &str) -> Option<Exchange> {
+    let name = name.trim();
+    Exchange::BUILTIN
+        .into_iter()
+        .find(|exchange| exchange.name().eq_ignore_ascii_case(name))
+        .or_else(|| {
+            REGISTRY
+                .read()
+                .unwrap_or_else(PoisonError::into_inner)
+                .get_key_value(name.to_ascii_lowercase().as_str())
+                .map(|(name, handler)| Exchange::Custom(CustomExchange { name, handler: *handler }))
+        })
+}
+
+/// Returns every built-in exchange followed by the registered custom ones
+pub fn exchanges() -> Vec<Exchange> {
+    let registry = REGISTRY.read().unwrap_or_else(PoisonError::into_inner);
+    Exchange::BUILTIN
+        .into_iter()
+        .chain(registry.iter().map(|(name, handler)| Exchange::Custom(CustomExchange { name, handler: *handler })))
+        .collect()
+}
+
+/// Returns the handler registered in place of a built-in exchange's static handler, if any
+pub(crate) fn builtin_handler(exchange: Exchange) -> Option<&'static dyn ExchangeHandler> {
+    if !HAS_OVERRIDES.load(Ordering::Acquire) {
+        return None;
+    }
+    OVERRIDES.read().unwrap_or_else(PoisonError::into_inner).get(&exchange).map(|handler| *handler as &'static dyn ExchangeHandler)
+}
+
+#[cfg(test)]
+mod registry_tests {
+    use super::{lookup, register_exchange};
+    use crate::exchange::deribit::DERIBIT_HANDLER;
+    use crate::{Exchange, InstrumentError, MarketType};
+
+    #[test]
+    fn test_builtins_are_registered() {
+        for exchange in Exchange::BUILTIN {
+            assert_eq!(lookup(exchange.name()), Some(exchange));
+            assert_eq!(Exchange::try_from(exchange.name()), Ok(exchange));
+        }
+    }
+
+    #[test]
+    fn test_register_invalid_name() {
+        assert!(matches!(register_exchange("", &DERIBIT_HANDLER), Err(InstrumentError::InvalidExchangeName(_))));
+        assert!(matches!(register_exchange("my.venue", &DERIBIT_HANDLER), Err(InstrumentError::InvalidExchangeName(_))));
+    }
+
+    #[test]
+    fn test_register_builtin_name() {
+        assert!(matches!(register_exchange("deribit", &DERIBIT_HANDLER), Err(InstrumentError::InvalidExchangeName(_))));
+        assert!(matches!(register_exchange(" Kucoin ", &DERIBIT_HANDLER), Err(InstrumentError::InvalidExchangeName(_))));
+        // The built-in handler is untouched
+        assert!(Exchange::Deribit.handler().normalize(MarketType::OrderBook, "BTC-PERPETUAL").is_some());
+        assert_eq!(lookup("deribit"), Some(Exchange::Deribit));
+    }
+}
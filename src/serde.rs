@@ -0,0 +1,505 @@
+//! Serde support for the instrument model
+//!
+//! Instruments serialize to the compact standard format (e.g., `o.p.BTC-USD.deribit`) by default.
+//! Use [`structured`] with `#[serde(with = "normify::serde::structured")]` to write the object form instead.
+//! Deserialization accepts either form and validates the result against the exchange handler,
+//! so both decode to the same value.
+//!
+//! Formats that are not human-readable (e.g., bincode) always use the structured form, since
+//! they cannot tell a string from an object without a schema.
+//!
+//! The structured and binary forms keep an expiry's settlement time. The compact form of a
+//! standalone [`InstrumentType`] does not: it has no exchange to restore the time from, so
+//! its expiries decode with the `DEFAULT_SETTLEMENT_TIME`.
+
+use std::fmt::{self, Formatter};
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use ::serde::de::{self, MapAccess, Visitor};
+use ::serde::de::value::MapAccessDeserializer;
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    ComboKind, ComboLeg, ContractStyle, Currency, Decimal, Exchange, Expiry, IndexKind, Instrument,
+    InstrumentType, MarketType, OptionKind, DEFAULT_SETTLEMENT_TIME, STANDARD_DATE_FORMAT,
+};
+
+/// Implements serde as a plain string through `Display` and the given parser
+macro_rules! impl_string_serde {
+    ($ty:ty, $expecting:literal, $parse:expr) => {
+        impl Serialize for $ty {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(self)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $ty {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+                let parse: fn(&str) -> Option<$ty> = $parse;
+                parse(&value).ok_or_else(|| de::Error::invalid_value(de::Unexpected::Str(&value), &$expecting))
+            }
+        }
+    };
+}
+
+impl_string_serde!(Currency, "a currency symbol", |s| s.parse().ok());
+impl_string_serde!(Exchange, "an exchange name", |s| Exchange::try_from(s).ok());
+impl_string_serde!(MarketType, "a market type", |s| MarketType::try_from(s).ok());
+impl_string_serde!(OptionKind, "an option kind", |s| OptionKind::try_from(s).ok());
+impl_string_serde!(IndexKind, "an index kind", |s| IndexKind::try_from(s).ok());
+impl_string_serde!(ComboKind, "a combo kind", |s| ComboKind::try_from(s).ok());
+impl_string_serde!(Decimal, "a decimal number", |s| s.parse().ok());
+impl_string_serde!(ContractStyle, "a contract style", |s| ContractStyle::try_from(s).ok());
+
+/// Standard format: `<market-type>.<instrument-kind>.<instrument-name>.<exchange>[.<contract-style>]`
+impl Serialize for Instrument {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            InstrumentRepr::<BinaryInstrumentTypeRepr>::from(self).serialize(serializer)
+        }
+    }
+}
+
+/// Accepts the standard format string or the structured object form
+impl<'de> Deserialize<'de> for Instrument {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(StringOrMap::<InstrumentRepr<InstrumentTypeRepr>>::new("an instrument"))
+        } else {
+            InstrumentRepr::<BinaryInstrumentTypeRepr>::deserialize(deserializer)?
+                .into_target()
+                .map_err(de::Error::custom)
+        }
+    }
+}
+
+/// Standard format without market type and exchange: `<instrument-kind>.<instrument-name>`
+///
+/// Like `Display`, the compact form drops the settlement time of an expiry.
+impl Serialize for InstrumentType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            BinaryInstrumentTypeRepr::from(self).serialize(serializer)
+        }
+    }
+}
+
+/// Accepts the standard format string or the structured object form
+impl<'de> Deserialize<'de> for InstrumentType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(StringOrMap::<InstrumentTypeRepr>::new("an instrument type"))
+        } else {
+            BinaryInstrumentTypeRepr::deserialize(deserializer)?
+                .into_target()
+                .map_err(de::Error::custom)
+        }
+    }
+}
+
+/// Structured object form of an [`Instrument`], for use with `#[serde(with = "...")]`
+///
+/// ```json
+/// {"exchange":"deribit","market_type":"o","instrument_type":{"kind":"perpetual","base":"BTC","quote":"USD"},"contract_style":"inverse"}
+/// ```
+///
+/// The contract style may be left out, in which case the exchange's usual style for the instrument is used.
+pub mod structured {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(instrument: &Instrument, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            InstrumentRepr::<InstrumentTypeRepr>::from(instrument).serialize(serializer)
+        } else {
+            instrument.serialize(serializer)
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Instrument, D::Error> {
+        Instrument::deserialize(deserializer)
+    }
+}
+
+/// Deserializes either a standard format string (through `FromStr`) or a map (through `R`)
+struct StringOrMap<R> {
+    expecting: &'static str,
+    repr: PhantomData<R>,
+}
+
+impl<R> StringOrMap<R> {
+    fn new(expecting: &'static str) -> Self {
+        Self { expecting, repr: PhantomData }
+    }
+}
+
+/// Conversion from a structured representation into its validated model type
+trait Repr: Sized {
+    type Target: FromStr<Err = crate::InstrumentError>;
+
+    fn into_target(self) -> Result<Self::Target, crate::InstrumentError>;
+}
+
+impl<'de, R: Repr + Deserialize<'de>> Visitor<'de> for StringOrMap<R> {
+    type Value = R::Target;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} in standard format or structured form", self.expecting)
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        value.parse().map_err(E::custom)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        R::deserialize(MapAccessDeserializer::new(map))?
+            .into_target()
+            .map_err(de::Error::custom)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct InstrumentRepr<T> {
+    exchange: Exchange,
+    market_type: MarketType,
+    instrument_type: T,
+    #[serde(default)]
+    contract_style: Option<ContractStyle>,
+}
+
+impl<T: for<'a> From<&'a InstrumentType>> From<&Instrument> for InstrumentRepr<T> {
+    fn from(instrument: &Instrument) -> Self {
+        Self {
+            exchange: instrument.exchange,
+            market_type: instrument.market_type.clone(),
+            instrument_type: T::from(&instrument.instrument_type),
+            contract_style: Some(instrument.contract_style),
+        }
+    }
+}
+
+impl<T: Repr<Target = InstrumentType>> Repr for InstrumentRepr<T> {
+    type Target = Instrument;
+
+    fn into_target(self) -> Result<Instrument, crate::InstrumentError> {
+        Instrument::validated(self.exchange, self.market_type, self.instrument_type.into_target()?, self.contract_style)
+    }
+}
+
+/// Defines a structured representation of `InstrumentType` and its combo legs
+macro_rules! instrument_type_repr {
+    ($(#[$attr:meta])* $name:ident, $leg:ident) => {
+        $(#[$attr])*
+        enum $name {
+            Future {
+                base: Currency,
+                quote: Currency,
+                #[serde(with = "standard_date")]
+                expiry: Expiry,
+            },
+            Option {
+                base: Currency,
+                quote: Currency,
+                #[serde(with = "standard_date")]
+                expiry: Expiry,
+                strike: Decimal,
+                option_kind: OptionKind,
+            },
+            PerpetualOption {
+                base: Currency,
+                quote: Currency,
+                strike: Decimal,
+                option_kind: OptionKind,
+            },
+            Spot {
+                base: Currency,
+                quote: Currency,
+            },
+            Perpetual {
+                base: Currency,
+                quote: Currency,
+            },
+            Index {
+                base: Currency,
+                quote: Currency,
+                index_kind: IndexKind,
+            },
+            Move {
+                base: Currency,
+                quote: Currency,
+                #[serde(with = "standard_date")]
+                expiry: Expiry,
+                strike: Decimal,
+            },
+            Combo {
+                combo_kind: ComboKind,
+                legs: Vec<$leg>,
+            },
+        }
+
+        #[derive(Serialize, Deserialize)]
+        struct $leg {
+            ratio: i32,
+            instrument_type: $name,
+        }
+
+        impl From<&InstrumentType> for $name {
+            fn from(instrument_type: &InstrumentType) -> Self {
+                match instrument_type.clone() {
+                    InstrumentType::Future { base, quote, expiry } => Self::Future { base, quote, expiry },
+                    InstrumentType::Option { base, quote, expiry, strike, kind } =>
+                        Self::Option { base, quote, expiry, strike, option_kind: kind },
+                    InstrumentType::PerpetualOption { base, quote, strike, kind } =>
+                        Self::PerpetualOption { base, quote, strike, option_kind: kind },
+                    InstrumentType::Spot { base, quote } => Self::Spot { base, quote },
+                    InstrumentType::Perpetual { base, quote } => Self::Perpetual { base, quote },
+                    InstrumentType::Index { base, quote, kind } => Self::Index { base, quote, index_kind: kind },
+                    InstrumentType::Move { base, quote, expiry, strike } => Self::Move { base, quote, expiry, strike },
+                    InstrumentType::Combo { kind, legs } => Self::Combo {
+                        combo_kind: kind,
+                        legs: legs.iter()
+                            .map(|leg| $leg { ratio: leg.ratio, instrument_type: Self::from(&leg.instrument_type) })
+                            .collect(),
+                    },
+                }
+            }
+        }
+
+        impl Repr for $name {
+            type Target = InstrumentType;
+
+            fn into_target(self) -> Result<InstrumentType, crate::InstrumentError> {
+                Ok(match self {
+                    Self::Future { base, quote, expiry } => InstrumentType::Future { base, quote, expiry },
+                    Self::Option { base, quote, expiry, strike, option_kind } =>
+                        InstrumentType::Option { base, quote, expiry, strike, kind: option_kind },
+                    Self::PerpetualOption { base, quote, strike, option_kind } =>
+                        InstrumentType::PerpetualOption { base, quote, strike, kind: option_kind },
+                    Self::Spot { base, quote } => InstrumentType::Spot { base, quote },
+                    Self::Perpetual { base, quote } => InstrumentType::Perpetual { base, quote },
+                    Self::Index { base, quote, index_kind } => InstrumentType::Index { base, quote, kind: index_kind },
+                    Self::Move { base, quote, expiry, strike } => InstrumentType::Move { base, quote, expiry, strike },
+                    Self::Combo { combo_kind, legs } => {
+                        let legs = legs.into_iter()
+                            .map(|leg| match leg.instrument_type.into_target()? {
+                                InstrumentType::Combo { .. } => Err(crate::InstrumentError::InvalidFormat(
+                                    "Combo legs cannot be combos".to_string(),
+                                )),
+                                _ if leg.ratio == 0 => Err(crate::InstrumentError::InvalidFormat(
+                                    "Combo leg ratio cannot be zero".to_string(),
+                                )),
+                                instrument_type => Ok(ComboLeg { instrument_type, ratio: leg.ratio }),
+                            })
+                            .collect::<Result<Vec<_>, _>>()?;
+                        InstrumentType::Combo { kind: combo_kind, legs }
+                    }
+                })
+            }
+        }
+    };
+}
+
+instrument_type_repr!(
+    /// Object form tagged with its `kind`, for human-readable formats
+    #[derive(Serialize, Deserialize)]
+    #[serde(tag = "kind", rename_all = "snake_case")]
+    InstrumentTypeRepr,
+    ComboLegRepr
+);
+
+instrument_type_repr!(
+    /// Externally tagged form for binary formats (e.g., bincode), which cannot read
+    /// the internally tagged `InstrumentTypeRepr` since they are not self-describing
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    BinaryInstrumentTypeRepr,
+    BinaryComboLegRepr
+);
+
+/// Expiry dates in the standard `YYYYMMDD` format, followed by the settlement time as
+/// `THHMMSS` when it is not the `DEFAULT_SETTLEMENT_TIME` (e.g., `20250328T160000` for Kraken)
+mod standard_date {
+    use chrono::NaiveDateTime;
+
+    use super::*;
+
+    const SETTLEMENT_FORMAT: &str = "%Y%m%dT%H%M%S";
+
+    pub fn serialize<S: Serializer>(expiry: &Expiry, serializer: S) -> Result<S::Ok, S::Error> {
+        if expiry.settlement_time() == DEFAULT_SETTLEMENT_TIME {
+            serializer.collect_str(&expiry.format(STANDARD_DATE_FORMAT))
+        } else {
+            serializer.collect_str(&expiry.settlement().format(SETTLEMENT_FORMAT))
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Expiry, D::Error> {
+        let value = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+        Expiry::parse(&value, STANDARD_DATE_FORMAT, DEFAULT_SETTLEMENT_TIME)
+            .or_else(|| {
+                NaiveDateTime::parse_from_str(&value, SETTLEMENT_FORMAT)
+                    .ok()
+                    .map(|settlement| Expiry::new(settlement.date(), settlement.time()))
+            })
+            .ok_or_else(|| de::Error::invalid_value(de::Unexpected::Str(&value), &"a date in YYYYMMDD[THHMMSS] format"))
+    }
+}
+
+#[cfg(test)]
+mod serde_tests {
+    use std::borrow::Cow;
+
+    use ::serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    use crate::{Currency, Exchange, Instrument, InstrumentType, MarketType, OptionKind, DEFAULT_SETTLEMENT_TIME};
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Config {
+        #[serde(with = "crate::serde::structured")]
+        instrument: Instrument,
+    }
+
+    #[test]
+    fn test_compact_and_structured_forms_are_equal() {
+        let compact: Instrument = serde_json::from_value(json!("o.o.BTC-USD-20250328-100000-C.deribit")).unwrap();
+        let structured: Instrument = serde_json::from_value(json!({
+            "exchange": "deribit",
+            "market_type": "o",
+            "instrument_type": {
+                "kind": "option",
+                "base": "BTC",
+                "quote": "USD",
+                "expiry": "20250328",
+                "strike": "100000",
+                "option_kind": "C",
+            },
+        })).unwrap();
+
+        assert_eq!(compact, structured);
+        assert_eq!(compact, "o.o.BTC-USD-20250328-100000-C.deribit".parse().unwrap());
+    }
+
+    #[test]
+    fn test_serialize_forms() {
+        let instrument = Instrument::new(
+            Exchange::Deribit,
+            MarketType::OrderBook,
+            InstrumentType::Perpetual {
+                base: Currency::new(Cow::Borrowed("BTC")),
+                quote: Currency::new(Cow::Borrowed("USD")),
+            });
+
+        assert_eq!(serde_json::to_value(&instrument).unwrap(), json!("o.p.BTC-USD.deribit"));
+
+        let config = Config { instrument: instrument.clone() };
+        let value = serde_json::to_value(&config).unwrap();
+        assert_eq!(value, json!({
+            "instrument": {
+                "exchange": "deribit",
+                "market_type": "o",
+                "instrument_type": { "kind": "perpetual", "base": "BTC", "quote": "USD" },
+                "contract_style": "inverse",
+            },
+        }));
+        assert_eq!(serde_json::from_value::<Config>(value).unwrap(), config);
+        assert_eq!(serde_json::from_value::<Config>(json!({ "instrument": "o.p.BTC-USD.deribit" })).unwrap(), config);
+    }
+
+    #[test]
+    fn test_structured_round_trip_combo() {
+        let instrument: Instrument = "o.c.CS_+1o:BTC-USD-20250328-90000-C_-1o:BTC-USD-20250328-100000-C.deribit"
+            .parse()
+            .unwrap();
+        let value = serde_json::to_value(Config { instrument: instrument.clone() }).unwrap();
+        assert_eq!(serde_json::from_value::<Config>(value).unwrap().instrument, instrument);
+    }
+
+    #[test]
+    fn test_structured_move() {
+        let instrument: Instrument = "o.m.BTC-USD-20250328-100000.delta".parse().unwrap();
+        let value = serde_json::to_value(Config { instrument: instrument.clone() }).unwrap();
+        assert_eq!(value["instrument"]["instrument_type"], json!({
+            "kind": "move",
+            "base": "BTC",
+            "quote": "USD",
+            "expiry": "20250328T120000",
+            "strike": "100000",
+        }));
+        assert_eq!(serde_json::from_value::<Config>(value).unwrap().instrument, instrument);
+    }
+
+    #[test]
+    fn test_binary_round_trip() {
+        for standard_format in [
+            "o.p.BTC-USD.deribit",
+            "o.o.BTC-USD-20250328-100000-C.deribit",
+            "o.c.CS_+1o:BTC-USD-20250328-90000-C_-1o:BTC-USD-20250328-100000-C.deribit",
+        ] {
+            let instrument: Instrument = standard_format.parse().unwrap();
+            let bytes = bincode::serialize(&instrument).unwrap();
+            assert_eq!(bincode::deserialize::<Instrument>(&bytes).unwrap(), instrument);
+            assert_eq!(bincode::deserialize::<InstrumentType>(&bincode::serialize(&instrument.instrument_type).unwrap()).unwrap(), instrument.instrument_type);
+
+            let config = Config { instrument };
+            assert_eq!(bincode::deserialize::<Config>(&bincode::serialize(&config).unwrap()).unwrap(), config);
+        }
+    }
+
+    #[test]
+    fn test_settlement_time_round_trip() {
+        let instrument: Instrument = "o.f.BTC-USD-20250328.kraken_futures".parse().unwrap();
+        let settlement_time = |instrument_type: &InstrumentType| match instrument_type {
+            InstrumentType::Future { expiry, .. } => expiry.settlement_time(),
+            _ => unreachable!(),
+        };
+        let kraken_time = settlement_time(&instrument.instrument_type);
+        assert_ne!(kraken_time, DEFAULT_SETTLEMENT_TIME);
+
+        let value = serde_json::to_value(Config { instrument: instrument.clone() }).unwrap();
+        assert_eq!(value["instrument"]["instrument_type"]["expiry"], json!("20250328T160000"));
+        let decoded = serde_json::from_value::<Config>(value).unwrap().instrument;
+        assert_eq!(settlement_time(&decoded.instrument_type), kraken_time);
+
+        let bytes = bincode::serialize(&instrument.instrument_type).unwrap();
+        let decoded = bincode::deserialize::<InstrumentType>(&bytes).unwrap();
+        assert_eq!(settlement_time(&decoded), kraken_time);
+
+        let structured: InstrumentType = serde_json::from_value(json!({
+            "kind": "future", "base": "BTC", "quote": "USD", "expiry": "20250328T160000",
+        })).unwrap();
+        assert_eq!(settlement_time(&structured), kraken_time);
+
+        // The compact form of a standalone instrument type is lossy
+        let compact = serde_json::to_value(&instrument.instrument_type).unwrap();
+        assert_eq!(compact, json!("f.BTC-USD-20250328"));
+        let decoded = serde_json::from_value::<InstrumentType>(compact).unwrap();
+        assert_eq!(settlement_time(&decoded), DEFAULT_SETTLEMENT_TIME);
+    }
+
+    #[test]
+    fn test_components() {
+        assert_eq!(serde_json::to_value(Exchange::Aevo).unwrap(), json!("aevo"));
+        assert_eq!(serde_json::from_value::<MarketType>(json!("ticker")).unwrap(), MarketType::Ticker);
+        assert_eq!(serde_json::from_value::<OptionKind>(json!("put")).unwrap(), OptionKind::Put);
+        assert_eq!(serde_json::from_value::<Currency>(json!("eth")).unwrap(), Currency::new(Cow::Borrowed("ETH")));
+        assert!(serde_json::from_value::<Currency>(json!("ETH-USD")).is_err());
+    }
+
+    #[test]
+    fn test_invalid_instruments_are_rejected() {
+        // Well formed but not supported by the exchange
+        assert!(serde_json::from_value::<Instrument>(json!("o.s.BTC-USD.dydx")).is_err());
+        assert!(serde_json::from_value::<Instrument>(json!({
+            "exchange": "dydx",
+            "market_type": "o",
+            "instrument_type": { "kind": "spot", "base": "BTC", "quote": "USD" },
+        })).is_err());
+        assert!(serde_json::from_value::<Instrument>(json!(42)).is_err());
+    }
+}
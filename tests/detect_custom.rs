@@ -0,0 +1,50 @@
+//! Detection across registered exchanges, in its own binary since registering an exchange
+//! changes what `detect_exchange` returns for every other test in the process.
+
+use std::borrow::Cow;
+
+use normify::detect::detect_exchange;
+use normify::registry::{lookup, register_exchange};
+use normify::{Currency, Exchange, ExchangeHandler, Instrument, InstrumentError, InstrumentResult, InstrumentType, MarketType};
+
+/// Venue reading Deribit-style USDC option names as spot pairs (e.g., BTC_USDC-28MAR25-100000-C
+/// as BTC-USDC), which it writes back as BTC_USDC
+struct LooseHandler {
+    name: &'static str,
+}
+
+static LOOSE_HANDLER: LooseHandler = LooseHandler { name: "loose_venue" };
+
+impl ExchangeHandler for LooseHandler {
+    fn try_normalize(&self, market_type: MarketType, instrument_name: &str) -> InstrumentResult<Instrument> {
+        let exchange = lookup(self.name).ok_or_else(|| InstrumentError::InvalidExchangeName(self.name.to_string()))?;
+        let base = instrument_name.split_once("_USDC-")
+            .filter(|_| instrument_name.ends_with("-C") || instrument_name.ends_with("-P"))
+            .map(|(base, _)| base)
+            .ok_or_else(|| InstrumentError::UnrecognizedInstrument { exchange, value: instrument_name.to_string() })?;
+        Ok(Instrument::new(exchange, market_type, InstrumentType::Spot {
+            base: Currency::new(Cow::Owned(base.to_string())),
+            quote: Currency::new(Cow::Borrowed("USDC")),
+        }))
+    }
+
+    fn try_denormalize(&self, instrument: &Instrument) -> InstrumentResult<String> {
+        Ok(format!("{}_{}", instrument.instrument_type.base(), instrument.instrument_type.quote()))
+    }
+}
+
+#[test]
+fn test_detect_ranking() {
+    let loose_venue = register_exchange(LOOSE_HANDLER.name, &LOOSE_HANDLER).unwrap();
+
+    // The Deribit option spells out every field and round-trips exactly, while the
+    // spot reading only explains the base and quote and writes the name differently
+    let candidates = detect_exchange("BTC_USDC-28MAR25-100000-C", MarketType::OrderBook);
+    assert_eq!(candidates[0].exchange, Exchange::Deribit);
+    assert_eq!(candidates[0].instrument.to_string(), "o.o.BTC-USDC-20250328-100000-C.deribit");
+    let loose = candidates.iter().find(|c| c.exchange == loose_venue).unwrap();
+    assert_eq!(loose.instrument.to_string(), "o.s.BTC-USDC.loose_venue");
+    assert!(candidates[0].confidence > loose.confidence);
+    let total: f64 = candidates.iter().map(|c| c.confidence).sum();
+    assert!((total - 1.0).abs() < 1e-9);
+}
@@ -0,0 +1,34 @@
+//! Taking over a built-in exchange replaces its handler for the whole process, so these
+//! tests run in their own binary rather than alongside the unit tests.
+
+use std::borrow::Cow;
+
+use normify::exchange::hyperliquid::{HyperliquidHandler, HyperliquidSpotMeta};
+use normify::registry::{register_exchange, register_handler};
+use normify::{parse_standard_format, to_exchange_format, Currency, Exchange, InstrumentError, MarketType};
+
+#[test]
+fn test_register_builtin_handler() {
+    let mut spot_meta = HyperliquidSpotMeta::new();
+    spot_meta
+        .insert("PURR/USDC", Currency::new(Cow::Borrowed("PURR")), Currency::new(Cow::Borrowed("USDC")))
+        .insert("@107", Currency::new(Cow::Borrowed("HYPE")), Currency::new(Cow::Borrowed("USDC")));
+    register_handler(Exchange::Hyperliquid, Box::new(HyperliquidHandler::new().with_spot_meta(spot_meta))).unwrap();
+
+    // The configured handler now serves the built-in exchange
+    let instrument = Exchange::Hyperliquid.handler().normalize(MarketType::OrderBook, "@107").unwrap();
+    assert_eq!(instrument.to_string(), "o.s.HYPE-USDC.hyperliquid");
+    assert_eq!(to_exchange_format("o.s.HYPE-USDC.hyperliquid"), Some(String::from("@107")));
+    assert_eq!(parse_standard_format("o.p.BTC-USDC.hyperliquid").unwrap().to_string(), "o.p.BTC-USDC.hyperliquid");
+
+    // Handlers are never replaced, and custom exchanges are registered by name
+    assert!(matches!(
+        register_handler(Exchange::Hyperliquid, Box::new(HyperliquidHandler::new())),
+        Err(InstrumentError::ExchangeAlreadyRegistered(_))
+    ));
+    let custom = register_exchange("elsewhere", Box::new(HyperliquidHandler::new())).unwrap();
+    assert!(matches!(
+        register_handler(custom, Box::new(HyperliquidHandler::new())),
+        Err(InstrumentError::InvalidExchangeName(_))
+    ));
+}
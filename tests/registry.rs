@@ -0,0 +1,66 @@
+//! Registered exchanges live for the rest of the process and are seen by `detect_exchange`,
+//! so the tests registering them run in their own binary rather than alongside the unit tests.
+
+use std::borrow::Cow;
+
+use normify::registry::{lookup, register_exchange};
+use normify::{
+    parse_standard_format, to_exchange_format, Currency, Exchange, ExchangeHandler, Instrument, InstrumentError,
+    InstrumentResult, InstrumentType, MarketType,
+};
+
+/// Venue listing perpetuals as `<BASE>/<QUOTE>`, registered under `name`
+struct SlashHandler {
+    name: &'static str,
+}
+
+static SLASH_HANDLER: SlashHandler = SlashHandler { name: "slashex" };
+
+impl ExchangeHandler for SlashHandler {
+    fn try_normalize(&self, market_type: MarketType, instrument_name: &str) -> InstrumentResult<Instrument> {
+        let exchange = lookup(self.name).ok_or_else(|| InstrumentError::InvalidExchangeName(self.name.to_string()))?;
+        let (base, quote) = instrument_name
+            .split_once('/')
+            .ok_or_else(|| InstrumentError::UnrecognizedInstrument { exchange, value: instrument_name.to_string() })?;
+        Ok(Instrument::new(
+            exchange,
+            market_type,
+            InstrumentType::Perpetual {
+                base: Currency::new(Cow::Owned(base.to_string())),
+                quote: Currency::new(Cow::Owned(quote.to_string())),
+            }))
+    }
+
+    fn try_denormalize(&self, instrument: &Instrument) -> InstrumentResult<String> {
+        match &instrument.instrument_type {
+            InstrumentType::Perpetual { base, quote } => Ok(format!("{}/{}", base, quote)),
+            instrument_type => Err(InstrumentError::UnsupportedInstrumentType {
+                exchange: instrument.exchange,
+                value: instrument_type.to_string(),
+            }),
+        }
+    }
+}
+
+#[test]
+fn test_register_custom_exchange() {
+    let exchange = register_exchange("SlashEx", &SLASH_HANDLER).unwrap();
+    assert_eq!(exchange.name(), "slashex");
+    assert_eq!(Exchange::try_from("SLASHEX"), Ok(exchange));
+    assert_eq!(lookup("SlashEx"), Some(exchange));
+    // Registered handlers are never replaced
+    assert!(matches!(register_exchange("slashex", &SLASH_HANDLER), Err(InstrumentError::ExchangeAlreadyRegistered(_))));
+
+    let instrument = exchange.handler().normalize(MarketType::OrderBook, "BTC/USDT").unwrap();
+    assert_eq!(instrument.to_string(), "o.p.BTC-USDT.slashex");
+    assert_eq!(parse_standard_format("o.p.BTC-USDT.slashex").unwrap(), instrument);
+    assert_eq!(to_exchange_format("o.p.BTC-USDT.slashex"), Some(String::from("BTC/USDT")));
+    assert!(parse_standard_format("o.s.BTC-USDT.slashex").is_err());
+}
+
+#[test]
+fn test_register_boxed_handler() {
+    let exchange = register_exchange("boxed_venue", Box::new(SlashHandler { name: "boxed_venue" })).unwrap();
+    assert_eq!(exchange.name(), "boxed_venue");
+    assert_eq!(to_exchange_format("o.p.BTC-USDT.boxed_venue"), Some(String::from("BTC/USDT")));
+}